@@ -12,6 +12,8 @@ pub mod derive_attr {
         pub schema_name: Option<syn::Lit>,
         pub table_name: Option<syn::Lit>,
         pub table_iden: Option<()>,
+        pub soft_delete_column: Option<syn::Lit>,
+        pub version_column: Option<syn::Lit>,
     }
 }
 
@@ -32,3 +34,104 @@ pub mod field_attr {
         pub fk_name: Option<syn::Lit>,
     }
 }
+
+pub mod index_attr {
+    use heck::CamelCase;
+    use proc_macro2::TokenStream;
+    use quote::{format_ident, quote};
+    use syn::{
+        bracketed, ext::IdentExt, parenthesized, parse::ParseStream, punctuated::Punctuated,
+        token::Comma, Attribute, Ident, LitStr, Token,
+    };
+
+    /// An index of the Entity, `#[sea_orm(index(name = "..", columns = ["a", "b"], unique, where = ".."))]`
+    pub struct Index {
+        pub name: Option<LitStr>,
+        pub columns: Vec<LitStr>,
+        pub unique: bool,
+        pub condition: Option<LitStr>,
+    }
+
+    impl Index {
+        /// Check if the attribute is a `#[sea_orm(index(..))]`
+        pub fn is_index_attribute(attr: &Attribute) -> bool {
+            if attr.path.get_ident().map(|i| i == "sea_orm") != Some(true) {
+                return false;
+            }
+            attr.parse_args_with(|input: ParseStream| {
+                let ident = input.call(Ident::parse_any)?;
+                input.parse::<TokenStream>()?;
+                Ok(ident == "index")
+            })
+            .unwrap_or(false)
+        }
+
+        /// Parse the attribute if it is a `#[sea_orm(index(..))]`, or return `None`
+        pub fn try_from_attribute(attr: &Attribute) -> syn::Result<Option<Self>> {
+            if !Self::is_index_attribute(attr) {
+                return Ok(None);
+            }
+            attr.parse_args_with(Self::parse).map(Some)
+        }
+
+        fn parse(input: ParseStream) -> syn::Result<Self> {
+            input.call(Ident::parse_any)?;
+            let content;
+            parenthesized!(content in input);
+            let mut index = Self {
+                name: None,
+                columns: Vec::new(),
+                unique: false,
+                condition: None,
+            };
+            while !content.is_empty() {
+                let key = content.call(Ident::parse_any)?;
+                if key == "unique" {
+                    index.unique = true;
+                } else {
+                    content.parse::<Token![=]>()?;
+                    if key == "name" {
+                        index.name = Some(content.parse()?);
+                    } else if key == "where" {
+                        index.condition = Some(content.parse()?);
+                    } else if key == "columns" {
+                        let columns;
+                        bracketed!(columns in content);
+                        index.columns = Punctuated::<LitStr, Comma>::parse_terminated(&columns)?
+                            .into_iter()
+                            .collect();
+                    } else {
+                        return Err(syn::Error::new_spanned(
+                            key,
+                            "expected one of `name`, `columns`, `unique` or `where`",
+                        ));
+                    }
+                }
+                if !content.is_empty() {
+                    content.parse::<Comma>()?;
+                }
+            }
+            if index.columns.is_empty() {
+                return Err(content.error("index should have at least one column"));
+            }
+            Ok(index)
+        }
+
+        /// Expand into an `IndexDef` of the given Column enum
+        pub fn expand(&self, column_ident: &Ident) -> TokenStream {
+            let columns = self.columns.iter().map(|column| {
+                let variant = format_ident!("{}", column.value().to_camel_case());
+                quote!(#column_ident::#variant)
+            });
+            let name = self.name.as_ref().map(|name| quote!(.name(#name)));
+            let unique = self.unique.then(|| quote!(.unique()));
+            let condition = self
+                .condition
+                .as_ref()
+                .map(|condition| quote!(.partial(#condition)));
+            quote!(
+                sea_orm::entity::IndexDef::new([#(#columns),*]) #name #unique #condition
+            )
+        }
+    }
+}
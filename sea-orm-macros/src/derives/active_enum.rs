@@ -1,4 +1,4 @@
-use heck::CamelCase;
+use heck::{CamelCase, KebabCase, MixedCase, ShoutySnakeCase, SnakeCase};
 use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned};
 use syn::{parse, punctuated::Punctuated, token::Comma, Expr, Lit, LitInt, LitStr, Meta, UnOp};
@@ -13,9 +13,19 @@ struct ActiveEnum {
     ident: syn::Ident,
     enum_name: String,
     rs_type: TokenStream,
+    int128: Option<TokenStream>,
     db_type: TokenStream,
     is_string: bool,
     variants: Vec<ActiveEnumVariant>,
+    fallback: Option<syn::Ident>,
+    impl_display: bool,
+    impl_from_str: bool,
+    case_insensitive: bool,
+    is_json: bool,
+    is_char: bool,
+    default: Option<syn::Ident>,
+    serde_db_value: bool,
+    is_checked: bool,
 }
 
 struct ActiveEnumVariant {
@@ -36,6 +46,17 @@ impl ActiveEnum {
         let mut db_type = Err(Error::TT(quote_spanned! {
             ident_span => compile_error!("Missing macro attribute `db_type`");
         }));
+        let mut int128 = None;
+        let mut db_type_lit = None;
+        let mut rename_all = None;
+        let mut impl_display = false;
+        let mut impl_from_str = false;
+        let mut is_native_enum = false;
+        let mut case_insensitive = false;
+        let mut is_json = false;
+        let mut is_char = false;
+        let mut serde_db_value = false;
+        let mut is_checked = false;
         for attr in input.attrs.iter() {
             if let Some(ident) = attr.path.get_ident() {
                 if ident != "sea_orm" {
@@ -46,23 +67,48 @@ impl ActiveEnum {
             }
             if let Ok(list) = attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated) {
                 for meta in list.iter() {
+                    if let Meta::Path(path) = meta {
+                        if path.is_ident("impl_display") {
+                            impl_display = true;
+                        } else if path.is_ident("impl_from_str") {
+                            impl_from_str = true;
+                        } else if path.is_ident("case_insensitive") {
+                            case_insensitive = true;
+                        } else if path.is_ident("serde_db_value") {
+                            serde_db_value = true;
+                        }
+                    }
                     if let Meta::NameValue(nv) = meta {
                         if let Some(name) = nv.path.get_ident() {
                             if name == "rs_type" {
                                 if let Lit::Str(litstr) = &nv.lit {
+                                    int128 = Some(litstr.value())
+                                        .filter(|s| matches!(s.as_str(), "i128" | "u128"));
                                     rs_type = syn::parse_str::<TokenStream>(&litstr.value())
                                         .map_err(Error::Syn);
                                 }
                             } else if name == "db_type" {
                                 if let Lit::Str(litstr) = &nv.lit {
+                                    db_type_lit = Some(litstr.clone());
                                     let s = litstr.value();
                                     match s.as_ref() {
                                         "Enum" => {
+                                            is_native_enum = true;
                                             db_type = Ok(quote! {
                                                 Enum(Self::name(), Self::values())
                                             })
                                         }
+                                        "StringChecked" => {
+                                            is_checked = true;
+                                            db_type = Ok(quote! { String(None) });
+                                        }
+                                        "Json" | "JsonBinary" => {
+                                            is_json = true;
+                                            db_type = syn::parse_str::<TokenStream>(&s)
+                                                .map_err(Error::Syn);
+                                        }
                                         _ => {
+                                            is_char = s.starts_with("Char");
                                             db_type = syn::parse_str::<TokenStream>(&s)
                                                 .map_err(Error::Syn);
                                         }
@@ -72,6 +118,15 @@ impl ActiveEnum {
                                 if let Lit::Str(litstr) = &nv.lit {
                                     enum_name = litstr.value();
                                 }
+                            } else if name == "rename_all" {
+                                if let Lit::Str(litstr) = &nv.lit {
+                                    if rename_variant(&litstr.value(), "").is_none() {
+                                        return Err(Error::TT(quote_spanned! {
+                                            litstr.span() => compile_error!("Unsupported `rename_all` value, expected one of `snake_case`, `camelCase`, `kebab-case` or `SCREAMING_SNAKE_CASE`");
+                                        }));
+                                    }
+                                    rename_all = Some(litstr.value());
+                                }
                             }
                         }
                     }
@@ -86,11 +141,15 @@ impl ActiveEnum {
 
         let mut is_string = false;
         let mut is_int = false;
-        let mut variants = Vec::new();
+        let mut variants: Vec<ActiveEnumVariant> = Vec::new();
+        let mut fallback = None;
+        let mut default = None;
         for variant in variant_vec {
             let variant_span = variant.ident.span();
             let mut string_value = None;
             let mut num_value = None;
+            let mut is_fallback = false;
+            let mut is_default = false;
             for attr in variant.attrs.iter() {
                 if let Some(ident) = attr.path.get_ident() {
                     if ident != "sea_orm" {
@@ -102,6 +161,13 @@ impl ActiveEnum {
                 if let Ok(list) = attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)
                 {
                     for meta in list {
+                        if let Meta::Path(path) = &meta {
+                            if path.is_ident("fallback") {
+                                is_fallback = true;
+                            } else if path.is_ident("default") {
+                                is_default = true;
+                            }
+                        }
                         if let Meta::NameValue(nv) = meta {
                             if let Some(name) = nv.path.get_ident() {
                                 if name == "string_value" {
@@ -127,6 +193,60 @@ impl ActiveEnum {
                 }));
             }
 
+            if is_default {
+                if default.is_some() {
+                    return Err(Error::TT(quote_spanned! {
+                        variant_span => compile_error!("Only one enum variant can be marked as `default`");
+                    }));
+                }
+                if is_fallback {
+                    return Err(Error::TT(quote_spanned! {
+                        variant_span => compile_error!("The `fallback` variant cannot be marked as `default`");
+                    }));
+                }
+                default = Some(variant.ident.clone());
+            }
+
+            if is_fallback {
+                if fallback.is_some() {
+                    return Err(Error::TT(quote_spanned! {
+                        variant_span => compile_error!("Only one enum variant can be marked as `fallback`");
+                    }));
+                }
+                if string_value.is_some() || num_value.is_some() {
+                    return Err(Error::TT(quote_spanned! {
+                        variant_span => compile_error!("The `fallback` variant cannot specify `string_value` or `num_value`");
+                    }));
+                }
+                let field_ty = match &variant.fields {
+                    syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                        &fields.unnamed[0].ty
+                    }
+                    _ => {
+                        return Err(Error::TT(quote_spanned! {
+                            variant_span => compile_error!("The `fallback` variant should be a tuple variant with a single field, e.g. `Unknown(String)`");
+                        }));
+                    }
+                };
+                if let Ok(rs_type) = &rs_type {
+                    if quote!(#field_ty).to_string() != rs_type.to_string() {
+                        return Err(Error::TT(quote_spanned! {
+                            variant_span => compile_error!("The field type of `fallback` variant should be the same as `rs_type`");
+                        }));
+                    }
+                }
+                fallback = Some(variant.ident);
+                continue;
+            }
+
+            if string_value.is_none() && num_value.is_none() && variant.discriminant.is_none() {
+                if let Some(rename_all) = &rename_all {
+                    is_string = true;
+                    string_value = rename_variant(rename_all, &variant.ident.to_string())
+                        .map(|value| LitStr::new(&value, variant_span));
+                }
+            }
+
             if string_value.is_none() && num_value.is_none() {
                 match variant.discriminant {
                     Some((_, Expr::Lit(exprlit))) => {
@@ -165,6 +285,30 @@ impl ActiveEnum {
                 }
             }
 
+            if rename_all.is_some() && (is_int || num_value.is_some()) {
+                return Err(Error::TT(quote_spanned! {
+                    variant_span => compile_error!("`rename_all` can only be used with `string_value` variants, not `num_value`");
+                }));
+            }
+
+            for other in variants.iter() {
+                let is_duplicate = match (
+                    &string_value,
+                    &other.string_value,
+                    &num_value,
+                    &other.num_value,
+                ) {
+                    (Some(a), Some(b), _, _) => a.value() == b.value(),
+                    (_, _, Some(a), Some(b)) => a.base10_digits() == b.base10_digits(),
+                    _ => false,
+                };
+                if is_duplicate {
+                    return Err(Error::TT(quote_spanned! {
+                        variant_span => compile_error!("Enum variant has the same `string_value` or `num_value` as another variant");
+                    }));
+                }
+            }
+
             variants.push(ActiveEnumVariant {
                 ident: variant.ident,
                 string_value,
@@ -172,20 +316,225 @@ impl ActiveEnum {
             });
         }
 
+        if is_native_enum && is_int {
+            return Err(Error::TT(quote_spanned! {
+                ident_span => compile_error!("`db_type = \"Enum\"` can only be used on enums with `string_value` variants");
+            }));
+        }
+
+        if is_checked && is_int {
+            return Err(Error::TT(quote_spanned! {
+                ident_span => compile_error!("`db_type = \"StringChecked\"` can only be used on enums with `string_value` variants");
+            }));
+        }
+
+        if is_json && is_int {
+            return Err(Error::TT(quote_spanned! {
+                ident_span => compile_error!("`db_type = \"Json\"` and `db_type = \"JsonBinary\"` can only be used on enums with `string_value` variants");
+            }));
+        }
+
+        if let Some(int128) = &int128 {
+            if let Some(litstr) = &db_type_lit {
+                let s = litstr.value();
+                if !(s.starts_with("String") || s.starts_with("Char") || s == "Text") {
+                    return Err(Error::TT(quote_spanned! {
+                        litstr.span() => compile_error!("128-bit integers are stored as their decimal string, `db_type` should be `String(..)`, `Char(..)` or `Text`");
+                    }));
+                }
+            }
+            for variant in variants.iter() {
+                if let Some(num_value) = &variant.num_value {
+                    let fits = if int128 == "i128" {
+                        num_value.base10_parse::<i128>().is_ok()
+                    } else {
+                        num_value.base10_parse::<u128>().is_ok()
+                    };
+                    if !fits {
+                        return Err(Error::TT(quote_spanned! {
+                            num_value.span() => compile_error!("`num_value` is out of range for `rs_type`");
+                        }));
+                    }
+                }
+            }
+        }
+
+        if case_insensitive {
+            if !is_string {
+                return Err(Error::TT(quote_spanned! {
+                    ident_span => compile_error!("`case_insensitive` can only be used on enums with `string_value` variants");
+                }));
+            }
+            let mut lowercased: Vec<String> = Vec::new();
+            for variant in variants.iter() {
+                if let Some(string_value) = &variant.string_value {
+                    let value = string_value.value().to_lowercase();
+                    if lowercased.contains(&value) {
+                        return Err(Error::TT(quote_spanned! {
+                            string_value.span() => compile_error!("Enum variants have the same `string_value` when compared case-insensitively");
+                        }));
+                    }
+                    lowercased.push(value);
+                }
+            }
+        }
+
+        if impl_display && !is_string {
+            return Err(Error::TT(quote_spanned! {
+                ident_span => compile_error!("`impl_display` can only be used on enums with `string_value` variants");
+            }));
+        }
+
         Ok(ActiveEnum {
             ident,
             enum_name,
             rs_type: rs_type?,
+            int128: int128.map(|s| match s.as_str() {
+                "i128" => quote! { sea_orm::I128 },
+                _ => quote! { sea_orm::U128 },
+            }),
             db_type: db_type?,
             is_string,
             variants,
+            fallback,
+            impl_display,
+            impl_from_str,
+            case_insensitive,
+            is_json,
+            is_char,
+            default,
+            serde_db_value,
+            is_checked,
         })
     }
 
     fn expand(&self) -> syn::Result<TokenStream> {
         let expanded_impl_active_enum = self.impl_active_enum();
+        let expanded_impl_display = self.impl_display();
+        let expanded_impl_from_str = self.impl_from_str();
+        let expanded_impl_default = self.impl_default();
+        let expanded_impl_serde = self.impl_serde();
+
+        Ok(TokenStream::from_iter([
+            expanded_impl_active_enum,
+            expanded_impl_display,
+            expanded_impl_from_str,
+            expanded_impl_default,
+            expanded_impl_serde,
+        ]))
+    }
+
+    fn impl_serde(&self) -> TokenStream {
+        if !self.serde_db_value {
+            return TokenStream::new();
+        }
+        let ident = &self.ident;
+
+        quote!(
+            #[automatically_derived]
+            impl sea_orm::serde::Serialize for #ident {
+                fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+                where
+                    S: sea_orm::serde::Serializer,
+                {
+                    sea_orm::serde::Serialize::serialize(
+                        &<Self as sea_orm::ActiveEnum>::to_value(self),
+                        serializer,
+                    )
+                }
+            }
+
+            #[automatically_derived]
+            impl<'de> sea_orm::serde::Deserialize<'de> for #ident {
+                fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+                where
+                    D: sea_orm::serde::Deserializer<'de>,
+                {
+                    let value = <<Self as sea_orm::ActiveEnum>::Value as sea_orm::serde::Deserialize>::deserialize(deserializer)?;
+                    <Self as sea_orm::ActiveEnum>::try_from_value(&value).map_err(|e| {
+                        let msg = match e {
+                            sea_orm::DbErr::Type(msg) => msg,
+                            e => e.to_string(),
+                        };
+                        <D::Error as sea_orm::serde::de::Error>::custom(msg)
+                    })
+                }
+            }
+        )
+    }
+
+    fn impl_default(&self) -> TokenStream {
+        let default = match &self.default {
+            Some(default) => default,
+            None => return TokenStream::new(),
+        };
+        let ident = &self.ident;
+
+        quote!(
+            #[automatically_derived]
+            impl std::default::Default for #ident {
+                fn default() -> Self {
+                    Self::#default
+                }
+            }
+        )
+    }
+
+    fn impl_display(&self) -> TokenStream {
+        if !self.impl_display {
+            return TokenStream::new();
+        }
+        let ident = &self.ident;
+
+        let value = if self.is_json {
+            quote! { <Self as sea_orm::ActiveEnum>::to_value(self).as_str().unwrap_or_default() }
+        } else {
+            quote! { <Self as sea_orm::ActiveEnum>::to_value(self) }
+        };
+
+        quote!(
+            #[automatically_derived]
+            impl std::fmt::Display for #ident {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(f, "{}", #value)
+                }
+            }
+        )
+    }
+
+    fn impl_from_str(&self) -> TokenStream {
+        if !self.impl_from_str {
+            return TokenStream::new();
+        }
+        let ident = &self.ident;
+        let rs_type = self.int128.as_ref().unwrap_or(&self.rs_type);
+
+        let value = if self.is_json {
+            quote! { sea_orm::prelude::Json::String(s.to_owned()) }
+        } else if self.is_string {
+            quote! { s.to_owned() }
+        } else {
+            quote! {
+                s.parse::<#rs_type>().map_err(|e| sea_orm::DbErr::Type(format!(
+                    "unexpected value for {} enum: {}, {}",
+                    stringify!(#ident),
+                    s,
+                    e
+                )))?
+            }
+        };
 
-        Ok(expanded_impl_active_enum)
+        quote!(
+            #[automatically_derived]
+            impl std::str::FromStr for #ident {
+                type Err = sea_orm::DbErr;
+
+                fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                    let value: <Self as sea_orm::ActiveEnum>::Value = #value;
+                    <Self as sea_orm::ActiveEnum>::try_from_value(&value)
+                }
+            }
+        )
     }
 
     fn impl_active_enum(&self) -> TokenStream {
@@ -193,9 +542,17 @@ impl ActiveEnum {
             ident,
             enum_name,
             rs_type,
+            int128,
             db_type,
             is_string,
             variants,
+            fallback,
+            case_insensitive,
+            is_json,
+            is_char,
+            default,
+            is_checked,
+            ..
         } = self;
 
         let variant_idents: Vec<syn::Ident> = variants
@@ -212,7 +569,10 @@ impl ActiveEnum {
                     let string = string_value.value();
                     quote! { #string }
                 } else if let Some(num_value) = &variant.num_value {
-                    quote! { #num_value }
+                    match int128 {
+                        Some(int128) => quote! { #int128(#num_value) },
+                        None => quote! { #num_value },
+                    }
                 } else {
                     quote_spanned! {
                         variant_span => compile_error!("Missing macro attribute, either `string_value` or `num_value` should be specified");
@@ -221,42 +581,197 @@ impl ActiveEnum {
             })
             .collect();
 
-        let val = if *is_string {
+        let val = if *case_insensitive {
+            quote! { v.to_lowercase().as_str() }
+        } else if *is_json || *is_char {
+            quote! { v }
+        } else if *is_string {
             quote! { v.as_ref() }
         } else {
             quote! { v }
         };
 
+        let mut trim_char = quote! {};
+
+        if *is_char && *is_string {
+            trim_char = quote! { let v = v.trim_end(); };
+        }
+
+        let match_values: Vec<TokenStream> = if *case_insensitive {
+            variants
+                .iter()
+                .map(|variant| {
+                    let string = variant
+                        .string_value
+                        .as_ref()
+                        .map(|string_value| string_value.value().to_lowercase());
+                    quote! { #string }
+                })
+                .collect()
+        } else {
+            variant_values.clone()
+        };
+
+        let (fallback_to_value, fallback_from_value, impl_values) = if let Some(fallback) = fallback
+        {
+            (
+                if *is_string {
+                    quote! { Self::#fallback(v) => v.as_str(), }
+                } else if let Some(int128) = int128 {
+                    quote! { Self::#fallback(v) => #int128(*v), }
+                } else {
+                    quote! { Self::#fallback(v) => *v, }
+                },
+                if int128.is_some() {
+                    quote! { _ => Ok(Self::#fallback(v.0)), }
+                } else {
+                    quote! { _ => Ok(Self::#fallback(v.to_owned())), }
+                },
+                quote! {
+                    fn values() -> Vec<Self::Value> {
+                        <Self as sea_orm::Iterable>::iter()
+                            .filter(|v| !matches!(v, Self::#fallback(_)))
+                            .map(<Self as sea_orm::ActiveEnum>::into_value)
+                            .collect()
+                    }
+
+                    fn iter_with_values() -> Vec<(Self, Self::Value)> {
+                        <Self as sea_orm::Iterable>::iter()
+                            .filter(|v| !matches!(v, Self::#fallback(_)))
+                            .map(|v| {
+                                let value = <Self as sea_orm::ActiveEnum>::to_value(&v);
+                                (v, value)
+                            })
+                            .collect()
+                    }
+                },
+            )
+        } else {
+            (
+                quote! {},
+                quote! {
+                    _ => Err(sea_orm::DbErr::Type(format!(
+                        "unexpected value for {} enum: {}",
+                        stringify!(#ident),
+                        v
+                    ))),
+                },
+                quote! {},
+            )
+        };
+
+        let variant_count = variants.len();
+
+        let default_variant = match default {
+            Some(default) => quote! {
+                fn default_variant() -> Option<Self> {
+                    Some(Self::#default)
+                }
+            },
+            None => quote! {},
+        };
+
+        let value_type = if *is_json {
+            quote! { sea_orm::prelude::Json }
+        } else if let Some(int128) = int128 {
+            quote! { #int128 }
+        } else {
+            quote! { #rs_type }
+        };
+
+        let mut to_value = quote! {
+            match self {
+                #( Self::#variant_idents => #variant_values, )*
+                #fallback_to_value
+            }
+            .to_owned()
+        };
+
+        let mut column_def_ext = if let Some(default) = default {
+            quote! { .default_value(<Self as sea_orm::ActiveEnum>::to_value(&Self::#default)) }
+        } else {
+            quote! {}
+        };
+
+        if *is_checked {
+            column_def_ext =
+                quote! { #column_def_ext.check_in(<Self as sea_orm::ActiveEnum>::values()) };
+        }
+
+        let mut from_json = quote! {};
+
+        if *is_json {
+            to_value = quote! { sea_orm::prelude::Json::String(#to_value) };
+            from_json = quote! {
+                let v = match v.as_str() {
+                    Some(v) => v,
+                    None => {
+                        return Err(sea_orm::DbErr::Type(format!(
+                            "unexpected value for {} enum: {}",
+                            stringify!(#ident),
+                            v
+                        )))
+                    }
+                };
+            };
+        }
+
+        let value_slice_type = if *is_string {
+            quote! { &'static str }
+        } else if let Some(int128) = int128 {
+            quote! { #int128 }
+        } else {
+            quote! { #rs_type }
+        };
+
         quote!(
+            #[automatically_derived]
+            impl #ident {
+                /// The number of enum variants, excluding the `fallback` variant
+                pub const VARIANT_COUNT: usize = #variant_count;
+
+                /// Get the value of all enum variants without allocation
+                pub const fn value_slice() -> &'static [#value_slice_type] {
+                    &[ #( #variant_values, )* ]
+                }
+            }
+
             #[automatically_derived]
             impl sea_orm::ActiveEnum for #ident {
-                type Value = #rs_type;
+                type Value = #value_type;
 
                 fn name() -> String {
                     #enum_name.to_owned()
                 }
 
                 fn to_value(&self) -> Self::Value {
-                    match self {
-                        #( Self::#variant_idents => #variant_values, )*
-                    }
-                    .to_owned()
+                    #to_value
                 }
 
                 fn try_from_value(v: &Self::Value) -> std::result::Result<Self, sea_orm::DbErr> {
+                    #from_json
+                    #trim_char
                     match #val {
-                        #( #variant_values => Ok(Self::#variant_idents), )*
-                        _ => Err(sea_orm::DbErr::Type(format!(
-                            "unexpected value for {} enum: {}",
-                            stringify!(#ident),
-                            v
-                        ))),
+                        #( #match_values => Ok(Self::#variant_idents), )*
+                        #fallback_from_value
                     }
                 }
 
                 fn db_type() -> sea_orm::ColumnDef {
-                    sea_orm::ColumnType::#db_type.def()
+                    sea_orm::ColumnType::#db_type.def()#column_def_ext
+                }
+
+                fn as_column_type() -> sea_orm::ColumnType {
+                    sea_orm::ColumnType::#db_type
+                }
+
+                fn num_variants() -> usize {
+                    Self::VARIANT_COUNT
                 }
+
+                #default_variant
+
+                #impl_values
             }
 
             #[automatically_derived]
@@ -287,10 +802,7 @@ impl ActiveEnum {
                 }
 
                 fn column_type() -> sea_orm::sea_query::ColumnType {
-                    <Self as sea_orm::ActiveEnum>::db_type()
-                        .get_column_type()
-                        .to_owned()
-                        .into()
+                    <Self as sea_orm::ActiveEnum>::as_column_type().into()
                 }
             }
 
@@ -304,6 +816,16 @@ impl ActiveEnum {
     }
 }
 
+fn rename_variant(rename_all: &str, variant: &str) -> Option<String> {
+    match rename_all {
+        "snake_case" => Some(variant.to_snake_case()),
+        "camelCase" => Some(variant.to_mixed_case()),
+        "kebab-case" => Some(variant.to_kebab_case()),
+        "SCREAMING_SNAKE_CASE" => Some(variant.to_shouty_snake_case()),
+        _ => None,
+    }
+}
+
 pub fn expand_derive_active_enum(input: syn::DeriveInput) -> syn::Result<TokenStream> {
     let ident_span = input.ident.span();
 
@@ -140,6 +140,14 @@ pub fn expand_derive_active_model(ident: Ident, data: Data) -> syn::Result<Token
                     #(#field: sea_orm::ActiveValue::not_set()),*
                 }
             }
+
+            fn default_values() -> Self {
+                use sea_orm::{DefaultActiveEnumValue as _, DefaultNotSetValue as _};
+
+                Self {
+                    #(#field: (&sea_orm::DefaultActiveValue::<#ty>::new()).default_active_value()),*
+                }
+            }
         }
     ))
 }
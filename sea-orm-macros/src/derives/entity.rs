@@ -1,9 +1,10 @@
 use std::iter::FromIterator;
 
+use heck::CamelCase;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
-use crate::attributes::derive_attr;
+use crate::attributes::{derive_attr, index_attr};
 
 struct DeriveEntity {
     column_ident: syn::Ident,
@@ -13,11 +14,23 @@ struct DeriveEntity {
     relation_ident: syn::Ident,
     schema_name: Option<syn::Lit>,
     table_name: Option<syn::Lit>,
+    soft_delete_column: Option<syn::Ident>,
+    version_column: Option<syn::Ident>,
+    indexes: Vec<index_attr::Index>,
 }
 
 impl DeriveEntity {
     fn new(input: syn::DeriveInput) -> Result<Self, syn::Error> {
-        let sea_attr = derive_attr::SeaOrm::try_from_attributes(&input.attrs)?.unwrap_or_default();
+        // `#[sea_orm(index(..))]` attributes are parsed apart, as they can be repeated
+        let mut indexes = Vec::new();
+        let mut attrs = Vec::new();
+        for attr in input.attrs {
+            match index_attr::Index::try_from_attribute(&attr)? {
+                Some(index) => indexes.push(index),
+                None => attrs.push(attr),
+            }
+        }
+        let sea_attr = derive_attr::SeaOrm::try_from_attributes(&attrs)?.unwrap_or_default();
 
         let ident = input.ident;
         let column_ident = sea_attr.column.unwrap_or_else(|| format_ident!("Column"));
@@ -31,6 +44,8 @@ impl DeriveEntity {
 
         let table_name = sea_attr.table_name;
         let schema_name = sea_attr.schema_name;
+        let soft_delete_column = column_variant(sea_attr.soft_delete_column, "soft_delete_column")?;
+        let version_column = column_variant(sea_attr.version_column, "version_column")?;
 
         Ok(DeriveEntity {
             column_ident,
@@ -40,6 +55,9 @@ impl DeriveEntity {
             relation_ident,
             schema_name,
             table_name,
+            soft_delete_column,
+            version_column,
+            indexes,
         })
     }
 
@@ -90,9 +108,36 @@ impl DeriveEntity {
             column_ident,
             primary_key_ident,
             relation_ident,
+            soft_delete_column,
+            version_column,
+            indexes,
             ..
         } = self;
 
+        let expanded_soft_delete_column = soft_delete_column.as_ref().map(|col| {
+            quote!(
+                fn soft_delete_column() -> Option<Self::Column> {
+                    Some(#column_ident::#col)
+                }
+            )
+        });
+        let expanded_version_column = version_column.as_ref().map(|col| {
+            quote!(
+                fn version_column() -> Option<Self::Column> {
+                    Some(#column_ident::#col)
+                }
+            )
+        });
+
+        let expanded_indexes = (!indexes.is_empty()).then(|| {
+            let indexes = indexes.iter().map(|index| index.expand(column_ident));
+            quote!(
+                fn indexes() -> Vec<sea_orm::entity::IndexDef<Self::Column>> {
+                    vec![#(#indexes),*]
+                }
+            )
+        });
+
         quote!(
             #[automatically_derived]
             impl sea_orm::entity::EntityTrait for #ident {
@@ -103,6 +148,12 @@ impl DeriveEntity {
                 type PrimaryKey = #primary_key_ident;
 
                 type Relation = #relation_ident;
+
+                #expanded_soft_delete_column
+
+                #expanded_version_column
+
+                #expanded_indexes
             }
         )
     }
@@ -134,6 +185,18 @@ impl DeriveEntity {
     }
 }
 
+/// Map the field name in a `#[sea_orm(attr = "field_name")]` attribute to its Column variant
+fn column_variant(lit: Option<syn::Lit>, attr: &str) -> syn::Result<Option<syn::Ident>> {
+    match lit {
+        Some(syn::Lit::Str(name)) => Ok(Some(format_ident!("{}", name.value().to_camel_case()))),
+        Some(lit) => Err(syn::Error::new_spanned(
+            lit,
+            format!("{} should be a string literal", attr),
+        )),
+        None => Ok(None),
+    }
+}
+
 pub fn expand_derive_entity(input: syn::DeriveInput) -> syn::Result<TokenStream> {
     Ok(DeriveEntity::new(input)?.expand())
 }
@@ -1,4 +1,7 @@
-use crate::util::{escape_rust_keyword, trim_starting_raw_identifier};
+use crate::{
+    attributes::index_attr,
+    util::{escape_rust_keyword, trim_starting_raw_identifier},
+};
 use heck::{CamelCase, SnakeCase};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, quote_spanned};
@@ -13,9 +16,16 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
     let mut table_name = None;
     let mut schema_name = quote! { None };
     let mut table_iden = false;
-    attrs.iter().for_each(|attr| {
+    let mut soft_delete_column = None;
+    let mut index_attrs = Vec::new();
+    for attr in attrs.iter() {
+        // `#[sea_orm(index(..))]` is forwarded to the Entity as is
+        if index_attr::Index::try_from_attribute(attr)?.is_some() {
+            index_attrs.push(attr);
+            continue;
+        }
         if attr.path.get_ident().map(|i| i == "sea_orm") != Some(true) {
-            return;
+            continue;
         }
 
         if let Ok(list) = attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated) {
@@ -27,6 +37,8 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                         } else if ident == "schema_name" {
                             let name = &nv.lit;
                             schema_name = quote! { Some(#name) };
+                        } else if ident == "soft_delete_column" {
+                            soft_delete_column = Some(nv.lit.clone());
                         }
                     }
                 } else if let Meta::Path(path) = meta {
@@ -38,36 +50,16 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                 }
             }
         }
-    });
-    let entity_def = table_name
-        .as_ref()
-        .map(|table_name| {
-            quote! {
-                #[derive(Copy, Clone, Default, Debug, sea_orm::prelude::DeriveEntity)]
-                pub struct Entity;
-
-                #[automatically_derived]
-                impl sea_orm::prelude::EntityName for Entity {
-                    fn schema_name(&self) -> Option<&str> {
-                        #schema_name
-                    }
-
-                    fn table_name(&self) -> &str {
-                        #table_name
-                    }
-                }
-            }
-        })
-        .unwrap_or_default();
-
+    }
     // generate Column enum and it's ColumnTrait impl
     let mut columns_enum: Punctuated<_, Comma> = Punctuated::new();
     let mut columns_trait: Punctuated<_, Comma> = Punctuated::new();
     let mut primary_keys: Punctuated<_, Comma> = Punctuated::new();
     let mut primary_key_types: Punctuated<_, Comma> = Punctuated::new();
     let mut auto_increment = true;
+    let mut version_column = None;
     if table_iden {
-        if let Some(table_name) = table_name {
+        if let Some(table_name) = &table_name {
             let table_field_name = Ident::new("Table", Span::call_site());
             columns_enum.push(quote! {
                 #[sea_orm(table_name=#table_name)]
@@ -90,6 +82,8 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     let mut nullable = false;
                     let mut default_value = None;
                     let mut default_expr = None;
+                    let mut generated = None;
+                    let mut stored = false;
                     let mut indexed = false;
                     let mut ignore = false;
                     let mut unique = false;
@@ -104,7 +98,8 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     };
                     let mut enum_name = None;
                     let mut is_primary_key = false;
-                    // search for #[sea_orm(primary_key, auto_increment = false, column_type = "String(Some(255))", default_value = "new user", default_expr = "gen_random_uuid()", column_name = "name", enum_name = "Name", nullable, indexed, unique)]
+                    let mut is_version = false;
+                    // search for #[sea_orm(primary_key, auto_increment = false, column_type = "String(Some(255))", default_value = "new user", default_expr = "gen_random_uuid()", generated = "price * quantity", stored, column_name = "name", enum_name = "Name", nullable, indexed, unique, version)]
                     for attr in field.attrs.iter() {
                         if let Some(ident) = attr.path.get_ident() {
                             if ident != "sea_orm" {
@@ -149,6 +144,15 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                                                 default_value = Some(nv.lit.to_owned());
                                             } else if name == "default_expr" {
                                                 default_expr = Some(nv.lit.to_owned());
+                                            } else if name == "generated" {
+                                                if let Lit::Str(litstr) = &nv.lit {
+                                                    generated = Some(litstr.value());
+                                                } else {
+                                                    return Err(Error::new(
+                                                        field.span(),
+                                                        format!("Invalid generated {:?}", nv.lit),
+                                                    ));
+                                                }
                                             } else if name == "column_name" {
                                                 if let Lit::Str(litstr) = &nv.lit {
                                                     column_name = Some(litstr.value());
@@ -186,6 +190,10 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                                                 indexed = true;
                                             } else if name == "unique" {
                                                 unique = true;
+                                            } else if name == "version" {
+                                                is_version = true;
+                                            } else if name == "stored" {
+                                                stored = true;
                                             }
                                         }
                                     }
@@ -201,6 +209,10 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
 
                     field_name = Ident::new(&escape_rust_keyword(field_name), Span::call_site());
 
+                    if is_version {
+                        version_column = Some(LitStr::new(&field_name.to_string(), field.span()));
+                    }
+
                     let variant_attrs = match &column_name {
                         Some(column_name) => quote! {
                             #[sea_orm(column_name = #column_name)]
@@ -300,12 +312,45 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     if let Some(default_expr) = default_expr {
                         match_row = quote! { #match_row.default_expr(#default_expr) };
                     }
+                    if let Some(generated) = generated {
+                        match_row = quote! { #match_row.generated(#generated, #stored) };
+                    }
                     columns_trait.push(match_row);
                 }
             }
         }
     }
 
+    let entity_def = table_name
+        .as_ref()
+        .map(|table_name| {
+            let soft_delete_attr = soft_delete_column
+                .as_ref()
+                .map(|col| quote! { #[sea_orm(soft_delete_column = #col)] });
+            let version_attr = version_column
+                .as_ref()
+                .map(|col| quote! { #[sea_orm(version_column = #col)] });
+            quote! {
+                #[derive(Copy, Clone, Default, Debug, sea_orm::prelude::DeriveEntity)]
+                #soft_delete_attr
+                #version_attr
+                #(#index_attrs)*
+                pub struct Entity;
+
+                #[automatically_derived]
+                impl sea_orm::prelude::EntityName for Entity {
+                    fn schema_name(&self) -> Option<&str> {
+                        #schema_name
+                    }
+
+                    fn table_name(&self) -> &str {
+                        #table_name
+                    }
+                }
+            }
+        })
+        .unwrap_or_default();
+
     let primary_key = (!primary_keys.is_empty())
         .then(|| {
             let auto_increment = auto_increment && primary_keys.len() == 1;
@@ -1,6 +1,9 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote, quote_spanned};
-use syn::{ext::IdentExt, Data, DataStruct, Field, Fields};
+use syn::{
+    ext::IdentExt, punctuated::Punctuated, token::Comma, Data, DataStruct, Fields, GenericArgument,
+    Lit, Meta, PathArguments, Type,
+};
 
 /// Method to derive a [QueryResult](sea_orm::QueryResult)
 pub fn expand_derive_from_query_result(ident: Ident, data: Data) -> syn::Result<TokenStream> {
@@ -16,27 +19,92 @@ pub fn expand_derive_from_query_result(ident: Ident, data: Data) -> syn::Result<
         }
     };
 
-    let field: Vec<Ident> = fields
-        .into_iter()
-        .map(|Field { ident, .. }| format_ident!("{}", ident.unwrap().to_string()))
-        .collect();
+    let mut field: Vec<Ident> = Vec::new();
+    let mut value: Vec<TokenStream> = Vec::new();
+    for f in fields {
+        let f_ident = format_ident!("{}", f.ident.as_ref().unwrap().to_string());
+        let name = f_ident.unraw().to_string();
 
-    let name: Vec<TokenStream> = field
-        .iter()
-        .map(|f| {
-            let s = f.unraw().to_string();
-            quote! { #s }
-        })
-        .collect();
+        // search for #[sea_orm(nested, prefix = "user_")] and #[sea_orm(skip)]
+        let mut nested = false;
+        let mut skip = false;
+        let mut prefix = None;
+        for attr in f.attrs.iter() {
+            if attr.path.get_ident().map(|i| i == "sea_orm") != Some(true) {
+                continue;
+            }
+            let list = attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)?;
+            for meta in list.iter() {
+                match meta {
+                    Meta::Path(path) if path.is_ident("nested") => nested = true,
+                    Meta::Path(path) if path.is_ident("skip") => skip = true,
+                    Meta::NameValue(nv) if nv.path.is_ident("prefix") => match &nv.lit {
+                        Lit::Str(litstr) => prefix = Some(litstr.value()),
+                        lit => {
+                            return Err(syn::Error::new_spanned(
+                                lit,
+                                "prefix should be a string literal",
+                            ))
+                        }
+                    },
+                    _ => {}
+                }
+            }
+        }
+
+        let expr = if skip {
+            quote! { std::default::Default::default() }
+        } else if nested {
+            let prefix = prefix.unwrap_or_else(|| format!("{}_", name));
+            let ty = &f.ty;
+            match option_inner_type(ty) {
+                Some(inner) => quote! {
+                    <#inner as sea_orm::FromQueryResult>::from_query_result_optional(
+                        row,
+                        &format!("{}{}", pre, #prefix),
+                    )?
+                },
+                None => quote! {
+                    <#ty as sea_orm::FromQueryResult>::from_query_result(
+                        row,
+                        &format!("{}{}", pre, #prefix),
+                    )?
+                },
+            }
+        } else {
+            quote! { row.try_get(pre, #name)? }
+        };
+
+        field.push(f_ident);
+        value.push(expr);
+    }
 
     Ok(quote!(
         #[automatically_derived]
         impl sea_orm::FromQueryResult for #ident {
             fn from_query_result(row: &sea_orm::QueryResult, pre: &str) -> std::result::Result<Self, sea_orm::DbErr> {
                 Ok(Self {
-                    #(#field: row.try_get(pre, #name)?),*
+                    #(#field: #value),*
                 })
             }
         }
     ))
 }
+
+/// Get `T` of an `Option<T>` type
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let segment = match ty {
+        Type::Path(type_path) => type_path.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != "Option" {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => match args.args.first()? {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -72,7 +72,7 @@ impl IntoActiveModel {
             let field_ident = field.ident.as_ref().unwrap();
 
             quote!(
-                sea_orm::IntoActiveValue::<_>::into_active_value(self.#field_ident).into()
+                sea_orm::IntoActiveValue::<_>::into_active_value(self.#field_ident)
             )
         });
 
@@ -26,7 +26,38 @@ impl DeriveMigrationName {
     }
 }
 
+struct DeriveCoreMigrationName {
+    ident: syn::Ident,
+}
+
+impl DeriveCoreMigrationName {
+    fn new(input: syn::DeriveInput) -> Self {
+        let ident = input.ident;
+
+        DeriveCoreMigrationName { ident }
+    }
+
+    fn expand(&self) -> TokenStream {
+        let ident = &self.ident;
+
+        quote!(
+            #[automatically_derived]
+            impl sea_orm::migration::MigrationName for #ident {
+                fn name(&self) -> &str {
+                    let path = module_path!();
+                    path.rsplit("::").next().unwrap_or(path)
+                }
+            }
+        )
+    }
+}
+
 /// Method to derive a MigrationName
 pub fn expand_derive_migration_name(input: syn::DeriveInput) -> syn::Result<TokenStream> {
     Ok(DeriveMigrationName::new(input).expand())
 }
+
+/// Method to derive a MigrationName of the core migrations, named after the module
+pub fn expand_derive_core_migration_name(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    Ok(DeriveCoreMigrationName::new(input).expand())
+}
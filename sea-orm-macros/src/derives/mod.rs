@@ -11,6 +11,7 @@ mod model;
 mod primary_key;
 mod relation;
 mod try_getable_from_json;
+mod value_type;
 
 pub use active_enum::*;
 pub use active_model::*;
@@ -25,3 +26,4 @@ pub use model::*;
 pub use primary_key::*;
 pub use relation::*;
 pub use try_getable_from_json::*;
+pub use value_type::*;
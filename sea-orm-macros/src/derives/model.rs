@@ -1,5 +1,5 @@
 use crate::{
-    attributes::derive_attr,
+    attributes::{derive_attr, index_attr},
     util::{escape_rust_keyword, field_not_ignored, trim_starting_raw_identifier},
 };
 use heck::CamelCase;
@@ -31,7 +31,13 @@ impl DeriveModel {
             _ => return Err(Error::InputNotStruct),
         };
 
-        let sea_attr = derive_attr::SeaOrm::try_from_attributes(&input.attrs)
+        // `#[sea_orm(index(..))]` attributes belong to the Entity
+        let attrs: Vec<_> = input
+            .attrs
+            .into_iter()
+            .filter(|attr| !index_attr::Index::is_index_attribute(attr))
+            .collect();
+        let sea_attr = derive_attr::SeaOrm::try_from_attributes(&attrs)
             .map_err(Error::Syn)?
             .unwrap_or_default();
 
@@ -0,0 +1,167 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{punctuated::Punctuated, token::Comma, Data, DataStruct, Fields, Lit, Meta};
+
+struct ValueType {
+    ident: syn::Ident,
+    inner: syn::Type,
+    column_type: Option<TokenStream>,
+    array_type: Option<TokenStream>,
+}
+
+impl ValueType {
+    fn new(input: syn::DeriveInput) -> syn::Result<Self> {
+        let inner = match input.data {
+            Data::Struct(DataStruct {
+                fields: Fields::Unnamed(fields),
+                ..
+            }) if fields.unnamed.len() == 1 => fields.unnamed.into_iter().next().unwrap().ty,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    input.ident,
+                    "you can only derive DeriveValueType on tuple structs with a single field",
+                ))
+            }
+        };
+
+        let mut column_type = None;
+        let mut array_type = None;
+        for attr in input.attrs.iter() {
+            if attr.path.get_ident().map(|i| i == "sea_orm") != Some(true) {
+                continue;
+            }
+            let list = attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)?;
+            for meta in list.iter() {
+                let nv = match meta {
+                    Meta::NameValue(nv) => nv,
+                    _ => continue,
+                };
+                let litstr = match &nv.lit {
+                    Lit::Str(litstr) => litstr,
+                    lit => {
+                        return Err(syn::Error::new_spanned(
+                            lit,
+                            "the value should be a string literal",
+                        ))
+                    }
+                };
+                if nv.path.is_ident("column_type") {
+                    column_type = Some(litstr.parse::<TokenStream>()?);
+                } else if nv.path.is_ident("array_type") {
+                    if cfg!(not(feature = "postgres-array")) {
+                        return Err(syn::Error::new_spanned(
+                            nv,
+                            "array_type requires the `postgres-array` feature of sea-orm",
+                        ));
+                    }
+                    let element = match litstr.value().as_str() {
+                        "Bool" => quote! { bool },
+                        "SmallInt" => quote! { i16 },
+                        "Int" => quote! { i32 },
+                        "BigInt" => quote! { i64 },
+                        "Float" => quote! { f32 },
+                        "Double" => quote! { f64 },
+                        "String" => quote! { String },
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                litstr,
+                                "array_type should be one of `Bool`, `SmallInt`, `Int`, `BigInt`, `Float`, `Double` or `String`",
+                            ))
+                        }
+                    };
+                    array_type = Some(element);
+                }
+            }
+        }
+
+        Ok(Self {
+            ident: input.ident,
+            inner,
+            column_type,
+            array_type,
+        })
+    }
+
+    fn expand(&self) -> TokenStream {
+        let Self {
+            ident,
+            inner,
+            column_type,
+            array_type,
+        } = self;
+
+        let column_type = match column_type {
+            Some(column_type) => quote! {
+                sea_orm::ColumnType::#column_type.into()
+            },
+            None => quote! {
+                <#inner as sea_orm::sea_query::ValueType>::column_type()
+            },
+        };
+
+        let impl_try_getable_array = array_type.as_ref().map(|element| {
+            quote! {
+                #[automatically_derived]
+                impl sea_orm::TryGetableArray for #ident {
+                    type Value = #element;
+
+                    fn try_from_array_value(v: Self::Value) -> std::result::Result<Self, sea_orm::TryGetError> {
+                        <#inner as std::convert::TryFrom<#element>>::try_from(v)
+                            .map(Self)
+                            .map_err(|e| sea_orm::TryGetError::DbErr(sea_orm::DbErr::Type(format!(
+                                "unexpected value for {}: {}",
+                                stringify!(#ident),
+                                e
+                            ))))
+                    }
+                }
+            }
+        });
+
+        quote!(
+            #[automatically_derived]
+            impl std::convert::From<#ident> for sea_orm::Value {
+                fn from(source: #ident) -> Self {
+                    source.0.into()
+                }
+            }
+
+            #[automatically_derived]
+            impl sea_orm::TryGetable for #ident {
+                fn try_get(res: &sea_orm::QueryResult, pre: &str, col: &str) -> std::result::Result<Self, sea_orm::TryGetError> {
+                    <#inner as sea_orm::TryGetable>::try_get(res, pre, col).map(Self)
+                }
+            }
+
+            #[automatically_derived]
+            impl sea_orm::sea_query::ValueType for #ident {
+                fn try_from(v: sea_orm::Value) -> std::result::Result<Self, sea_orm::sea_query::ValueTypeErr> {
+                    <#inner as sea_orm::sea_query::ValueType>::try_from(v).map(Self)
+                }
+
+                fn type_name() -> String {
+                    stringify!(#ident).to_owned()
+                }
+
+                fn column_type() -> sea_orm::sea_query::ColumnType {
+                    #column_type
+                }
+            }
+
+            #[automatically_derived]
+            impl sea_orm::sea_query::Nullable for #ident {
+                fn null() -> sea_orm::Value {
+                    <#inner as sea_orm::sea_query::Nullable>::null()
+                }
+            }
+
+            #impl_try_getable_array
+        )
+    }
+}
+
+/// Method to derive `Into<Value>`, [ValueType](sea_orm::sea_query::ValueType), [TryGetable](sea_orm::TryGetable)
+/// and [Nullable](sea_orm::sea_query::Nullable) for a newtype, by delegating to its inner type
+pub fn expand_derive_value_type(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    Ok(ValueType::new(input)?.expand())
+}
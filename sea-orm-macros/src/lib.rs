@@ -411,6 +411,11 @@ pub fn derive_active_model(input: TokenStream) -> TokenStream {
 }
 
 /// Derive into an active model
+///
+/// Each field is `Set` on the ActiveModel field of the same name, other fields are `NotSet`.
+/// An `Option<T>` field is `Set` only when it is `Some`, which suits partial updates,
+/// e.g. `name: Option<String>` for a `name: String` column or
+/// `cake_id: Option<Option<i32>>` for a nullable `cake_id: Option<i32>` column.
 #[proc_macro_derive(DeriveIntoActiveModel, attributes(sea_orm))]
 pub fn derive_into_active_model(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -498,26 +503,68 @@ pub fn derive_active_model_behavior(input: TokenStream) -> TokenStream {
 ///
 /// This derive macros can only be used on enums.
 ///
+/// # Value Slice
+///
+/// Besides the `ActiveEnum` implementation, a `const fn value_slice()` is generated on the enum
+/// returning the value of all enum variants as a static slice, without allocation.
+/// The element type is `&'static str` for `rs_type = "String"` and `rs_type` otherwise.
+///
+/// A `const VARIANT_COUNT: usize` holding the number of enum variants is generated as well,
+/// which is also returned by `ActiveEnum::num_variants()`.
+///
 /// # Macro Attributes
 ///
 /// All macro attributes listed below have to be annotated in the form of `#[sea_orm(attr = value)]`.
 ///
 /// - For enum
 ///     - `rs_type`: Define `ActiveEnum::Value`
-///         - Possible values: `String`, `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`
+///         - Possible values: `String`, `i8`, `i16`, `i32`, `i64`, `i128`, `u8`, `u16`, `u32`, `u64`, `u128`
+///         - Note that `i128` and `u128` are stored as their decimal string, i.e. `ActiveEnum::Value` is `sea_orm::I128` or
+///           `sea_orm::U128` and `db_type` has to be `String(..)`, `Char(..)` or `Text`
 ///         - Note that value has to be passed as string, i.e. `rs_type = "i8"`
 ///     - `db_type`: Define `ColumnType` returned by `ActiveEnum::db_type()`
 ///         - Possible values: all available enum variants of `ColumnType`, e.g. `String(None)`, `String(Some(1))`, `Integer`
 ///         - Note that value has to be passed as string, i.e. `db_type = "Integer"`
+///         - Use `db_type = "Enum"` to map onto a native database enum, e.g. `CREATE TYPE ... AS ENUM` in Postgres,
+///           named by `enum_name` with the variant set taken from `ActiveEnum::values()`; this requires `string_value` variants
+///           and the type can be created by `Schema::create_enum_from_active_enum`
+///         - Use `db_type = "StringChecked"` to store the value in a `String(None)` column restricted by
+///           `CHECK ("column" IN (..))` on table creation in Postgres and SQLite, with the values taken from `ActiveEnum::values()`;
+///           the constraint is skipped in MySQL and it is only emitted for columns defined by `ActiveEnum::db_type()`
+///         - For `db_type = "Char(..)"`, trailing padding spaces returned for fixed-width columns are trimmed before matching
+///         - Use `db_type = "Json"` or `db_type = "JsonBinary"` to store the `string_value` as a JSON string,
+///           `ActiveEnum::Value` will then be `Json` instead of `rs_type`; this requires `string_value` variants
 ///     - `enum_name`: Define `String` returned by `ActiveEnum::name()`
 ///         - This attribute is optional with default value being the name of enum in camel-case
 ///         - Note that value has to be passed as string, i.e. `db_type = "Integer"`
+///     - `rename_all`: Derive `string_value` of every variant from its identifier
+///         - Possible values: `snake_case`, `camelCase`, `kebab-case`, `SCREAMING_SNAKE_CASE`
+///         - Variants with an explicit `string_value` keep their own value
+///         - Cannot be combined with `num_value` variants
+///     - `impl_display`: Implement `std::fmt::Display` using `ActiveEnum::to_value()`
+///         - This attribute is optional and can only be used on enums with `string_value` variants
+///     - `impl_from_str`: Implement `std::str::FromStr` using `ActiveEnum::try_from_value()`, with `DbErr` as error type
+///         - This attribute is optional, for enums with `num_value` variants the string is parsed as `rs_type` first
+///     - `serde_db_value`: Implement `serde::Serialize` and `serde::Deserialize` using the value of enum variants
+///         - This attribute is optional and requires the `with-json` feature of `sea-orm`
+///         - It cannot be combined with `#[derive(Serialize, Deserialize)]` on the same enum
+///     - `case_insensitive`: Match the value case-insensitively in `ActiveEnum::try_from_value()`
+///         - This attribute is optional and can only be used on enums with `string_value` variants
+///         - `ActiveEnum::to_value()` still returns the `string_value` as specified
 ///
 /// - For enum variant
 ///     - `string_value` or `num_value`:
 ///         - For `string_value`, value should be passed as string, i.e. `string_value = "A"`
 ///         - For `num_value`, value should be passed as integer, i.e. `num_value = 1` or `num_value = 1i32`
 ///         - Note that only one of it can be specified, and all variants of an enum have to annotate with the same `*_value` macro attribute
+///     - `default`: Implement `Default` for the enum returning this variant
+///         - Only one variant can be marked as `default`
+///         - The value of this variant is also set as the default value of `ActiveEnum::db_type()`,
+///           and `ActiveModel::new()` sets a field of this enum, or an `Option` of it, to this variant
+///     - `fallback`: Capture any unmatched value in this variant instead of returning an error
+///         - Only one variant can be marked as `fallback`, i.e. `#[sea_orm(fallback)] Unknown(String)`
+///         - The variant should be a tuple variant with a single field of type `rs_type`
+///         - The variant is excluded from `ActiveEnum::values()`, annotate it with `#[strum(disabled)]` to exclude it from `EnumIter` as well
 #[proc_macro_derive(DeriveActiveEnum, attributes(sea_orm))]
 pub fn derive_active_enum(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -540,7 +587,32 @@ pub fn derive_active_enum(input: TokenStream) -> TokenStream {
 ///     num_of_fruits: i32,
 /// }
 /// ```
-#[proc_macro_derive(FromQueryResult)]
+///
+/// ### Attributes
+///
+/// - `#[sea_orm(nested)]`: the field is itself a [FromQueryResult] read from columns prefixed
+///   by the field name and an underscore, e.g. `cake_id` and `cake_name` for a field `cake`.
+///   Use `#[sea_orm(nested, prefix = "c_")]` to customize the prefix. A nested `Option<T>`
+///   is `None` when its columns cannot be read, e.g. they are `NULL` after a `LEFT JOIN`.
+/// - `#[sea_orm(skip)]`: the field is not read from the query result and set to `Default::default()`.
+///
+/// Any other field is read with `TryGetable`. This includes enums deriving `DeriveActiveEnum`
+/// and `Option`s of them, whose values are converted with `ActiveEnum::try_from_value`.
+///
+/// ```
+/// use sea_orm::{entity::prelude::*, tests_cfg::{cake, fruit}, FromQueryResult};
+///
+/// #[derive(Debug, FromQueryResult)]
+/// struct CakeWithFruit {
+///     #[sea_orm(nested)]
+///     cake: cake::Model,
+///     #[sea_orm(nested, prefix = "f_")]
+///     fruit: Option<fruit::Model>,
+///     #[sea_orm(skip)]
+///     note: String,
+/// }
+/// ```
+#[proc_macro_derive(FromQueryResult, attributes(sea_orm))]
 pub fn derive_from_query_result(input: TokenStream) -> TokenStream {
     let DeriveInput { ident, data, .. } = parse_macro_input!(input);
 
@@ -609,6 +681,36 @@ pub fn derive_migration_name(input: TokenStream) -> TokenStream {
         .into()
 }
 
+/// The DeriveCoreMigrationName derive macro will implement `sea_orm::migration::MigrationName` for a
+/// migration of `sea_orm::migration`, without depending on `sea_orm_migration`.
+///
+/// ### Usage
+///
+/// ```ignore
+/// mod m20220120_000001_create_post_table {
+///     #[derive(DeriveCoreMigrationName)]
+///     pub struct Migration;
+/// }
+/// ```
+///
+/// The derive macro above will provide following implementation,
+/// named after the module defining the migration.
+///
+/// ```ignore
+/// impl MigrationName for Migration {
+///     fn name(&self) -> &str {
+///         "m20220120_000001_create_post_table"
+///     }
+/// }
+/// ```
+#[proc_macro_derive(DeriveCoreMigrationName)]
+pub fn derive_core_migration_name(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derives::expand_derive_core_migration_name(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
 #[proc_macro_derive(FromJsonQueryResult)]
 pub fn derive_from_json_query_result(input: TokenStream) -> TokenStream {
     let DeriveInput { ident, .. } = parse_macro_input!(input);
@@ -619,6 +721,54 @@ pub fn derive_from_json_query_result(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Use a newtype as the type of a Model field, by delegating its conversion
+/// from and into database values to the wrapped type.
+///
+/// ### Usage
+///
+/// ```
+/// use sea_orm::entity::prelude::*;
+///
+/// /// An amount of money, stored as a number of cents
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq, DeriveValueType)]
+/// #[sea_orm(column_type = "BigInteger")]
+/// pub struct Money(pub i64);
+///
+/// #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+/// #[sea_orm(table_name = "invoice")]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: i32,
+///     pub amount: Money,
+/// }
+///
+/// # #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+/// # pub enum Relation {}
+/// #
+/// # impl ActiveModelBehavior for ActiveModel {}
+/// ```
+///
+/// The derive macro implements `Into<Value>`, [ValueType](sea_orm::sea_query::ValueType),
+/// [TryGetable](sea_orm::TryGetable) and [Nullable](sea_orm::sea_query::Nullable).
+/// It can only be used on tuple structs with a single field.
+///
+/// ### Attributes
+///
+/// - `column_type`: The column type of the newtype, e.g. `column_type = "Integer"`
+///     - This attribute is optional and defaults to the column type of the wrapped type
+/// - `array_type`: The type of the elements of a Postgres array of the newtype, e.g. `array_type = "Int"`
+///     - This attribute is optional and requires the `postgres-array` feature of `sea-orm`
+///     - It implements [TryGetableArray](sea_orm::TryGetableArray) to read a `Vec` of the newtype,
+///       converting each element into the wrapped type with `TryFrom`
+///     - Possible values: `Bool`, `SmallInt`, `Int`, `BigInt`, `Float`, `Double`, `String`
+#[proc_macro_derive(DeriveValueType, attributes(sea_orm))]
+pub fn derive_value_type(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derives::expand_derive_value_type(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
 #[doc(hidden)]
 #[proc_macro_attribute]
 pub fn test(_: TokenStream, input: TokenStream) -> TokenStream {
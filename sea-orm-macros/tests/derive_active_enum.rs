@@ -0,0 +1,5 @@
+#[test]
+fn derive_active_enum_compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/active_enum_*.rs");
+}
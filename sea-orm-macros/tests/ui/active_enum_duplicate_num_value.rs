@@ -0,0 +1,14 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "i32", db_type = "Integer")]
+pub enum Color {
+    #[sea_orm(num_value = 0)]
+    Black,
+    #[sea_orm(num_value = 1)]
+    White,
+    #[sea_orm(num_value = 1i32)]
+    Grey,
+}
+
+fn main() {}
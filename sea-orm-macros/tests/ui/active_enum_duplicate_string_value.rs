@@ -0,0 +1,12 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "String(Some(1))")]
+pub enum Category {
+    #[sea_orm(string_value = "B")]
+    Big,
+    #[sea_orm(string_value = "B")]
+    Small,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "i128", db_type = "BigInteger")]
+pub enum Flags {
+    #[sea_orm(num_value = 0)]
+    None,
+    #[sea_orm(num_value = 1267650600228229401496703205376)]
+    Wide,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "u128", db_type = "String(Some(40))")]
+pub enum Flags {
+    #[sea_orm(num_value = 0)]
+    None,
+    #[sea_orm(num_value = -1)]
+    Negative,
+}
+
+fn main() {}
@@ -11,12 +11,9 @@ pub use migrator::*;
 pub use async_trait;
 pub use sea_orm;
 pub use sea_orm::sea_query;
+pub use sea_orm::migration::MigrationName;
 pub use sea_orm::DbErr;
 
-pub trait MigrationName {
-    fn name(&self) -> &str;
-}
-
 /// The migration definition
 #[async_trait::async_trait]
 pub trait MigrationTrait: MigrationName + Send + Sync {
@@ -1,8 +1,8 @@
 use sea_orm::sea_query::{
     extension::postgres::{TypeAlterStatement, TypeCreateStatement, TypeDropStatement},
-    ForeignKeyCreateStatement, ForeignKeyDropStatement, IndexCreateStatement, IndexDropStatement,
-    TableAlterStatement, TableCreateStatement, TableDropStatement, TableRenameStatement,
-    TableTruncateStatement,
+    Alias, BinOper, Expr, ForeignKeyCreateStatement, ForeignKeyDropStatement, IndexCreateStatement,
+    IndexDropStatement, Query, SelectStatement, TableAlterStatement, TableCreateStatement,
+    TableDropStatement, TableRenameStatement, TableTruncateStatement,
 };
 use sea_orm::{ConnectionTrait, DbBackend, DbConn, DbErr, StatementBuilder};
 use sea_schema::{mysql::MySql, postgres::Postgres, probe::SchemaProbe, sqlite::Sqlite};
@@ -130,4 +130,61 @@ impl<'c> SchemaManager<'c> {
 
         res.try_get("", "has_column")
     }
+
+    pub async fn has_index<T, I>(&self, table: T, index: I) -> Result<bool, DbErr>
+    where
+        T: AsRef<str>,
+        I: AsRef<str>,
+    {
+        let stmt = has_index(self.conn.get_database_backend(), table, index);
+
+        let builder = self.conn.get_database_backend();
+        let res = self
+            .conn
+            .query_one(builder.build(&stmt))
+            .await?
+            .ok_or_else(|| DbErr::Custom("Failed to check index exists".to_owned()))?;
+
+        res.try_get("", "has_index")
+    }
+}
+
+/// Query whether the `index` of `table` exists in the current schema
+fn has_index<T, I>(backend: DbBackend, table: T, index: I) -> SelectStatement
+where
+    T: AsRef<str>,
+    I: AsRef<str>,
+{
+    let (catalog, schema_col, schema, table_col, index_col) = match backend {
+        DbBackend::MySql => (
+            (Alias::new("information_schema"), Alias::new("statistics")),
+            "table_schema",
+            "DATABASE()",
+            "table_name",
+            "index_name",
+        ),
+        DbBackend::Postgres => (
+            (Alias::new("pg_catalog"), Alias::new("pg_indexes")),
+            "schemaname",
+            "CURRENT_SCHEMA()",
+            "tablename",
+            "indexname",
+        ),
+        DbBackend::Sqlite => {
+            return Query::select()
+                .expr_as(Expr::cust("COUNT(*) > 0"), Alias::new("has_index"))
+                .from(Alias::new("sqlite_master"))
+                .and_where(Expr::col(Alias::new("type")).eq("index"))
+                .and_where(Expr::col(Alias::new("tbl_name")).eq(table.as_ref()))
+                .and_where(Expr::col(Alias::new("name")).eq(index.as_ref()))
+                .to_owned();
+        }
+    };
+    Query::select()
+        .expr_as(Expr::cust("COUNT(*) > 0"), Alias::new("has_index"))
+        .from(catalog)
+        .and_where(Expr::col(Alias::new(schema_col)).binary(BinOper::Equal, Expr::cust(schema)))
+        .and_where(Expr::col(Alias::new(table_col)).eq(table.as_ref()))
+        .and_where(Expr::col(Alias::new(index_col)).eq(index.as_ref()))
+        .to_owned()
 }
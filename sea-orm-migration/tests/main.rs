@@ -93,6 +93,30 @@ async fn main() -> Result<(), DbErr> {
 
     assert!(manager.has_column("cake", "name").await?);
     assert!(manager.has_column("fruit", "cake_id").await?);
+    assert!(!manager.has_column("cake", "cake_id").await?);
+    assert!(!manager.has_column("bakery", "name").await?);
+
+    assert!(!manager.has_index("cake", "idx-cake-name").await?);
+    manager
+        .create_index(
+            Index::create()
+                .name("idx-cake-name")
+                .table(Alias::new("cake"))
+                .col(Alias::new("name"))
+                .to_owned(),
+        )
+        .await?;
+    assert!(manager.has_index("cake", "idx-cake-name").await?);
+    assert!(!manager.has_index("fruit", "idx-cake-name").await?);
+    manager
+        .drop_index(
+            Index::drop()
+                .name("idx-cake-name")
+                .table(Alias::new("cake"))
+                .to_owned(),
+        )
+        .await?;
+    assert!(!manager.has_index("cake", "idx-cake-name").await?);
 
     println!("\nMigrator::down");
     Migrator::down(db, None).await?;
@@ -1,5 +1,6 @@
 use crate::{
-    DatabaseTransaction, DbBackend, DbErr, ExecResult, QueryResult, Statement, TransactionError,
+    AccessMode, DatabaseTransaction, DbBackend, DbErr, ExecResult, IsolationLevel, QueryResult,
+    Statement, TransactionError,
 };
 use futures::Stream;
 use std::{future::Future, pin::Pin};
@@ -15,6 +16,19 @@ pub trait ConnectionTrait: Sync {
     /// Execute a [Statement]
     async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr>;
 
+    /// Execute an unprepared SQL statement, which can hold multiple `;` separated statements,
+    /// e.g. a DDL script. No values are bound, so never build it from user input
+    ///
+    /// Defaults to [ConnectionTrait::execute] with the SQL as a [Statement], which some
+    /// backends only accept as a single statement.
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        self.execute(Statement::from_string(
+            self.get_database_backend(),
+            sql.to_owned(),
+        ))
+        .await
+    }
+
     /// Execute a [Statement] and return a query
     async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr>;
 
@@ -50,8 +64,27 @@ pub trait StreamTrait<'a>: Send + Sync {
 pub trait TransactionTrait {
     /// Execute SQL `BEGIN` transaction.
     /// Returns a Transaction that can be committed or rolled back
+    ///
+    /// Beginning a transaction within a [DatabaseTransaction] creates a `SAVEPOINT` instead,
+    /// committing the nested transaction releases the savepoint and rolling it back only
+    /// reverts the changes made since the savepoint.
     async fn begin(&self) -> Result<DatabaseTransaction, DbErr>;
 
+    /// Execute SQL `BEGIN` transaction with the given isolation level and access mode.
+    /// Returns a Transaction that can be committed or rolled back
+    ///
+    /// The database default is used for the ones which are [Option::None].
+    /// SQLite does not support them and ignores both.
+    ///
+    /// Defaults to [TransactionTrait::begin], which ignores both as well.
+    async fn begin_with_config(
+        &self,
+        _isolation_level: Option<IsolationLevel>,
+        _access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        self.begin().await
+    }
+
     /// Execute the function inside a transaction.
     /// If the function returns an error, the transaction will be rolled back. If it does not return an error, the transaction will be committed.
     async fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
@@ -1,6 +1,7 @@
 use crate::{
-    error::*, ConnectionTrait, DatabaseTransaction, ExecResult, QueryResult, Statement,
-    StatementBuilder, StreamTrait, TransactionError, TransactionTrait,
+    error::*, AccessMode, ConnectionTrait, DatabaseTransaction, ExecResult, IsolationLevel,
+    QueryResult, RetryPolicy, Statement, StatementBuilder, StreamTrait, TransactionError,
+    TransactionTrait,
 };
 use sea_query::{MysqlQueryBuilder, PostgresQueryBuilder, QueryBuilder, SqliteQueryBuilder};
 use std::{future::Future, pin::Pin};
@@ -50,6 +51,33 @@ pub enum DatabaseBackend {
 
 /// The same as [DatabaseBackend] just shorter :)
 pub type DbBackend = DatabaseBackend;
+
+/// The utilization of a connection pool, see [DatabaseConnection::pool_status]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PoolStatus {
+    /// The number of connections opened by the pool, idle or in use
+    pub size: u32,
+    /// The number of idle connections
+    pub idle: u32,
+    /// The number of connections in use
+    pub in_use: u32,
+}
+
+#[cfg(feature = "sqlx-dep")]
+impl<DB> From<&sqlx::Pool<DB>> for PoolStatus
+where
+    DB: sqlx::Database,
+{
+    fn from(pool: &sqlx::Pool<DB>) -> Self {
+        let size = pool.size();
+        let idle = pool.num_idle() as u32;
+        Self {
+            size,
+            idle,
+            in_use: size.saturating_sub(idle),
+        }
+    }
+}
 #[derive(Debug)]
 pub(crate) enum InnerConnection {
     #[cfg(feature = "sqlx-mysql")]
@@ -120,6 +148,26 @@ impl ConnectionTrait for DatabaseConnection {
         }
     }
 
+    #[instrument(level = "trace")]
+    #[allow(unused_variables)]
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnection::SqlxMySqlPoolConnection(conn) => conn.execute_unprepared(sql).await,
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => {
+                conn.execute_unprepared(sql).await
+            }
+            #[cfg(feature = "sqlx-sqlite")]
+            DatabaseConnection::SqlxSqlitePoolConnection(conn) => {
+                conn.execute_unprepared(sql).await
+            }
+            #[cfg(feature = "mock")]
+            DatabaseConnection::MockDatabaseConnection(conn) => conn.execute_unprepared(sql),
+            DatabaseConnection::Disconnected => Err(DbErr::Conn("Disconnected".to_owned())),
+        }
+    }
+
     #[instrument(level = "trace")]
     #[allow(unused_variables)]
     async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
@@ -199,7 +247,40 @@ impl TransactionTrait for DatabaseConnection {
             DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.begin().await,
             #[cfg(feature = "mock")]
             DatabaseConnection::MockDatabaseConnection(conn) => {
-                DatabaseTransaction::new_mock(Arc::clone(conn), None).await
+                DatabaseTransaction::new_mock(Arc::clone(conn), None, None, None).await
+            }
+            DatabaseConnection::Disconnected => panic!("Disconnected"),
+        }
+    }
+
+    #[instrument(level = "trace")]
+    async fn begin_with_config(
+        &self,
+        _isolation_level: Option<IsolationLevel>,
+        _access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnection::SqlxMySqlPoolConnection(conn) => {
+                conn.begin_with_config(_isolation_level, _access_mode).await
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => {
+                conn.begin_with_config(_isolation_level, _access_mode).await
+            }
+            #[cfg(feature = "sqlx-sqlite")]
+            DatabaseConnection::SqlxSqlitePoolConnection(conn) => {
+                conn.begin_with_config(_isolation_level, _access_mode).await
+            }
+            #[cfg(feature = "mock")]
+            DatabaseConnection::MockDatabaseConnection(conn) => {
+                DatabaseTransaction::new_mock(
+                    Arc::clone(conn),
+                    None,
+                    _isolation_level,
+                    _access_mode,
+                )
+                .await
             }
             DatabaseConnection::Disconnected => panic!("Disconnected"),
         }
@@ -228,7 +309,7 @@ impl TransactionTrait for DatabaseConnection {
             DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.transaction(_callback).await,
             #[cfg(feature = "mock")]
             DatabaseConnection::MockDatabaseConnection(conn) => {
-                let transaction = DatabaseTransaction::new_mock(Arc::clone(conn), None)
+                let transaction = DatabaseTransaction::new_mock(Arc::clone(conn), None, None, None)
                     .await
                     .map_err(TransactionError::Connection)?;
                 transaction.run(_callback).await
@@ -253,9 +334,150 @@ impl DatabaseConnection {
         let mut mocker = self.as_mock_connection().get_mocker_mutex().lock().unwrap();
         mocker.drain_transaction_log()
     }
+
+    /// Check the statements issued so far against the expectations of the [crate::MockDatabase],
+    /// panicking with a report of every statement up to the first mismatch
+    pub fn verify_expectations(&self) {
+        self.as_mock_connection()
+            .get_mocker_mutex()
+            .lock()
+            .unwrap()
+            .verify_expectations()
+    }
 }
 
 impl DatabaseConnection {
+    /// Checks if a connection to the database is still valid.
+    #[instrument(level = "trace")]
+    pub async fn ping(&self) -> Result<(), DbErr> {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnection::SqlxMySqlPoolConnection(conn) => conn.ping().await,
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.ping().await,
+            #[cfg(feature = "sqlx-sqlite")]
+            DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.ping().await,
+            #[cfg(feature = "mock")]
+            DatabaseConnection::MockDatabaseConnection(conn) => conn.ping(),
+            DatabaseConnection::Disconnected => Err(DbErr::Conn("Disconnected".to_owned())),
+        }
+    }
+
+    /// Get the number of open, idle and in use connections of the pool.
+    /// Returns [None] if the connection is not backed by a pool, i.e. a mock connection.
+    pub fn pool_status(&self) -> Option<PoolStatus> {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnection::SqlxMySqlPoolConnection(conn) => Some((&conn.pool).into()),
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => Some((&conn.pool).into()),
+            #[cfg(feature = "sqlx-sqlite")]
+            DatabaseConnection::SqlxSqlitePoolConnection(conn) => Some((&conn.pool).into()),
+            _ => None,
+        }
+    }
+
+    /// Close the connection pool, waiting for the connections in use to be returned.
+    /// Queries on the other handles of the pool fail with [DbErr::Conn] afterwards.
+    #[instrument(level = "trace")]
+    pub async fn close(self) -> Result<(), DbErr> {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnection::SqlxMySqlPoolConnection(conn) => conn.close().await,
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.close().await,
+            #[cfg(feature = "sqlx-sqlite")]
+            DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.close().await,
+            #[cfg(feature = "mock")]
+            DatabaseConnection::MockDatabaseConnection(_) => {}
+            DatabaseConnection::Disconnected => return Err(DbErr::Conn("Disconnected".to_owned())),
+        }
+        Ok(())
+    }
+
+    /// Execute a [Statement] and return each row as a JSON object keyed by column name.
+    /// The JSON type of each value is derived from the column type reported by the driver.
+    #[cfg(feature = "with-json")]
+    #[instrument(level = "trace")]
+    pub async fn query_all_json(&self, stmt: Statement) -> Result<Vec<crate::JsonValue>, DbErr> {
+        use crate::FromQueryResult;
+
+        self.query_all(stmt)
+            .await?
+            .iter()
+            .map(|row| crate::JsonValue::from_query_result(row, ""))
+            .collect()
+    }
+
+    /// Execute the function inside a transaction, like [TransactionTrait::transaction].
+    /// If the transaction fails with a transient error, it is retried according to the [RetryPolicy].
+    /// Other errors are returned immediately. The function is called with a fresh transaction
+    /// for every attempt.
+    ///
+    /// The transient errors are a serialization failure or a deadlock, which the database rolls
+    /// back, and a connection lost or not acquired in time while beginning the transaction,
+    /// before anything is executed. A lost connection after that is not retried, as it is unknown
+    /// whether the transaction has been committed, and neither is a closed connection pool.
+    ///
+    /// The function is not idempotent in general: every effect it has outside the transaction,
+    /// e.g. sending a request or updating a captured variable, is repeated on every attempt.
+    #[instrument(level = "trace", skip(callback))]
+    pub async fn transaction_with_retry<F, T>(
+        &self,
+        policy: &RetryPolicy,
+        callback: F,
+    ) -> Result<T, TransactionError<DbErr>>
+    where
+        F: for<'c> Fn(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, DbErr>> + Send + 'c>>
+            + Send
+            + Sync,
+        T: Send,
+    {
+        let mut attempt = 1;
+        loop {
+            let (err, transient) = match self.begin().await {
+                Ok(txn) => match callback(&txn).await {
+                    Ok(res) => match txn.commit().await {
+                        Ok(()) => return Ok(res),
+                        Err(err) => {
+                            let transient = RetryPolicy::is_transient(&err);
+                            (TransactionError::Connection(err), transient)
+                        }
+                    },
+                    Err(err) => {
+                        txn.rollback().await.map_err(TransactionError::Connection)?;
+                        let transient = RetryPolicy::is_transient(&err);
+                        (TransactionError::Transaction(err), transient)
+                    }
+                },
+                Err(err) => {
+                    let transient = RetryPolicy::is_transient_begin(&err) && !self.is_closed();
+                    (TransactionError::Connection(err), transient)
+                }
+            };
+            if !transient || attempt >= policy.max_attempts {
+                return Err(err);
+            }
+            crate::database::retry::sleep(policy.delay(attempt)).await;
+            attempt += 1;
+        }
+    }
+
+    /// Whether the connection pool has been closed, after which no connection can be acquired
+    fn is_closed(&self) -> bool {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnection::SqlxMySqlPoolConnection(conn) => conn.pool.is_closed(),
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => conn.pool.is_closed(),
+            #[cfg(feature = "sqlx-sqlite")]
+            DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.pool.is_closed(),
+            _ => false,
+        }
+    }
+
     /// Sets a callback to metric this connection
     pub fn set_metric_callback<F>(&mut self, _callback: F)
     where
@@ -279,6 +501,35 @@ impl DatabaseConnection {
     }
 }
 
+impl DatabaseConnection {
+    /// Get [sqlx::MySqlPool], or [Option::None] if [DbConn] is not a MySQL connection
+    #[cfg(feature = "sqlx-mysql")]
+    pub fn get_mysql_connection_pool(&self) -> Option<&sqlx::MySqlPool> {
+        match self {
+            DatabaseConnection::SqlxMySqlPoolConnection(conn) => Some(&conn.pool),
+            _ => None,
+        }
+    }
+
+    /// Get [sqlx::PgPool], or [Option::None] if [DbConn] is not a Postgres connection
+    #[cfg(feature = "sqlx-postgres")]
+    pub fn get_postgres_connection_pool(&self) -> Option<&sqlx::PgPool> {
+        match self {
+            DatabaseConnection::SqlxPostgresPoolConnection(conn) => Some(&conn.pool),
+            _ => None,
+        }
+    }
+
+    /// Get [sqlx::SqlitePool], or [Option::None] if [DbConn] is not a SQLite connection
+    #[cfg(feature = "sqlx-sqlite")]
+    pub fn get_sqlite_connection_pool(&self) -> Option<&sqlx::SqlitePool> {
+        match self {
+            DatabaseConnection::SqlxSqlitePoolConnection(conn) => Some(&conn.pool),
+            _ => None,
+        }
+    }
+}
+
 impl DbBackend {
     /// Check if the URI is the same as the specified database backend.
     /// Returns true if they match.
@@ -13,8 +13,9 @@ pub struct MockDatabase {
     db_backend: DbBackend,
     transaction: Option<OpenTransaction>,
     transaction_log: Vec<Transaction>,
-    exec_results: Vec<MockExecResult>,
-    query_results: Vec<Vec<MockRow>>,
+    exec_results: Vec<Result<MockExecResult, DbErr>>,
+    query_results: Vec<Result<Vec<MockRow>, DbErr>>,
+    expectations: Vec<StatementMatcher>,
 }
 
 /// Defines the results obtained from a [MockDatabase]
@@ -39,6 +40,19 @@ pub trait IntoMockRow {
     fn into_mock_row(self) -> MockRow;
 }
 
+/// An expectation on a [Statement] issued to the [MockDatabase],
+/// registered with [MockDatabase::expect]
+pub struct StatementMatcher {
+    sql: SqlMatcher,
+    values: Option<Vec<Value>>,
+}
+
+enum SqlMatcher {
+    Exact(String),
+    Contains(String),
+    Fn(Box<dyn Fn(&str) -> bool + Send + Sync>),
+}
+
 /// Defines a transaction that is has not been committed
 #[derive(Debug)]
 pub struct OpenTransaction {
@@ -62,6 +76,7 @@ impl MockDatabase {
             transaction_log: Vec::new(),
             exec_results: Vec::new(),
             query_results: Vec::new(),
+            expectations: Vec::new(),
         }
     }
 
@@ -71,8 +86,15 @@ impl MockDatabase {
     }
 
     /// Add the [MockExecResult]s to the `exec_results` field for `Self`
-    pub fn append_exec_results(mut self, mut vec: Vec<MockExecResult>) -> Self {
-        self.exec_results.append(&mut vec);
+    pub fn append_exec_results(mut self, vec: Vec<MockExecResult>) -> Self {
+        self.exec_results.extend(vec.into_iter().map(Ok));
+        self
+    }
+
+    /// Add the [DbErr]s to the `exec_results` field for `Self`,
+    /// each one is returned by an `execute` call in turn
+    pub fn append_exec_errors(mut self, vec: Vec<DbErr>) -> Self {
+        self.exec_results.extend(vec.into_iter().map(Err));
         self
     }
 
@@ -83,24 +105,45 @@ impl MockDatabase {
     {
         for row in vec.into_iter() {
             let row = row.into_iter().map(|vec| vec.into_mock_row()).collect();
-            self.query_results.push(row);
+            self.query_results.push(Ok(row));
         }
         self
     }
+
+    /// Add the [DbErr]s to the `query_results` field for `Self`,
+    /// each one is returned by a `query` call in turn
+    pub fn append_query_errors(mut self, vec: Vec<DbErr>) -> Self {
+        self.query_results.extend(vec.into_iter().map(Err));
+        self
+    }
+
+    /// Expect the next statement to match `matcher`. The statements are checked in order,
+    /// including `BEGIN` and `COMMIT` of transactions, by
+    /// [DatabaseConnection::verify_expectations]
+    pub fn expect(mut self, matcher: StatementMatcher) -> Self {
+        self.expectations.push(matcher);
+        self
+    }
+
+    fn statements(&self) -> impl Iterator<Item = &Statement> {
+        self.transaction_log
+            .iter()
+            .flat_map(|txn| txn.stmts.iter())
+            .chain(self.transaction.iter().flat_map(|txn| txn.stmts.iter()))
+    }
 }
 
 impl MockDatabaseTrait for MockDatabase {
     #[instrument(level = "trace")]
     fn execute(&mut self, counter: usize, statement: Statement) -> Result<ExecResult, DbErr> {
-        if let Some(transaction) = &mut self.transaction {
-            transaction.push(statement);
-        } else {
-            self.transaction_log.push(Transaction::one(statement));
-        }
+        self.log(statement);
         if counter < self.exec_results.len() {
-            Ok(ExecResult {
-                result: ExecResultHolder::Mock(std::mem::take(&mut self.exec_results[counter])),
-            })
+            match std::mem::replace(&mut self.exec_results[counter], Ok(Default::default())) {
+                Ok(result) => Ok(ExecResult {
+                    result: ExecResultHolder::Mock(result),
+                }),
+                Err(err) => Err(err),
+            }
         } else {
             Err(DbErr::Exec("`exec_results` buffer is empty.".to_owned()))
         }
@@ -108,18 +151,16 @@ impl MockDatabaseTrait for MockDatabase {
 
     #[instrument(level = "trace")]
     fn query(&mut self, counter: usize, statement: Statement) -> Result<Vec<QueryResult>, DbErr> {
-        if let Some(transaction) = &mut self.transaction {
-            transaction.push(statement);
-        } else {
-            self.transaction_log.push(Transaction::one(statement));
-        }
+        self.log(statement);
         if counter < self.query_results.len() {
-            Ok(std::mem::take(&mut self.query_results[counter])
-                .into_iter()
-                .map(|row| QueryResult {
-                    row: QueryResultRow::Mock(row),
-                })
-                .collect())
+            Ok(
+                std::mem::replace(&mut self.query_results[counter], Ok(Vec::new()))?
+                    .into_iter()
+                    .map(|row| QueryResult {
+                        row: QueryResultRow::Mock(row),
+                    })
+                    .collect(),
+            )
         } else {
             Err(DbErr::Query("`query_results` buffer is empty.".to_owned()))
         }
@@ -133,7 +174,7 @@ impl MockDatabaseTrait for MockDatabase {
                 .unwrap()
                 .begin_nested(self.db_backend);
         } else {
-            self.transaction = Some(OpenTransaction::init());
+            self.transaction = Some(OpenTransaction::init(self.db_backend));
         }
     }
 
@@ -161,10 +202,43 @@ impl MockDatabaseTrait for MockDatabase {
         }
     }
 
+    #[instrument(level = "trace")]
+    fn log(&mut self, statement: Statement) {
+        if let Some(transaction) = &mut self.transaction {
+            transaction.push(statement);
+        } else {
+            self.transaction_log.push(Transaction::one(statement));
+        }
+    }
+
     fn drain_transaction_log(&mut self) -> Vec<Transaction> {
         std::mem::take(&mut self.transaction_log)
     }
 
+    fn verify_expectations(&self) {
+        let stmts: Vec<&Statement> = self.statements().collect();
+        let len = stmts.len().max(self.expectations.len());
+        let mut report = String::new();
+        for i in 0..len {
+            let (expected, found) = (self.expectations.get(i), stmts.get(i));
+            let matched = matches!((expected, found), (Some(m), Some(s)) if m.matches(s));
+            report.push_str(&format!(
+                "{} #{}\n  expected: {}\n     found: {}\n",
+                if matched { " " } else { "x" },
+                i + 1,
+                expected.map_or("nothing".to_owned(), |m| format!("{:?}", m)),
+                found.map_or("nothing".to_owned(), |s| format!(
+                    "sql = {:?}, values = {:?}",
+                    s.sql,
+                    s.values.as_ref().map_or(&[][..], |values| &values.0)
+                )),
+            ));
+            if !matched {
+                panic!("Statements did not match the expectations:\n{}", report);
+            }
+        }
+    }
+
     fn get_database_backend(&self) -> DbBackend {
         self.db_backend
     }
@@ -179,6 +253,11 @@ impl MockRow {
         T::try_from(self.values.get(col).unwrap().clone()).map_err(|e| DbErr::Query(e.to_string()))
     }
 
+    /// Get the column names of a mock row, sorted by name
+    pub(crate) fn column_names(&self) -> Vec<String> {
+        self.values.keys().cloned().collect()
+    }
+
     /// An iterator over the keys and values of a mock row
     pub fn into_column_value_tuples(self) -> impl Iterator<Item = (String, Value)> {
         self.values.into_iter()
@@ -245,6 +324,76 @@ impl IntoMockRow for BTreeMap<&str, Value> {
     }
 }
 
+impl StatementMatcher {
+    /// Match a statement whose SQL equals `sql`
+    pub fn sql<T>(sql: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(SqlMatcher::Exact(sql.into()))
+    }
+
+    /// Match a statement whose SQL contains `pattern`
+    pub fn sql_contains<T>(pattern: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(SqlMatcher::Contains(pattern.into()))
+    }
+
+    /// Match a statement whose SQL satisfies the predicate, e.g. a regular expression
+    pub fn sql_matches<F>(f: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        Self::new(SqlMatcher::Fn(Box::new(f)))
+    }
+
+    fn new(sql: SqlMatcher) -> Self {
+        Self { sql, values: None }
+    }
+
+    /// Also require the statement to be bound with exactly these values
+    pub fn values<I>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = Value>,
+    {
+        self.values = Some(values.into_iter().collect());
+        self
+    }
+
+    /// Check if the [Statement] satisfies this matcher
+    pub fn matches(&self, stmt: &Statement) -> bool {
+        let sql = match &self.sql {
+            SqlMatcher::Exact(sql) => &stmt.sql == sql,
+            SqlMatcher::Contains(pattern) => stmt.sql.contains(pattern.as_str()),
+            SqlMatcher::Fn(f) => f(&stmt.sql),
+        };
+        let values = match &self.values {
+            Some(expected) => match &stmt.values {
+                Some(values) => &values.0 == expected,
+                None => expected.is_empty(),
+            },
+            None => true,
+        };
+        sql && values
+    }
+}
+
+impl std::fmt::Debug for StatementMatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.sql {
+            SqlMatcher::Exact(sql) => write!(f, "sql = {:?}", sql)?,
+            SqlMatcher::Contains(pattern) => write!(f, "sql contains {:?}", pattern)?,
+            SqlMatcher::Fn(_) => write!(f, "sql matches <fn>")?,
+        }
+        if let Some(values) = &self.values {
+            write!(f, ", values = {:?}", values)?;
+        }
+        Ok(())
+    }
+}
+
 impl Transaction {
     /// Get the [Value]s from s raw SQL statement depending on the [DatabaseBackend](crate::DatabaseBackend)
     pub fn from_sql_and_values<I>(db_backend: DbBackend, sql: &str, values: I) -> Self
@@ -279,15 +428,32 @@ impl Transaction {
     {
         stmts.into_iter().map(Self::one).collect()
     }
+
+    /// Get the statements of this Transaction, including `BEGIN`, `COMMIT` and `ROLLBACK`
+    pub fn statements(&self) -> &[Statement] {
+        &self.stmts
+    }
+
+    /// Check if this Transaction was opened with `BEGIN`
+    pub fn is_transaction(&self) -> bool {
+        matches!(self.stmts.first(), Some(stmt) if stmt.sql == "BEGIN")
+    }
+
+    /// Check if this Transaction ended with `COMMIT`
+    pub fn is_committed(&self) -> bool {
+        self.is_transaction() && matches!(self.stmts.last(), Some(stmt) if stmt.sql == "COMMIT")
+    }
+
+    /// Check if this Transaction ended with `ROLLBACK`
+    pub fn is_rolled_back(&self) -> bool {
+        self.is_transaction() && matches!(self.stmts.last(), Some(stmt) if stmt.sql == "ROLLBACK")
+    }
 }
 
 impl OpenTransaction {
-    fn init() -> Self {
+    fn init(db_backend: DbBackend) -> Self {
         Self {
-            stmts: vec![Statement::from_string(
-                DbBackend::Postgres,
-                "BEGIN".to_owned(),
-            )],
+            stmts: vec![Statement::from_string(db_backend, "BEGIN".to_owned())],
             transaction_depth: 0,
         }
     }
@@ -344,10 +510,13 @@ impl OpenTransaction {
 #[cfg(feature = "mock")]
 mod tests {
     use crate::{
-        entity::*, tests_cfg::*, DbBackend, DbErr, IntoMockRow, MockDatabase, Statement,
-        Transaction, TransactionError, TransactionTrait,
+        entity::*, tests_cfg::*, AccessMode, ConnectionTrait, DatabaseConnection,
+        DatabaseTransaction, DbBackend, DbErr, ExecResult, IntoMockRow, IsolationLevel,
+        MockDatabase, MockExecResult, QueryResult, Statement, Transaction, TransactionError,
+        TransactionTrait, Value,
     };
     use pretty_assertions::assert_eq;
+    use std::{collections::BTreeMap, future::Future, pin::Pin, time::Duration};
 
     #[derive(Debug, PartialEq, Eq)]
     pub struct MyErr(String);
@@ -360,6 +529,108 @@ mod tests {
         }
     }
 
+    /// A connection implementing only the required methods of the connection traits
+    #[derive(Debug)]
+    pub struct WrappedConnection(DatabaseConnection);
+
+    #[async_trait::async_trait]
+    impl ConnectionTrait for WrappedConnection {
+        fn get_database_backend(&self) -> DbBackend {
+            self.0.get_database_backend()
+        }
+
+        async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+            self.0.execute(stmt).await
+        }
+
+        async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+            self.0.query_one(stmt).await
+        }
+
+        async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+            self.0.query_all(stmt).await
+        }
+    }
+
+    #[smol_potat::test]
+    async fn test_default_execute_unprepared() -> Result<(), DbErr> {
+        let conn = WrappedConnection(
+            MockDatabase::new(DbBackend::Postgres)
+                .append_exec_results(vec![MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                }])
+                .into_connection(),
+        );
+
+        let sql = r#"DELETE FROM "cake""#;
+        assert_eq!(conn.execute_unprepared(sql).await?.rows_affected(), 1);
+        assert_eq!(
+            conn.0.into_transaction_log(),
+            vec![Transaction::one(Statement::from_string(
+                DbBackend::Postgres,
+                sql.to_owned()
+            ))]
+        );
+
+        Ok(())
+    }
+
+    #[async_trait::async_trait]
+    impl TransactionTrait for WrappedConnection {
+        async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+            self.0.begin().await
+        }
+
+        async fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
+        where
+            F: for<'c> FnOnce(
+                    &'c DatabaseTransaction,
+                )
+                    -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+                + Send,
+            T: Send,
+            E: std::error::Error + Send,
+        {
+            self.0.transaction(callback).await
+        }
+    }
+
+    #[smol_potat::test]
+    async fn test_default_begin_with_config() -> Result<(), DbErr> {
+        let conn = WrappedConnection(MockDatabase::new(DbBackend::Postgres).into_connection());
+
+        conn.begin_with_config(
+            Some(IsolationLevel::Serializable),
+            Some(AccessMode::ReadOnly),
+        )
+        .await?
+        .commit()
+        .await?;
+        // The isolation level and access mode are ignored, no `SET TRANSACTION` is executed
+        assert_eq!(
+            conn.0.into_transaction_log(),
+            vec![Transaction::many(vec![
+                Statement::from_string(DbBackend::Postgres, "BEGIN".to_owned()),
+                Statement::from_string(DbBackend::Postgres, "COMMIT".to_owned()),
+            ])]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn test_ping() {
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+        assert_eq!(db.ping().await, Ok(()));
+        assert_eq!(db.into_transaction_log(), vec![]);
+
+        assert_eq!(
+            crate::DatabaseConnection::Disconnected.ping().await,
+            Err(DbErr::Conn("Disconnected".to_owned()))
+        );
+    }
+
     #[smol_potat::test]
     async fn test_transaction_1() {
         let db = MockDatabase::new(DbBackend::Postgres).into_connection();
@@ -437,6 +708,253 @@ mod tests {
         );
     }
 
+    #[smol_potat::test]
+    async fn test_transaction_with_retry_1() -> Result<(), DbErr> {
+        use crate::{DatabaseErr, MockExecResult, RetryPolicy};
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_errors(vec![
+                DbErr::Database(DatabaseErr::new("deadlock detected").sql_state("40P01")),
+                DbErr::Database(DatabaseErr::new("could not serialize access").sql_state("40001")),
+            ])
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        let attempts = AtomicU32::new(0);
+        let rows_affected = db
+            .transaction_with_retry(RetryPolicy::new(3).backoff(Duration::ZERO), |txn| {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Box::pin(async move {
+                    let res = cake::Entity::delete_by_id(1).exec(txn).await?;
+                    Ok(res.rows_affected)
+                })
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(rows_affected, 1);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+        let attempt = |end: &str| {
+            Transaction::many(vec![
+                Statement::from_string(DbBackend::Postgres, "BEGIN".to_owned()),
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"DELETE FROM "cake" WHERE "cake"."id" = $1"#,
+                    vec![1i32.into()],
+                ),
+                Statement::from_string(DbBackend::Postgres, end.to_owned()),
+            ])
+        };
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![attempt("ROLLBACK"), attempt("ROLLBACK"), attempt("COMMIT")]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn test_transaction_with_retry_2() -> Result<(), DbErr> {
+        use crate::{DatabaseErr, RetryPolicy};
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_errors(vec![
+                DbErr::Conn("Connection reset".to_owned()),
+                DbErr::Database(DatabaseErr::new("could not serialize access").sql_state("40001")),
+                DbErr::Database(DatabaseErr::new("duplicate key").sql_state("23505")),
+                DbErr::Database(DatabaseErr::new("could not serialize access").sql_state("40001")),
+            ])
+            .into_connection();
+
+        // a lost connection within the transaction is returned immediately
+        let attempts = AtomicU32::new(0);
+        let res = db
+            .transaction_with_retry(RetryPolicy::new(3).backoff(Duration::ZERO), |txn| {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Box::pin(async move {
+                    cake::Entity::delete_by_id(1).exec(txn).await?;
+                    Ok(())
+                })
+            })
+            .await;
+
+        match res {
+            Err(TransactionError::Transaction(err)) => {
+                assert_eq!(err, DbErr::Conn("Connection reset".to_owned()))
+            }
+            _ => panic!(),
+        }
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+        // a non-transient error is returned immediately
+        let attempts = AtomicU32::new(0);
+        let res = db
+            .transaction_with_retry(RetryPolicy::new(3).backoff(Duration::ZERO), |txn| {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Box::pin(async move {
+                    cake::Entity::delete_by_id(1).exec(txn).await?;
+                    Ok(())
+                })
+            })
+            .await;
+
+        match res {
+            Err(TransactionError::Transaction(err)) => {
+                assert_eq!(err.sql_state(), Some("23505"))
+            }
+            _ => panic!(),
+        }
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+
+        // gives up after the maximum number of attempts
+        let attempts = AtomicU32::new(0);
+        let res = db
+            .transaction_with_retry(RetryPolicy::new(1).backoff(Duration::ZERO), |txn| {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Box::pin(async move {
+                    cake::Entity::delete_by_id(1).exec(txn).await?;
+                    Ok(())
+                })
+            })
+            .await;
+
+        match res {
+            Err(TransactionError::Transaction(err)) => {
+                assert_eq!(err.sql_state(), Some("40001"))
+            }
+            _ => panic!(),
+        }
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn test_exec_errors() -> Result<(), DbErr> {
+        use crate::{sea_query::Expr, MockExecResult, QueryFilter, Set};
+
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_errors(vec![DbErr::Exec("Duplicate entry '1'".to_owned())])
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        // fall back to updating the row if inserting it fails
+        let res = cake::Entity::insert(cake::ActiveModel {
+            id: Set(1),
+            name: Set("Chocolate Forest".to_owned()),
+        })
+        .exec(&db)
+        .await;
+
+        let rows_affected = match res {
+            Ok(_) => panic!("insert should fail"),
+            Err(DbErr::Exec(_)) => {
+                cake::Entity::update_many()
+                    .col_expr(cake::Column::Name, Expr::value("Chocolate Forest"))
+                    .filter(cake::Column::Id.eq(1))
+                    .exec(&db)
+                    .await?
+                    .rows_affected
+            }
+            Err(err) => return Err(err),
+        };
+        assert_eq!(rows_affected, 1);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    r#"INSERT INTO `cake` (`id`, `name`) VALUES (?, ?)"#,
+                    vec![1i32.into(), "Chocolate Forest".into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    r#"UPDATE `cake` SET `name` = ? WHERE `cake`.`id` = ?"#,
+                    vec!["Chocolate Forest".into(), 1i32.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn test_query_errors() {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![cake::Model {
+                id: 1,
+                name: "Chocolate Forest".to_owned(),
+            }]])
+            .append_query_errors(vec![DbErr::Conn("Connection reset".to_owned())])
+            .append_query_results(vec![Vec::<cake::Model>::new()])
+            .into_connection();
+
+        assert_eq!(
+            cake::Entity::find().all(&db).await,
+            Ok(vec![cake::Model {
+                id: 1,
+                name: "Chocolate Forest".to_owned(),
+            }])
+        );
+        assert_eq!(
+            cake::Entity::find().all(&db).await,
+            Err(DbErr::Conn("Connection reset".to_owned()))
+        );
+        assert_eq!(cake::Entity::find().all(&db).await, Ok(vec![]));
+    }
+
+    #[smol_potat::test]
+    async fn test_transaction_boundaries() {
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+
+        db.transaction::<_, (), DbErr>(|txn| {
+            Box::pin(async move {
+                let _ = cake::Entity::find().one(txn).await;
+                Ok(())
+            })
+        })
+        .await
+        .unwrap();
+
+        let result = db
+            .transaction::<_, (), MyErr>(|txn| {
+                Box::pin(async move {
+                    let _ = cake::Entity::find().one(txn).await;
+                    Err(MyErr("test".to_owned()))
+                })
+            })
+            .await;
+        assert!(result.is_err());
+
+        let _ = cake::Entity::find().all(&db).await;
+
+        let log = db.into_transaction_log();
+        assert_eq!(log.len(), 3);
+
+        assert!(log[0].is_transaction());
+        assert!(log[0].is_committed());
+        assert!(!log[0].is_rolled_back());
+        assert_eq!(log[0].statements().len(), 3);
+
+        assert!(log[1].is_transaction());
+        assert!(!log[1].is_committed());
+        assert!(log[1].is_rolled_back());
+
+        assert!(!log[2].is_transaction());
+        assert!(!log[2].is_committed());
+        assert!(!log[2].is_rolled_back());
+    }
+
     #[smol_potat::test]
     async fn test_nested_transaction_1() {
         let db = MockDatabase::new(DbBackend::Postgres).into_connection();
@@ -553,6 +1071,123 @@ mod tests {
         );
     }
 
+    #[smol_potat::test]
+    async fn test_nested_transaction_rollback() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+
+        let txn = db.begin().await?;
+        let _ = cake::Entity::find().one(&txn).await;
+
+        let nested = txn.begin().await?;
+        let _ = fruit::Entity::find().all(&nested).await;
+        nested.rollback().await?;
+
+        let nested = txn.begin().await?;
+        let _ = cake::Entity::find().all(&nested).await;
+        nested.commit().await?;
+
+        txn.commit().await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::many(vec![
+                Statement::from_string(DbBackend::Postgres, "BEGIN".to_owned()),
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake" LIMIT $1"#,
+                    vec![1u64.into()]
+                ),
+                Statement::from_string(DbBackend::Postgres, "SAVEPOINT savepoint_1".to_owned()),
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                    vec![]
+                ),
+                Statement::from_string(
+                    DbBackend::Postgres,
+                    "ROLLBACK TO SAVEPOINT savepoint_1".to_owned()
+                ),
+                Statement::from_string(DbBackend::Postgres, "SAVEPOINT savepoint_1".to_owned()),
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                    vec![]
+                ),
+                Statement::from_string(
+                    DbBackend::Postgres,
+                    "RELEASE SAVEPOINT savepoint_1".to_owned()
+                ),
+                Statement::from_string(DbBackend::Postgres, "COMMIT".to_owned()),
+            ]),]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn test_transaction_with_config() -> Result<(), DbErr> {
+        for backend in [DbBackend::MySql, DbBackend::Postgres, DbBackend::Sqlite] {
+            let db = MockDatabase::new(backend).into_connection();
+
+            let txn = db
+                .begin_with_config(
+                    Some(IsolationLevel::Serializable),
+                    Some(AccessMode::ReadOnly),
+                )
+                .await?;
+            txn.commit().await?;
+
+            let txn = db
+                .begin_with_config(Some(IsolationLevel::ReadCommitted), None)
+                .await?;
+            txn.commit().await?;
+
+            let txn = db.begin_with_config(None, None).await?;
+            txn.commit().await?;
+
+            let begin = Statement::from_string(backend, "BEGIN".to_owned());
+            let commit = Statement::from_string(backend, "COMMIT".to_owned());
+            let set_transaction = |characteristics: &str| {
+                Statement::from_string(backend, format!("SET TRANSACTION {}", characteristics))
+            };
+
+            assert_eq!(
+                db.into_transaction_log(),
+                match backend {
+                    DbBackend::MySql => vec![
+                        Transaction::one(set_transaction(
+                            "ISOLATION LEVEL SERIALIZABLE, READ ONLY"
+                        )),
+                        Transaction::many(vec![begin.clone(), commit.clone()]),
+                        Transaction::one(set_transaction("ISOLATION LEVEL READ COMMITTED")),
+                        Transaction::many(vec![begin.clone(), commit.clone()]),
+                        Transaction::many(vec![begin, commit]),
+                    ],
+                    DbBackend::Postgres => vec![
+                        Transaction::many(vec![
+                            begin.clone(),
+                            set_transaction("ISOLATION LEVEL SERIALIZABLE, READ ONLY"),
+                            commit.clone(),
+                        ]),
+                        Transaction::many(vec![
+                            begin.clone(),
+                            set_transaction("ISOLATION LEVEL READ COMMITTED"),
+                            commit.clone(),
+                        ]),
+                        Transaction::many(vec![begin, commit]),
+                    ],
+                    DbBackend::Sqlite => vec![
+                        Transaction::many(vec![begin.clone(), commit.clone()]),
+                        Transaction::many(vec![begin.clone(), commit.clone()]),
+                        Transaction::many(vec![begin, commit]),
+                    ],
+                }
+            );
+        }
+
+        Ok(())
+    }
+
     #[smol_potat::test]
     async fn test_stream_1() -> Result<(), DbErr> {
         use futures::TryStreamExt;
@@ -602,6 +1237,49 @@ mod tests {
         Ok(())
     }
 
+    #[smol_potat::test]
+    async fn test_stream_lazy() -> Result<(), DbErr> {
+        use futures::TryStreamExt;
+
+        let apple = fruit::Model {
+            id: 1,
+            name: "Apple".to_owned(),
+            cake_id: Some(1),
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                apple.clone().into_mock_row(),
+                // this row cannot be converted into a fruit::Model
+                BTreeMap::from([
+                    ("id", Value::from(2)),
+                    ("name", Value::from(2)),
+                    ("cake_id", Value::Int(None)),
+                ])
+                .into_mock_row(),
+            ]])
+            .into_connection();
+
+        {
+            let mut stream = fruit::Entity::find().stream(&db).await?;
+
+            // rows are only converted when the stream is polled
+            assert_eq!(stream.try_next().await?, Some(apple.clone()));
+            assert!(stream.try_next().await.is_err());
+        }
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                vec![]
+            )]
+        );
+
+        Ok(())
+    }
+
     #[smol_potat::test]
     async fn test_stream_in_transaction() -> Result<(), DbErr> {
         use futures::TryStreamExt;
@@ -707,4 +1385,71 @@ mod tests {
 
         Ok(())
     }
+
+    #[smol_potat::test]
+    async fn test_expectations() -> Result<(), DbErr> {
+        use crate::{MockExecResult, StatementMatcher};
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![cake::Model {
+                id: 1,
+                name: "Chocolate Forest".to_owned(),
+            }]])
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .expect(StatementMatcher::sql("BEGIN"))
+            .expect(
+                StatementMatcher::sql(r#"SELECT "cake"."id", "cake"."name" FROM "cake" LIMIT $1"#)
+                    .values(vec![1u64.into()]),
+            )
+            .expect(
+                StatementMatcher::sql_matches(|sql| {
+                    sql.starts_with(r#"DELETE FROM "cake""#) && sql.ends_with("$1")
+                })
+                .values(vec![1i32.into()]),
+            )
+            .expect(StatementMatcher::sql_contains("COMMIT"))
+            .into_connection();
+
+        db.transaction::<_, (), DbErr>(|txn| {
+            Box::pin(async move {
+                let cake = cake::Entity::find().one(txn).await?.unwrap();
+                cake.delete(txn).await?;
+                Ok(())
+            })
+        })
+        .await
+        .unwrap();
+
+        db.verify_expectations();
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    #[should_panic(expected = r#"Statements did not match the expectations:
+  #1
+  expected: sql contains "FROM \"cake\"", values = []
+     found: sql = "SELECT \"cake\".\"id\", \"cake\".\"name\" FROM \"cake\"", values = []
+x #2
+  expected: sql = "SELECT \"fruit\".\"id\" FROM \"fruit\""
+     found: sql = "SELECT \"fruit\".\"id\", \"fruit\".\"name\", \"fruit\".\"cake_id\" FROM \"fruit\"", values = []
+"#)]
+    async fn test_expectations_mismatch() {
+        use crate::StatementMatcher;
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![Vec::<cake::Model>::new()])
+            .append_query_results(vec![Vec::<fruit::Model>::new()])
+            .expect(StatementMatcher::sql_contains(r#"FROM "cake""#).values(vec![]))
+            .expect(StatementMatcher::sql(r#"SELECT "fruit"."id" FROM "fruit""#))
+            .into_connection();
+
+        cake::Entity::find().all(&db).await.unwrap();
+        fruit::Entity::find().all(&db).await.unwrap();
+
+        db.verify_expectations();
+    }
 }
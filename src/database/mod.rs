@@ -4,6 +4,7 @@ mod connection;
 mod db_connection;
 #[cfg(feature = "mock")]
 mod mock;
+mod retry;
 mod statement;
 mod stream;
 mod transaction;
@@ -12,6 +13,7 @@ pub use connection::*;
 pub use db_connection::*;
 #[cfg(feature = "mock")]
 pub use mock::*;
+pub use retry::*;
 pub use statement::*;
 use std::borrow::Cow;
 pub use stream::*;
@@ -44,8 +46,14 @@ pub struct ConnectOptions {
     pub(crate) sqlx_logging: bool,
     /// SQLx statement logging level (ignored if `sqlx_logging` is false)
     pub(crate) sqlx_logging_level: log::LevelFilter,
+    /// SQLx slow statements logging level (ignored if `sqlx_logging` is false)
+    pub(crate) sqlx_slow_statements_logging_level: log::LevelFilter,
+    /// SQLx slow statements duration threshold (ignored if `sqlx_logging` is false)
+    pub(crate) sqlx_slow_statements_logging_threshold: Duration,
     /// set sqlcipher key
     pub(crate) sqlcipher_key: Option<Cow<'static, str>>,
+    /// Schema search path (PostgreSQL only)
+    pub(crate) schema_search_path: Option<String>,
 }
 
 impl Database {
@@ -78,6 +86,39 @@ impl Database {
             opt.url
         )))
     }
+
+    /// Method to create a [DatabaseConnection] on a database without connecting to it.
+    /// The connections of the pool are established on first use, e.g. by a query or a
+    /// [ping](DatabaseConnection::ping), which is where connection errors will surface.
+    /// Only an invalid connection string is reported right away.
+    #[instrument(level = "trace", skip(opt))]
+    pub fn connect_lazy<C>(opt: C) -> Result<DatabaseConnection, DbErr>
+    where
+        C: Into<ConnectOptions>,
+    {
+        let opt: ConnectOptions = opt.into();
+
+        #[cfg(feature = "sqlx-mysql")]
+        if DbBackend::MySql.is_prefix_of(&opt.url) {
+            return crate::SqlxMySqlConnector::connect_lazy(opt);
+        }
+        #[cfg(feature = "sqlx-postgres")]
+        if DbBackend::Postgres.is_prefix_of(&opt.url) {
+            return crate::SqlxPostgresConnector::connect_lazy(opt);
+        }
+        #[cfg(feature = "sqlx-sqlite")]
+        if DbBackend::Sqlite.is_prefix_of(&opt.url) {
+            return crate::SqlxSqliteConnector::connect_lazy(opt);
+        }
+        #[cfg(feature = "mock")]
+        if crate::MockDatabaseConnector::accepts(&opt.url) {
+            return crate::MockDatabaseConnector::connect_lazy(&opt.url);
+        }
+        Err(DbErr::Conn(format!(
+            "The connection string '{}' has no supporting driver.",
+            opt.url
+        )))
+    }
 }
 
 impl From<&str> for ConnectOptions {
@@ -110,7 +151,10 @@ impl ConnectOptions {
             max_lifetime: None,
             sqlx_logging: true,
             sqlx_logging_level: log::LevelFilter::Info,
+            sqlx_slow_statements_logging_level: log::LevelFilter::Warn,
+            sqlx_slow_statements_logging_threshold: Duration::from_secs(1),
             sqlcipher_key: None,
+            schema_search_path: None,
         }
     }
 
@@ -143,6 +187,23 @@ impl ConnectOptions {
         opt
     }
 
+    #[cfg(feature = "sqlx-dep")]
+    /// Apply the statement logging settings onto the [sqlx::ConnectOptions] of a driver
+    pub(crate) fn apply_sqlx_logging<O>(&self, opt: &mut O)
+    where
+        O: sqlx::ConnectOptions,
+    {
+        if !self.sqlx_logging {
+            opt.disable_statement_logging();
+        } else {
+            opt.log_statements(self.sqlx_logging_level);
+            opt.log_slow_statements(
+                self.sqlx_slow_statements_logging_level,
+                self.sqlx_slow_statements_logging_threshold,
+            );
+        }
+    }
+
     /// Get the database URL of the pool
     pub fn get_url(&self) -> &str {
         &self.url
@@ -226,6 +287,30 @@ impl ConnectOptions {
         self.sqlx_logging_level
     }
 
+    /// Set SQLx slow statements logging level and duration threshold (default WARN, 1 second).
+    /// Statements taking longer than `duration` are logged at `level`, the rest at the
+    /// level set by [ConnectOptions::sqlx_logging_level].
+    /// (ignored if `sqlx_logging` is `false`)
+    pub fn sqlx_slow_statements_logging_settings(
+        &mut self,
+        level: log::LevelFilter,
+        duration: Duration,
+    ) -> &mut Self {
+        self.sqlx_slow_statements_logging_level = level;
+        self.sqlx_slow_statements_logging_threshold = duration;
+        self
+    }
+
+    /// Get the level of SQLx slow statements logging
+    pub fn get_sqlx_slow_statements_logging_level(&self) -> log::LevelFilter {
+        self.sqlx_slow_statements_logging_level
+    }
+
+    /// Get the duration threshold of SQLx slow statements logging
+    pub fn get_sqlx_slow_statements_logging_threshold(&self) -> Duration {
+        self.sqlx_slow_statements_logging_threshold
+    }
+
     /// set key for sqlcipher
     pub fn sqlcipher_key<T>(&mut self, value: T) -> &mut Self
     where
@@ -234,4 +319,108 @@ impl ConnectOptions {
         self.sqlcipher_key = Some(value.into());
         self
     }
+
+    /// Set the schema search path (PostgreSQL only), e.g. `my_schema, public`.
+    /// `SET search_path TO ...` is executed on every new connection of the pool,
+    /// so unqualified table names are resolved in these schemas.
+    pub fn schema_search_path<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.schema_search_path = Some(value.into());
+        self
+    }
+
+    /// Get the schema search path, if set
+    pub fn get_schema_search_path(&self) -> Option<&str> {
+        self.schema_search_path.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn connect_options_default() {
+        let opt = ConnectOptions::new("sqlite::memory:".to_owned());
+
+        assert_eq!(opt.get_url(), "sqlite::memory:");
+        assert_eq!(opt.get_max_connections(), None);
+        assert_eq!(opt.get_min_connections(), None);
+        assert_eq!(opt.get_connect_timeout(), None);
+        assert_eq!(opt.get_idle_timeout(), None);
+        assert_eq!(opt.get_max_lifetime(), None);
+        assert!(opt.get_sqlx_logging());
+        assert_eq!(opt.get_sqlx_logging_level(), log::LevelFilter::Info);
+        assert_eq!(
+            opt.get_sqlx_slow_statements_logging_level(),
+            log::LevelFilter::Warn
+        );
+        assert_eq!(
+            opt.get_sqlx_slow_statements_logging_threshold(),
+            Duration::from_secs(1)
+        );
+        assert_eq!(opt.get_schema_search_path(), None);
+    }
+
+    #[test]
+    fn connect_options_builder() {
+        let mut opt = ConnectOptions::new("sqlite::memory:".to_owned());
+        opt.max_connections(10)
+            .min_connections(2)
+            .connect_timeout(Duration::from_secs(8))
+            .idle_timeout(Duration::from_secs(60))
+            .max_lifetime(Duration::from_secs(600))
+            .sqlx_logging(false)
+            .sqlx_logging_level(log::LevelFilter::Debug)
+            .sqlx_slow_statements_logging_settings(
+                log::LevelFilter::Error,
+                Duration::from_millis(500),
+            )
+            .schema_search_path("my_schema, public");
+
+        assert_eq!(opt.get_max_connections(), Some(10));
+        assert_eq!(opt.get_min_connections(), Some(2));
+        assert_eq!(opt.get_connect_timeout(), Some(Duration::from_secs(8)));
+        assert_eq!(opt.get_idle_timeout(), Some(Duration::from_secs(60)));
+        assert_eq!(opt.get_max_lifetime(), Some(Duration::from_secs(600)));
+        assert!(!opt.get_sqlx_logging());
+        assert_eq!(opt.get_sqlx_logging_level(), log::LevelFilter::Debug);
+        assert_eq!(
+            opt.get_sqlx_slow_statements_logging_level(),
+            log::LevelFilter::Error
+        );
+        assert_eq!(
+            opt.get_sqlx_slow_statements_logging_threshold(),
+            Duration::from_millis(500)
+        );
+        assert_eq!(opt.get_schema_search_path(), Some("my_schema, public"));
+    }
+
+    #[test]
+    #[cfg(feature = "sqlx-sqlite")]
+    fn connect_options_apply_sqlx_logging() {
+        let mut opt = ConnectOptions::new("sqlite::memory:".to_owned());
+        opt.sqlx_logging_level(log::LevelFilter::Debug)
+            .sqlx_slow_statements_logging_settings(
+                log::LevelFilter::Warn,
+                Duration::from_millis(250),
+            );
+
+        let mut sqlx_opt = sqlx::sqlite::SqliteConnectOptions::new();
+        opt.apply_sqlx_logging(&mut sqlx_opt);
+        let debug = format!("{:?}", sqlx_opt);
+        assert!(debug.contains("statements_level: Debug"));
+        assert!(debug.contains("slow_statements_level: Warn"));
+        assert!(debug.contains("slow_statements_duration: 250ms"));
+
+        opt.sqlx_logging(false);
+        let mut sqlx_opt = sqlx::sqlite::SqliteConnectOptions::new();
+        opt.apply_sqlx_logging(&mut sqlx_opt);
+        let debug = format!("{:?}", sqlx_opt);
+        assert!(debug.contains("statements_level: Off"));
+        assert!(debug.contains("slow_statements_level: Off"));
+    }
 }
@@ -0,0 +1,159 @@
+use crate::DbErr;
+use std::time::Duration;
+
+/// Defines how [DatabaseConnection::transaction_with_retry](crate::DatabaseConnection::transaction_with_retry)
+/// retries a transaction that failed with a transient error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one
+    pub(crate) max_attempts: u32,
+    /// Delay before the first retry, doubled after every failed retry
+    pub(crate) backoff: Duration,
+    /// Upper bound of the delay between two attempts
+    pub(crate) max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+impl RetryPolicy {
+    /// Create a new [RetryPolicy] making at most `max_attempts` attempts,
+    /// waiting 100 milliseconds before the first retry
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+
+    /// Set the maximum number of attempts, including the first one
+    pub fn max_attempts(&mut self, value: u32) -> &mut Self {
+        self.max_attempts = value;
+        self
+    }
+
+    /// Get the maximum number of attempts
+    pub fn get_max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Set the delay before the first retry, it is doubled after every failed retry
+    pub fn backoff(&mut self, value: Duration) -> &mut Self {
+        self.backoff = value;
+        self
+    }
+
+    /// Get the delay before the first retry
+    pub fn get_backoff(&self) -> Duration {
+        self.backoff
+    }
+
+    /// Set the upper bound of the delay between two attempts
+    pub fn max_backoff(&mut self, value: Duration) -> &mut Self {
+        self.max_backoff = value;
+        self
+    }
+
+    /// Get the upper bound of the delay between two attempts
+    pub fn get_max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    /// The delay to wait after the given failed attempt, counting from 1
+    pub(crate) fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.backoff
+            .checked_mul(factor)
+            .map_or(self.max_backoff, |delay| delay.min(self.max_backoff))
+    }
+
+    /// Whether a failed transaction is worth retrying: a serialization failure (`40001`)
+    /// or a deadlock (`40P01`), which the database has rolled back
+    pub(crate) fn is_transient(err: &DbErr) -> bool {
+        matches!(err.sql_state(), Some("40001") | Some("40P01"))
+    }
+
+    /// Whether a failure to begin a transaction is worth retrying, which includes a lost
+    /// connection, as nothing has been executed yet
+    pub(crate) fn is_transient_begin(err: &DbErr) -> bool {
+        matches!(err, DbErr::Conn(_)) || Self::is_transient(err)
+    }
+}
+
+/// Wait for the given duration with the timer of the async runtime
+pub(crate) async fn sleep(duration: Duration) {
+    if duration.is_zero() {
+        return;
+    }
+    #[cfg(any(feature = "runtime-tokio", feature = "runtime-actix"))]
+    tokio::time::sleep(duration).await;
+    #[cfg(all(
+        feature = "runtime-async-std",
+        not(any(feature = "runtime-tokio", feature = "runtime-actix"))
+    ))]
+    async_std::task::sleep(duration).await;
+    // Without a runtime, i.e. with the mock only, wait on another thread
+    #[cfg(not(any(
+        feature = "runtime-tokio",
+        feature = "runtime-actix",
+        feature = "runtime-async-std"
+    )))]
+    {
+        let (tx, rx) = futures::channel::oneshot::channel();
+        std::thread::spawn(move || {
+            std::thread::sleep(duration);
+            let _ = tx.send(());
+        });
+        let _ = rx.await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RetryPolicy;
+    use crate::{DatabaseErr, DbErr};
+    use std::time::Duration;
+
+    #[test]
+    fn delay() {
+        let mut policy = RetryPolicy::new(10);
+        policy
+            .backoff(Duration::from_millis(50))
+            .max_backoff(Duration::from_millis(300));
+
+        assert_eq!(policy.delay(1), Duration::from_millis(50));
+        assert_eq!(policy.delay(2), Duration::from_millis(100));
+        assert_eq!(policy.delay(3), Duration::from_millis(200));
+        assert_eq!(policy.delay(4), Duration::from_millis(300));
+        assert_eq!(policy.delay(100), Duration::from_millis(300));
+    }
+
+    #[test]
+    fn is_transient() {
+        assert!(!RetryPolicy::is_transient(&DbErr::Conn(
+            "Connection reset".to_owned()
+        )));
+        assert!(RetryPolicy::is_transient_begin(&DbErr::Conn(
+            "Connection reset".to_owned()
+        )));
+        assert!(RetryPolicy::is_transient_begin(&DbErr::Database(
+            DatabaseErr::new("deadlock detected").sql_state("40P01")
+        )));
+        assert!(RetryPolicy::is_transient(&DbErr::Database(
+            DatabaseErr::new("could not serialize access").sql_state("40001")
+        )));
+        assert!(RetryPolicy::is_transient(&DbErr::Database(
+            DatabaseErr::new("deadlock detected").sql_state("40P01")
+        )));
+        assert!(!RetryPolicy::is_transient(&DbErr::Database(
+            DatabaseErr::new("duplicate key").sql_state("23505")
+        )));
+        assert!(!RetryPolicy::is_transient(&DbErr::Custom(
+            "error".to_owned()
+        )));
+    }
+}
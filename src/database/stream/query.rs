@@ -7,7 +7,10 @@ use std::sync::Arc;
 
 use futures::Stream;
 #[cfg(feature = "sqlx-dep")]
-use futures::TryStreamExt;
+use futures::{
+    future::{ready, Either},
+    stream, TryStreamExt,
+};
 
 #[cfg(feature = "sqlx-dep")]
 use sqlx::{pool::PoolConnection, Executor};
@@ -127,10 +130,14 @@ impl QueryStream {
                 InnerConnection::MySql(c) => {
                     let query = crate::driver::sqlx_mysql::sqlx_query(stmt);
                     let _start = _metric_callback.is_some().then(std::time::SystemTime::now);
-                    let stream = c
-                        .fetch(query)
-                        .map_ok(Into::into)
-                        .map_err(crate::sqlx_error_to_query_err);
+                    let stream = match query {
+                        Ok(query) => Either::Left(
+                            c.fetch(query)
+                                .map_ok(Into::into)
+                                .map_err(crate::sqlx_error_to_query_err),
+                        ),
+                        Err(err) => Either::Right(stream::once(ready(Err(DbErr::Query(err))))),
+                    };
                     let elapsed = _start.map(|s| s.elapsed().unwrap_or_default());
                     MetricStream::new(_metric_callback, stmt, elapsed, stream)
                 }
@@ -138,10 +145,14 @@ impl QueryStream {
                 InnerConnection::Postgres(c) => {
                     let query = crate::driver::sqlx_postgres::sqlx_query(stmt);
                     let _start = _metric_callback.is_some().then(std::time::SystemTime::now);
-                    let stream = c
-                        .fetch(query)
-                        .map_ok(Into::into)
-                        .map_err(crate::sqlx_error_to_query_err);
+                    let stream = match query {
+                        Ok(query) => Either::Left(
+                            c.fetch(query)
+                                .map_ok(Into::into)
+                                .map_err(crate::sqlx_error_to_query_err),
+                        ),
+                        Err(err) => Either::Right(stream::once(ready(Err(DbErr::Query(err))))),
+                    };
                     let elapsed = _start.map(|s| s.elapsed().unwrap_or_default());
                     MetricStream::new(_metric_callback, stmt, elapsed, stream)
                 }
@@ -149,10 +160,14 @@ impl QueryStream {
                 InnerConnection::Sqlite(c) => {
                     let query = crate::driver::sqlx_sqlite::sqlx_query(stmt);
                     let _start = _metric_callback.is_some().then(std::time::SystemTime::now);
-                    let stream = c
-                        .fetch(query)
-                        .map_ok(Into::into)
-                        .map_err(crate::sqlx_error_to_query_err);
+                    let stream = match query {
+                        Ok(query) => Either::Left(
+                            c.fetch(query)
+                                .map_ok(Into::into)
+                                .map_err(crate::sqlx_error_to_query_err),
+                        ),
+                        Err(err) => Either::Right(stream::once(ready(Err(DbErr::Query(err))))),
+                    };
                     let elapsed = _start.map(|s| s.elapsed().unwrap_or_default());
                     MetricStream::new(_metric_callback, stmt, elapsed, stream)
                 }
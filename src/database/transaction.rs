@@ -3,7 +3,7 @@ use crate::{
     Statement, StreamTrait, TransactionStream, TransactionTrait,
 };
 #[cfg(feature = "sqlx-dep")]
-use crate::{sqlx_error_to_exec_err, sqlx_error_to_query_err};
+use crate::{sqlx_error_to_begin_err, sqlx_error_to_exec_err, sqlx_error_to_query_err};
 use futures::lock::Mutex;
 #[cfg(feature = "sqlx-dep")]
 use sqlx::{pool::PoolConnection, TransactionManager};
@@ -31,11 +31,15 @@ impl DatabaseTransaction {
     pub(crate) async fn new_mysql(
         inner: PoolConnection<sqlx::MySql>,
         metric_callback: Option<crate::metric::Callback>,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
     ) -> Result<DatabaseTransaction, DbErr> {
         Self::begin(
             Arc::new(Mutex::new(InnerConnection::MySql(inner))),
             DbBackend::MySql,
             metric_callback,
+            isolation_level,
+            access_mode,
         )
         .await
     }
@@ -44,11 +48,15 @@ impl DatabaseTransaction {
     pub(crate) async fn new_postgres(
         inner: PoolConnection<sqlx::Postgres>,
         metric_callback: Option<crate::metric::Callback>,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
     ) -> Result<DatabaseTransaction, DbErr> {
         Self::begin(
             Arc::new(Mutex::new(InnerConnection::Postgres(inner))),
             DbBackend::Postgres,
             metric_callback,
+            isolation_level,
+            access_mode,
         )
         .await
     }
@@ -57,11 +65,15 @@ impl DatabaseTransaction {
     pub(crate) async fn new_sqlite(
         inner: PoolConnection<sqlx::Sqlite>,
         metric_callback: Option<crate::metric::Callback>,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
     ) -> Result<DatabaseTransaction, DbErr> {
         Self::begin(
             Arc::new(Mutex::new(InnerConnection::Sqlite(inner))),
             DbBackend::Sqlite,
             metric_callback,
+            isolation_level,
+            access_mode,
         )
         .await
     }
@@ -70,12 +82,16 @@ impl DatabaseTransaction {
     pub(crate) async fn new_mock(
         inner: Arc<crate::MockDatabaseConnection>,
         metric_callback: Option<crate::metric::Callback>,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
     ) -> Result<DatabaseTransaction, DbErr> {
         let backend = inner.get_database_backend();
         Self::begin(
             Arc::new(Mutex::new(InnerConnection::Mock(inner))),
             backend,
             metric_callback,
+            isolation_level,
+            access_mode,
         )
         .await
     }
@@ -86,6 +102,8 @@ impl DatabaseTransaction {
         conn: Arc<Mutex<InnerConnection>>,
         backend: DbBackend,
         metric_callback: Option<crate::metric::Callback>,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
     ) -> Result<DatabaseTransaction, DbErr> {
         let res = DatabaseTransaction {
             conn,
@@ -93,33 +111,83 @@ impl DatabaseTransaction {
             open: true,
             metric_callback,
         };
+        let _config = Self::set_transaction_config(backend, isolation_level, access_mode);
         match *res.conn.lock().await {
             #[cfg(feature = "sqlx-mysql")]
             InnerConnection::MySql(ref mut c) => {
+                // MySQL applies the characteristics to the next transaction, so set them first
+                if let Some(stmt) = &_config {
+                    crate::driver::sqlx_mysql::sqlx_query(stmt)
+                        .map_err(DbErr::Exec)?
+                        .execute(&mut *c)
+                        .await
+                        .map_err(sqlx_error_to_exec_err)?;
+                }
                 <sqlx::MySql as sqlx::Database>::TransactionManager::begin(c)
                     .await
-                    .map_err(sqlx_error_to_query_err)?
+                    .map_err(sqlx_error_to_begin_err)?
             }
             #[cfg(feature = "sqlx-postgres")]
             InnerConnection::Postgres(ref mut c) => {
                 <sqlx::Postgres as sqlx::Database>::TransactionManager::begin(c)
                     .await
-                    .map_err(sqlx_error_to_query_err)?
+                    .map_err(sqlx_error_to_begin_err)?;
+                if let Some(stmt) = &_config {
+                    crate::driver::sqlx_postgres::sqlx_query(stmt)
+                        .map_err(DbErr::Exec)?
+                        .execute(&mut *c)
+                        .await
+                        .map_err(sqlx_error_to_exec_err)?;
+                }
             }
             #[cfg(feature = "sqlx-sqlite")]
             InnerConnection::Sqlite(ref mut c) => {
                 <sqlx::Sqlite as sqlx::Database>::TransactionManager::begin(c)
                     .await
-                    .map_err(sqlx_error_to_query_err)?
+                    .map_err(sqlx_error_to_begin_err)?
             }
             #[cfg(feature = "mock")]
-            InnerConnection::Mock(ref mut c) => {
-                c.begin();
-            }
+            InnerConnection::Mock(ref mut c) => match (backend, _config) {
+                (DbBackend::MySql, Some(stmt)) => {
+                    c.log(stmt);
+                    c.begin();
+                }
+                (_, Some(stmt)) => {
+                    c.begin();
+                    c.log(stmt);
+                }
+                (_, None) => c.begin(),
+            },
         }
         Ok(res)
     }
 
+    /// The `SET TRANSACTION` statement applying the isolation level and access mode.
+    /// SQLite has no such statement, so they are ignored there.
+    fn set_transaction_config(
+        backend: DbBackend,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Option<Statement> {
+        if backend == DbBackend::Sqlite {
+            return None;
+        }
+        let mut characteristics = Vec::new();
+        if let Some(isolation_level) = isolation_level {
+            characteristics.push(format!("ISOLATION LEVEL {}", isolation_level));
+        }
+        if let Some(access_mode) = access_mode {
+            characteristics.push(access_mode.to_string());
+        }
+        if characteristics.is_empty() {
+            return None;
+        }
+        Some(Statement::from_string(
+            backend,
+            format!("SET TRANSACTION {}", characteristics.join(", ")),
+        ))
+    }
+
     /// Runs a transaction to completion returning an rolling back the transaction on
     /// encountering an error if it fails
     #[instrument(level = "trace", skip(callback))]
@@ -261,21 +329,21 @@ impl ConnectionTrait for DatabaseTransaction {
         let _res = match &mut *self.conn.lock().await {
             #[cfg(feature = "sqlx-mysql")]
             InnerConnection::MySql(conn) => {
-                let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
+                let query = crate::driver::sqlx_mysql::sqlx_query(&stmt).map_err(DbErr::Exec)?;
                 crate::metric::metric!(self.metric_callback, &stmt, {
                     query.execute(conn).await.map(Into::into)
                 })
             }
             #[cfg(feature = "sqlx-postgres")]
             InnerConnection::Postgres(conn) => {
-                let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
+                let query = crate::driver::sqlx_postgres::sqlx_query(&stmt).map_err(DbErr::Exec)?;
                 crate::metric::metric!(self.metric_callback, &stmt, {
                     query.execute(conn).await.map(Into::into)
                 })
             }
             #[cfg(feature = "sqlx-sqlite")]
             InnerConnection::Sqlite(conn) => {
-                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
+                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt).map_err(DbErr::Exec)?;
                 crate::metric::metric!(self.metric_callback, &stmt, {
                     query.execute(conn).await.map(Into::into)
                 })
@@ -289,6 +357,46 @@ impl ConnectionTrait for DatabaseTransaction {
         _res.map_err(sqlx_error_to_exec_err)
     }
 
+    #[instrument(level = "trace")]
+    #[allow(unused_variables)]
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        debug_print!("{}", sql);
+
+        let stmt = Statement::from_string(self.backend, sql.to_owned());
+        let _res = match &mut *self.conn.lock().await {
+            #[cfg(feature = "sqlx-mysql")]
+            InnerConnection::MySql(conn) => {
+                crate::metric::metric!(self.metric_callback, &stmt, {
+                    sqlx::Executor::execute(&mut **conn, sql)
+                        .await
+                        .map(Into::into)
+                })
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            InnerConnection::Postgres(conn) => {
+                crate::metric::metric!(self.metric_callback, &stmt, {
+                    sqlx::Executor::execute(&mut **conn, sql)
+                        .await
+                        .map(Into::into)
+                })
+            }
+            #[cfg(feature = "sqlx-sqlite")]
+            InnerConnection::Sqlite(conn) => {
+                crate::metric::metric!(self.metric_callback, &stmt, {
+                    sqlx::Executor::execute(&mut **conn, sql)
+                        .await
+                        .map(Into::into)
+                })
+            }
+            #[cfg(feature = "mock")]
+            InnerConnection::Mock(conn) => return conn.execute(stmt),
+            #[allow(unreachable_patterns)]
+            _ => unreachable!(),
+        };
+        #[cfg(feature = "sqlx-dep")]
+        _res.map_err(sqlx_error_to_exec_err)
+    }
+
     #[instrument(level = "trace")]
     #[allow(unused_variables)]
     async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
@@ -297,17 +405,18 @@ impl ConnectionTrait for DatabaseTransaction {
         let _res = match &mut *self.conn.lock().await {
             #[cfg(feature = "sqlx-mysql")]
             InnerConnection::MySql(conn) => {
-                let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
+                let query = crate::driver::sqlx_mysql::sqlx_query(&stmt).map_err(DbErr::Query)?;
                 query.fetch_one(conn).await.map(|row| Some(row.into()))
             }
             #[cfg(feature = "sqlx-postgres")]
             InnerConnection::Postgres(conn) => {
-                let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
+                let query =
+                    crate::driver::sqlx_postgres::sqlx_query(&stmt).map_err(DbErr::Query)?;
                 query.fetch_one(conn).await.map(|row| Some(row.into()))
             }
             #[cfg(feature = "sqlx-sqlite")]
             InnerConnection::Sqlite(conn) => {
-                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
+                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt).map_err(DbErr::Query)?;
                 query.fetch_one(conn).await.map(|row| Some(row.into()))
             }
             #[cfg(feature = "mock")]
@@ -331,7 +440,7 @@ impl ConnectionTrait for DatabaseTransaction {
         let _res = match &mut *self.conn.lock().await {
             #[cfg(feature = "sqlx-mysql")]
             InnerConnection::MySql(conn) => {
-                let query = crate::driver::sqlx_mysql::sqlx_query(&stmt);
+                let query = crate::driver::sqlx_mysql::sqlx_query(&stmt).map_err(DbErr::Query)?;
                 query
                     .fetch_all(conn)
                     .await
@@ -339,7 +448,8 @@ impl ConnectionTrait for DatabaseTransaction {
             }
             #[cfg(feature = "sqlx-postgres")]
             InnerConnection::Postgres(conn) => {
-                let query = crate::driver::sqlx_postgres::sqlx_query(&stmt);
+                let query =
+                    crate::driver::sqlx_postgres::sqlx_query(&stmt).map_err(DbErr::Query)?;
                 query
                     .fetch_all(conn)
                     .await
@@ -347,7 +457,7 @@ impl ConnectionTrait for DatabaseTransaction {
             }
             #[cfg(feature = "sqlx-sqlite")]
             InnerConnection::Sqlite(conn) => {
-                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt);
+                let query = crate::driver::sqlx_sqlite::sqlx_query(&stmt).map_err(DbErr::Query)?;
                 query
                     .fetch_all(conn)
                     .await
@@ -392,10 +502,23 @@ impl TransactionTrait for DatabaseTransaction {
             Arc::clone(&self.conn),
             self.backend,
             self.metric_callback.clone(),
+            None,
+            None,
         )
         .await
     }
 
+    /// A nested transaction shares the isolation level and access mode of the outer transaction,
+    /// so the given ones are ignored.
+    #[instrument(level = "trace")]
+    async fn begin_with_config(
+        &self,
+        _isolation_level: Option<IsolationLevel>,
+        _access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        self.begin().await
+    }
+
     /// Execute the function inside a transaction.
     /// If the function returns an error, the transaction will be rolled back. If it does not return an error, the transaction will be committed.
     #[instrument(level = "trace", skip(_callback))]
@@ -438,3 +561,45 @@ where
 }
 
 impl<E> std::error::Error for TransactionError<E> where E: std::error::Error {}
+
+/// Defines the isolation level of a transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsolationLevel {
+    /// Queries may see changes not yet committed by other transactions
+    ReadUncommitted,
+    /// Each query only sees changes committed before it began
+    ReadCommitted,
+    /// All queries only see changes committed before the first query of the transaction
+    RepeatableRead,
+    /// Transactions behave as if they were executed one after another
+    Serializable,
+}
+
+impl std::fmt::Display for IsolationLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IsolationLevel::ReadUncommitted => write!(f, "READ UNCOMMITTED"),
+            IsolationLevel::ReadCommitted => write!(f, "READ COMMITTED"),
+            IsolationLevel::RepeatableRead => write!(f, "REPEATABLE READ"),
+            IsolationLevel::Serializable => write!(f, "SERIALIZABLE"),
+        }
+    }
+}
+
+/// Defines the access mode of a transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessMode {
+    /// The transaction cannot modify the database
+    ReadOnly,
+    /// The transaction can read and modify the database
+    ReadWrite,
+}
+
+impl std::fmt::Display for AccessMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccessMode::ReadOnly => write!(f, "READ ONLY"),
+            AccessMode::ReadWrite => write!(f, "READ WRITE"),
+        }
+    }
+}
@@ -42,11 +42,23 @@ pub trait MockDatabaseTrait: Send + Debug {
     /// Roll back a transaction since errors were encountered
     fn rollback(&mut self);
 
+    /// Log a statement without consuming any of the mocked results,
+    /// e.g. the `SET TRANSACTION` statement of [TransactionTrait::begin_with_config](crate::TransactionTrait::begin_with_config)
+    fn log(&mut self, _stmt: Statement) {}
+
     /// Get all logs from a [MockDatabase] and return a [Transaction]
     fn drain_transaction_log(&mut self) -> Vec<Transaction>;
 
+    /// Panic if the statements issued so far do not match the expectations of the [MockDatabase]
+    fn verify_expectations(&self) {}
+
     /// Get the backend being used in the [MockDatabase]
     fn get_database_backend(&self) -> DbBackend;
+
+    /// Ping the [MockDatabase], which is always reachable by default
+    fn ping(&self) -> Result<(), DbErr> {
+        Ok(())
+    }
 }
 
 impl MockDatabaseConnector {
@@ -69,9 +81,15 @@ impl MockDatabaseConnector {
     }
 
     /// Connect to the [MockDatabase]
-    #[allow(unused_variables)]
     #[instrument(level = "trace")]
     pub async fn connect(string: &str) -> Result<DatabaseConnection, DbErr> {
+        Self::connect_lazy(string)
+    }
+
+    /// Connect to the [MockDatabase], which never involves any I/O
+    #[allow(unused_variables)]
+    #[instrument(level = "trace")]
+    pub fn connect_lazy(string: &str) -> Result<DatabaseConnection, DbErr> {
         macro_rules! connect_mock_db {
             ( $syntax: expr ) => {
                 Ok(DatabaseConnection::MockDatabaseConnection(Arc::new(
@@ -126,6 +144,15 @@ impl MockDatabaseConnection {
         self.mocker.lock().unwrap().execute(counter, statement)
     }
 
+    /// Execute an unprepared SQL statement in the [MockDatabase]
+    #[instrument(level = "trace")]
+    pub fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        self.execute(Statement::from_string(
+            self.get_database_backend(),
+            sql.to_owned(),
+        ))
+    }
+
     /// Return one [QueryResult] if the query was successful
     #[instrument(level = "trace")]
     pub fn query_one(&self, statement: Statement) -> Result<Option<QueryResult>, DbErr> {
@@ -172,4 +199,16 @@ impl MockDatabaseConnection {
     pub fn rollback(&self) {
         self.mocker.lock().unwrap().rollback()
     }
+
+    /// Log a statement in the [MockDatabase] without executing it
+    #[instrument(level = "trace")]
+    pub fn log(&self, statement: Statement) {
+        debug_print!("{}", statement);
+        self.mocker.lock().unwrap().log(statement)
+    }
+
+    /// Checks if a connection to the database is still valid.
+    pub fn ping(&self) -> Result<(), DbErr> {
+        self.mocker.lock().unwrap().ping()
+    }
 }
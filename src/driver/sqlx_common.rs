@@ -1,16 +1,109 @@
-use crate::DbErr;
+use crate::{DatabaseErr, DbErr, SqlErr};
 
 /// Converts an [sqlx::error] execution error to a [DbErr]
 pub fn sqlx_error_to_exec_err(err: sqlx::Error) -> DbErr {
-    DbErr::Exec(err.to_string())
+    match sqlx_error_to_database_err(&err) {
+        Some(err) => DbErr::Database(err),
+        None => DbErr::Exec(err.to_string()),
+    }
 }
 
 /// Converts an [sqlx::error] query error to a [DbErr]
 pub fn sqlx_error_to_query_err(err: sqlx::Error) -> DbErr {
-    DbErr::Query(err.to_string())
+    match sqlx_error_to_database_err(&err) {
+        Some(err) => DbErr::Database(err),
+        None => DbErr::Query(err.to_string()),
+    }
+}
+
+/// The error of a failed attempt to acquire a connection from the pool, built with `err`
+/// unless the pool has been closed
+pub(crate) fn sqlx_acquire_err<DB>(pool: &sqlx::Pool<DB>, err: fn(String) -> DbErr) -> DbErr
+where
+    DB: sqlx::Database,
+{
+    if pool.is_closed() {
+        DbErr::Conn("pool closed".to_owned())
+    } else {
+        err("Failed to acquire connection from pool.".to_owned())
+    }
 }
 
 /// Converts an [sqlx::error] connection error to a [DbErr]
 pub fn sqlx_error_to_conn_err(err: sqlx::Error) -> DbErr {
     DbErr::Conn(err.to_string())
 }
+
+/// Converts an [sqlx::error] error beginning a transaction to a [DbErr], which is a
+/// [DbErr::Conn] if the connection was lost or none could be acquired in time
+pub(crate) fn sqlx_error_to_begin_err(err: sqlx::Error) -> DbErr {
+    match err {
+        sqlx::Error::Io(_) | sqlx::Error::PoolTimedOut => sqlx_error_to_conn_err(err),
+        _ => sqlx_error_to_query_err(err),
+    }
+}
+
+/// Extract the SQLSTATE code and the class of an error reported by the database
+fn sqlx_error_to_database_err(err: &sqlx::Error) -> Option<DatabaseErr> {
+    let db_err = match err {
+        sqlx::Error::Database(db_err) => db_err,
+        _ => return None,
+    };
+    #[allow(unused_mut)]
+    let mut database_err = DatabaseErr::new(&err.to_string());
+
+    #[cfg(feature = "sqlx-mysql")]
+    if let Some(db_err) = db_err.try_downcast_ref::<sqlx::mysql::MySqlDatabaseError>() {
+        // MySQL reports SQLSTATE `23000` for all integrity constraint violations,
+        // the error number tells them apart
+        let sql_err = match db_err.number() {
+            1062 | 1586 => Some(SqlErr::UniqueViolation(None)),
+            1216 | 1217 | 1451 | 1452 => Some(SqlErr::ForeignKeyViolation(None)),
+            1048 => Some(SqlErr::NotNullViolation(None)),
+            3819 => Some(SqlErr::CheckViolation(None)),
+            _ => None,
+        };
+        database_err.sql_state = db_err.code().map(ToOwned::to_owned);
+        database_err.sql_err = sql_err;
+    }
+
+    #[cfg(feature = "sqlx-postgres")]
+    if let Some(db_err) = db_err.try_downcast_ref::<sqlx::postgres::PgDatabaseError>() {
+        let constraint = db_err.constraint().map(ToOwned::to_owned);
+        let sql_err = match db_err.code() {
+            "23505" => Some(SqlErr::UniqueViolation(constraint)),
+            "23503" => Some(SqlErr::ForeignKeyViolation(constraint)),
+            "23502" => Some(SqlErr::NotNullViolation(
+                db_err.column().map(ToOwned::to_owned),
+            )),
+            "23514" => Some(SqlErr::CheckViolation(constraint)),
+            _ => None,
+        };
+        database_err.sql_state = Some(db_err.code().to_owned());
+        database_err.sql_err = sql_err;
+    }
+
+    #[cfg(feature = "sqlx-sqlite")]
+    if let Some(db_err) = db_err.try_downcast_ref::<sqlx::sqlite::SqliteError>() {
+        use sqlx::error::DatabaseError;
+
+        // SQLite reports extended result codes instead of SQLSTATE codes
+        let sql_err = match db_err.code().as_deref() {
+            Some("1555") | Some("2067") => Some(SqlErr::UniqueViolation(None)),
+            Some("787") => Some(SqlErr::ForeignKeyViolation(None)),
+            Some("1299") => Some(SqlErr::NotNullViolation(None)),
+            Some("275") => Some(SqlErr::CheckViolation(None)),
+            _ => None,
+        };
+        database_err.sql_err = sql_err;
+    }
+
+    #[cfg(not(any(
+        feature = "sqlx-mysql",
+        feature = "sqlx-postgres",
+        feature = "sqlx-sqlite"
+    )))]
+    let _ = db_err;
+
+    Some(database_err)
+}
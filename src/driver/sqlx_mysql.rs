@@ -2,7 +2,7 @@ use std::{future::Future, pin::Pin, sync::Arc};
 
 use sqlx::{
     mysql::{MySqlArguments, MySqlConnectOptions, MySqlQueryResult, MySqlRow},
-    MySql, MySqlPool,
+    Connection, Executor, MySql, MySqlPool,
 };
 
 sea_query::sea_query_driver_mysql!();
@@ -10,8 +10,8 @@ use sea_query_driver_mysql::bind_query;
 use tracing::instrument;
 
 use crate::{
-    debug_print, error::*, executor::*, ConnectOptions, DatabaseConnection, DatabaseTransaction,
-    QueryStream, Statement, TransactionError,
+    debug_print, error::*, executor::*, AccessMode, ConnectOptions, DatabaseConnection,
+    DatabaseTransaction, DbBackend, IsolationLevel, QueryStream, Statement, TransactionError,
 };
 
 use super::sqlx_common::*;
@@ -23,7 +23,7 @@ pub struct SqlxMySqlConnector;
 /// Defines a sqlx MySQL pool
 #[derive(Clone)]
 pub struct SqlxMySqlPoolConnection {
-    pool: MySqlPool,
+    pub(crate) pool: MySqlPool,
     metric_callback: Option<crate::metric::Callback>,
 }
 
@@ -46,12 +46,7 @@ impl SqlxMySqlConnector {
             .url
             .parse::<MySqlConnectOptions>()
             .map_err(|e| DbErr::Conn(e.to_string()))?;
-        use sqlx::ConnectOptions;
-        if !options.sqlx_logging {
-            opt.disable_statement_logging();
-        } else {
-            opt.log_statements(options.sqlx_logging_level);
-        }
+        options.apply_sqlx_logging(&mut opt);
         match options.pool_options().connect_with(opt).await {
             Ok(pool) => Ok(DatabaseConnection::SqlxMySqlPoolConnection(
                 SqlxMySqlPoolConnection {
@@ -62,6 +57,23 @@ impl SqlxMySqlConnector {
             Err(e) => Err(sqlx_error_to_conn_err(e)),
         }
     }
+
+    /// Create a pool for the MySQL database without connecting to it.
+    /// Connections are opened when they are first needed.
+    #[instrument(level = "trace")]
+    pub fn connect_lazy(options: ConnectOptions) -> Result<DatabaseConnection, DbErr> {
+        let mut opt = options
+            .url
+            .parse::<MySqlConnectOptions>()
+            .map_err(|e| DbErr::Conn(e.to_string()))?;
+        options.apply_sqlx_logging(&mut opt);
+        Ok(DatabaseConnection::SqlxMySqlPoolConnection(
+            SqlxMySqlPoolConnection {
+                pool: options.pool_options().connect_lazy_with(opt),
+                metric_callback: None,
+            },
+        ))
+    }
 }
 
 impl SqlxMySqlConnector {
@@ -80,7 +92,7 @@ impl SqlxMySqlPoolConnection {
     pub async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
+        let query = sqlx_query(&stmt).map_err(DbErr::Exec)?;
         if let Ok(conn) = &mut self.pool.acquire().await {
             crate::metric::metric!(self.metric_callback, &stmt, {
                 match query.execute(conn).await {
@@ -89,9 +101,25 @@ impl SqlxMySqlPoolConnection {
                 }
             })
         } else {
-            Err(DbErr::Exec(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+            Err(sqlx_acquire_err(&self.pool, DbErr::Exec))
+        }
+    }
+
+    /// Execute an unprepared SQL statement on a MySQL backend
+    #[instrument(level = "trace")]
+    pub async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        debug_print!("{}", sql);
+
+        let stmt = Statement::from_string(DbBackend::MySql, sql.to_owned());
+        if let Ok(conn) = &mut self.pool.acquire().await {
+            crate::metric::metric!(self.metric_callback, &stmt, {
+                match conn.execute(sql).await {
+                    Ok(res) => Ok(res.into()),
+                    Err(err) => Err(sqlx_error_to_exec_err(err)),
+                }
+            })
+        } else {
+            Err(sqlx_acquire_err(&self.pool, DbErr::Exec))
         }
     }
 
@@ -100,7 +128,7 @@ impl SqlxMySqlPoolConnection {
     pub async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
+        let query = sqlx_query(&stmt).map_err(DbErr::Query)?;
         if let Ok(conn) = &mut self.pool.acquire().await {
             crate::metric::metric!(self.metric_callback, &stmt, {
                 match query.fetch_one(conn).await {
@@ -112,9 +140,7 @@ impl SqlxMySqlPoolConnection {
                 }
             })
         } else {
-            Err(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+            Err(sqlx_acquire_err(&self.pool, DbErr::Query))
         }
     }
 
@@ -123,7 +149,7 @@ impl SqlxMySqlPoolConnection {
     pub async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
+        let query = sqlx_query(&stmt).map_err(DbErr::Query)?;
         if let Ok(conn) = &mut self.pool.acquire().await {
             crate::metric::metric!(self.metric_callback, &stmt, {
                 match query.fetch_all(conn).await {
@@ -132,9 +158,7 @@ impl SqlxMySqlPoolConnection {
                 }
             })
         } else {
-            Err(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+            Err(sqlx_acquire_err(&self.pool, DbErr::Query))
         }
     }
 
@@ -150,21 +174,33 @@ impl SqlxMySqlPoolConnection {
                 self.metric_callback.clone(),
             )))
         } else {
-            Err(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+            Err(sqlx_acquire_err(&self.pool, DbErr::Query))
         }
     }
 
     /// Bundle a set of SQL statements that execute together.
     #[instrument(level = "trace")]
     pub async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+        self.begin_with_config(None, None).await
+    }
+
+    /// Bundle a set of SQL statements that execute together, with the given isolation level and access mode.
+    #[instrument(level = "trace")]
+    pub async fn begin_with_config(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
         if let Ok(conn) = self.pool.acquire().await {
-            DatabaseTransaction::new_mysql(conn, self.metric_callback.clone()).await
+            DatabaseTransaction::new_mysql(
+                conn,
+                self.metric_callback.clone(),
+                isolation_level,
+                access_mode,
+            )
+            .await
         } else {
-            Err(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+            Err(sqlx_acquire_err(&self.pool, DbErr::Conn))
         }
     }
 
@@ -180,13 +216,15 @@ impl SqlxMySqlPoolConnection {
         E: std::error::Error + Send,
     {
         if let Ok(conn) = self.pool.acquire().await {
-            let transaction = DatabaseTransaction::new_mysql(conn, self.metric_callback.clone())
-                .await
-                .map_err(|e| TransactionError::Connection(e))?;
+            let transaction =
+                DatabaseTransaction::new_mysql(conn, self.metric_callback.clone(), None, None)
+                    .await
+                    .map_err(|e| TransactionError::Connection(e))?;
             transaction.run(callback).await
         } else {
-            Err(TransactionError::Connection(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
+            Err(TransactionError::Connection(sqlx_acquire_err(
+                &self.pool,
+                DbErr::Query,
             )))
         }
     }
@@ -197,6 +235,20 @@ impl SqlxMySqlPoolConnection {
     {
         self.metric_callback = Some(Arc::new(callback));
     }
+
+    /// Checks if a connection to the database is still valid.
+    #[instrument(level = "trace")]
+    pub async fn ping(&self) -> Result<(), DbErr> {
+        let conn = &mut self.pool.acquire().await.map_err(sqlx_error_to_conn_err)?;
+        conn.ping().await.map_err(sqlx_error_to_conn_err)
+    }
+
+    /// Close the pool, waiting for the connections in use to be returned.
+    /// Any query on the pool afterwards fails with a [DbErr::Conn].
+    #[instrument(level = "trace")]
+    pub async fn close(self) {
+        self.pool.close().await
+    }
 }
 
 impl From<MySqlRow> for QueryResult {
@@ -215,10 +267,17 @@ impl From<MySqlQueryResult> for ExecResult {
     }
 }
 
-pub(crate) fn sqlx_query(stmt: &Statement) -> sqlx::query::Query<'_, MySql, MySqlArguments> {
+/// Bind the values of a [Statement] to an sqlx query, or describe why they cannot be bound
+pub(crate) fn sqlx_query(
+    stmt: &Statement,
+) -> Result<sqlx::query::Query<'_, MySql, MySqlArguments>, String> {
     let mut query = sqlx::query(&stmt.sql);
     if let Some(values) = &stmt.values {
+        #[cfg(feature = "postgres-array")]
+        if values.0.iter().any(|value| value.is_array()) {
+            return Err("Arrays are not supported by MySQL".to_owned());
+        }
         query = bind_query(query, values);
     }
-    query
+    Ok(query)
 }
@@ -2,7 +2,7 @@ use std::{future::Future, pin::Pin, sync::Arc};
 
 use sqlx::{
     postgres::{PgArguments, PgConnectOptions, PgQueryResult, PgRow},
-    PgPool, Postgres,
+    Connection, Executor, PgPool, Postgres,
 };
 
 sea_query::sea_query_driver_postgres!();
@@ -10,8 +10,8 @@ use sea_query_driver_postgres::bind_query;
 use tracing::instrument;
 
 use crate::{
-    debug_print, error::*, executor::*, ConnectOptions, DatabaseConnection, DatabaseTransaction,
-    QueryStream, Statement, TransactionError,
+    debug_print, error::*, executor::*, AccessMode, ConnectOptions, DatabaseConnection,
+    DatabaseTransaction, DbBackend, IsolationLevel, QueryStream, Statement, TransactionError,
 };
 
 use super::sqlx_common::*;
@@ -23,7 +23,7 @@ pub struct SqlxPostgresConnector;
 /// Defines a sqlx PostgreSQL pool
 #[derive(Clone)]
 pub struct SqlxPostgresPoolConnection {
-    pool: PgPool,
+    pub(crate) pool: PgPool,
     metric_callback: Option<crate::metric::Callback>,
 }
 
@@ -46,13 +46,8 @@ impl SqlxPostgresConnector {
             .url
             .parse::<PgConnectOptions>()
             .map_err(|e| DbErr::Conn(e.to_string()))?;
-        use sqlx::ConnectOptions;
-        if !options.sqlx_logging {
-            opt.disable_statement_logging();
-        } else {
-            opt.log_statements(options.sqlx_logging_level);
-        }
-        match options.pool_options().connect_with(opt).await {
+        options.apply_sqlx_logging(&mut opt);
+        match Self::pool_options(options).connect_with(opt).await {
             Ok(pool) => Ok(DatabaseConnection::SqlxPostgresPoolConnection(
                 SqlxPostgresPoolConnection {
                     pool,
@@ -62,6 +57,40 @@ impl SqlxPostgresConnector {
             Err(e) => Err(sqlx_error_to_conn_err(e)),
         }
     }
+
+    /// Create a pool for the PostgreSQL database without connecting to it.
+    /// Connections are opened when they are first needed.
+    #[instrument(level = "trace")]
+    pub fn connect_lazy(options: ConnectOptions) -> Result<DatabaseConnection, DbErr> {
+        let mut opt = options
+            .url
+            .parse::<PgConnectOptions>()
+            .map_err(|e| DbErr::Conn(e.to_string()))?;
+        options.apply_sqlx_logging(&mut opt);
+        Ok(DatabaseConnection::SqlxPostgresPoolConnection(
+            SqlxPostgresPoolConnection {
+                pool: Self::pool_options(options).connect_lazy_with(opt),
+                metric_callback: None,
+            },
+        ))
+    }
+
+    /// Convert [ConnectOptions] into [sqlx::pool::PoolOptions], setting the
+    /// schema search path of every new connection if there is one
+    fn pool_options(options: ConnectOptions) -> sqlx::pool::PoolOptions<Postgres> {
+        let set_search_path_sql = options
+            .schema_search_path
+            .as_ref()
+            .map(|path| format!("SET search_path TO {}", path));
+        let pool_options = options.pool_options();
+        match set_search_path_sql {
+            Some(sql) => pool_options.after_connect(move |conn, _| {
+                let sql = sql.clone();
+                Box::pin(async move { Executor::execute(conn, sql.as_str()).await.map(|_| ()) })
+            }),
+            None => pool_options,
+        }
+    }
 }
 
 impl SqlxPostgresConnector {
@@ -80,7 +109,7 @@ impl SqlxPostgresPoolConnection {
     pub async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
+        let query = sqlx_query(&stmt).map_err(DbErr::Exec)?;
         if let Ok(conn) = &mut self.pool.acquire().await {
             crate::metric::metric!(self.metric_callback, &stmt, {
                 match query.execute(conn).await {
@@ -89,9 +118,25 @@ impl SqlxPostgresPoolConnection {
                 }
             })
         } else {
-            Err(DbErr::Exec(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+            Err(sqlx_acquire_err(&self.pool, DbErr::Exec))
+        }
+    }
+
+    /// Execute an unprepared SQL statement on a PostgreSQL backend
+    #[instrument(level = "trace")]
+    pub async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        debug_print!("{}", sql);
+
+        let stmt = Statement::from_string(DbBackend::Postgres, sql.to_owned());
+        if let Ok(conn) = &mut self.pool.acquire().await {
+            crate::metric::metric!(self.metric_callback, &stmt, {
+                match conn.execute(sql).await {
+                    Ok(res) => Ok(res.into()),
+                    Err(err) => Err(sqlx_error_to_exec_err(err)),
+                }
+            })
+        } else {
+            Err(sqlx_acquire_err(&self.pool, DbErr::Exec))
         }
     }
 
@@ -100,7 +145,7 @@ impl SqlxPostgresPoolConnection {
     pub async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
+        let query = sqlx_query(&stmt).map_err(DbErr::Query)?;
         if let Ok(conn) = &mut self.pool.acquire().await {
             crate::metric::metric!(self.metric_callback, &stmt, {
                 match query.fetch_one(conn).await {
@@ -112,9 +157,7 @@ impl SqlxPostgresPoolConnection {
                 }
             })
         } else {
-            Err(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+            Err(sqlx_acquire_err(&self.pool, DbErr::Query))
         }
     }
 
@@ -123,7 +166,7 @@ impl SqlxPostgresPoolConnection {
     pub async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
+        let query = sqlx_query(&stmt).map_err(DbErr::Query)?;
         if let Ok(conn) = &mut self.pool.acquire().await {
             crate::metric::metric!(self.metric_callback, &stmt, {
                 match query.fetch_all(conn).await {
@@ -132,9 +175,7 @@ impl SqlxPostgresPoolConnection {
                 }
             })
         } else {
-            Err(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+            Err(sqlx_acquire_err(&self.pool, DbErr::Query))
         }
     }
 
@@ -150,21 +191,33 @@ impl SqlxPostgresPoolConnection {
                 self.metric_callback.clone(),
             )))
         } else {
-            Err(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+            Err(sqlx_acquire_err(&self.pool, DbErr::Query))
         }
     }
 
     /// Bundle a set of SQL statements that execute together.
     #[instrument(level = "trace")]
     pub async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+        self.begin_with_config(None, None).await
+    }
+
+    /// Bundle a set of SQL statements that execute together, with the given isolation level and access mode.
+    #[instrument(level = "trace")]
+    pub async fn begin_with_config(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
         if let Ok(conn) = self.pool.acquire().await {
-            DatabaseTransaction::new_postgres(conn, self.metric_callback.clone()).await
+            DatabaseTransaction::new_postgres(
+                conn,
+                self.metric_callback.clone(),
+                isolation_level,
+                access_mode,
+            )
+            .await
         } else {
-            Err(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+            Err(sqlx_acquire_err(&self.pool, DbErr::Conn))
         }
     }
 
@@ -180,13 +233,15 @@ impl SqlxPostgresPoolConnection {
         E: std::error::Error + Send,
     {
         if let Ok(conn) = self.pool.acquire().await {
-            let transaction = DatabaseTransaction::new_postgres(conn, self.metric_callback.clone())
-                .await
-                .map_err(|e| TransactionError::Connection(e))?;
+            let transaction =
+                DatabaseTransaction::new_postgres(conn, self.metric_callback.clone(), None, None)
+                    .await
+                    .map_err(|e| TransactionError::Connection(e))?;
             transaction.run(callback).await
         } else {
-            Err(TransactionError::Connection(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
+            Err(TransactionError::Connection(sqlx_acquire_err(
+                &self.pool,
+                DbErr::Query,
             )))
         }
     }
@@ -197,6 +252,20 @@ impl SqlxPostgresPoolConnection {
     {
         self.metric_callback = Some(Arc::new(callback));
     }
+
+    /// Checks if a connection to the database is still valid.
+    #[instrument(level = "trace")]
+    pub async fn ping(&self) -> Result<(), DbErr> {
+        let conn = &mut self.pool.acquire().await.map_err(sqlx_error_to_conn_err)?;
+        conn.ping().await.map_err(sqlx_error_to_conn_err)
+    }
+
+    /// Close the pool, waiting for the connections in use to be returned.
+    /// Any query on the pool afterwards fails with a [DbErr::Conn].
+    #[instrument(level = "trace")]
+    pub async fn close(self) {
+        self.pool.close().await
+    }
 }
 
 impl From<PgRow> for QueryResult {
@@ -215,10 +284,122 @@ impl From<PgQueryResult> for ExecResult {
     }
 }
 
-pub(crate) fn sqlx_query(stmt: &Statement) -> sqlx::query::Query<'_, Postgres, PgArguments> {
+/// Bind the values of a [Statement] to an sqlx query, or describe why they cannot be bound
+pub(crate) fn sqlx_query(
+    stmt: &Statement,
+) -> Result<sqlx::query::Query<'_, Postgres, PgArguments>, String> {
     let mut query = sqlx::query(&stmt.sql);
     if let Some(values) = &stmt.values {
+        #[cfg(feature = "postgres-array")]
+        if values.0.iter().any(|value| value.is_array()) {
+            use sea_query::Value;
+            // The SeaQuery driver cannot bind arrays, so they are bound one value at a time
+            for value in values.0.iter() {
+                query = match value {
+                    Value::Array(array) => bind_array(query, array.as_deref())?,
+                    _ => sea_query::bind_params_sqlx_postgres!(query, std::slice::from_ref(value)),
+                };
+            }
+            return Ok(query);
+        }
         query = bind_query(query, values);
     }
-    query
+    Ok(query)
+}
+
+/// Bind a [sea_query::Value::Array], typed after its first element.
+/// An empty or `NULL` array has no element to infer its type from, and is bound as `text[]`.
+/// Unsigned integers are widened the way the SeaQuery driver binds them outside of arrays.
+#[cfg(feature = "postgres-array")]
+fn bind_array<'q>(
+    query: sqlx::query::Query<'q, Postgres, PgArguments>,
+    array: Option<&Vec<sea_query::Value>>,
+) -> Result<sqlx::query::Query<'q, Postgres, PgArguments>, String> {
+    use sea_query::Value;
+
+    macro_rules! bind {
+        ( $variant: ident, $ty: ty, $v: ident => $into: expr ) => {{
+            let array = match array {
+                Some(array) => Some(
+                    array
+                        .iter()
+                        .map(|value| match value {
+                            Value::$variant($v) => Ok($into),
+                            _ => Err(format!(
+                                "Array elements should all be of the same type, found {:?} in an array of {}",
+                                value,
+                                stringify!($variant)
+                            )),
+                        })
+                        .collect::<Result<Vec<Option<$ty>>, _>>()?,
+                ),
+                None => None,
+            };
+            query.bind(array)
+        }};
+    }
+
+    Ok(match array.and_then(|array| array.first()) {
+        Some(Value::Bool(_)) => bind!(Bool, bool, v => *v),
+        Some(Value::TinyInt(_)) => bind!(TinyInt, i8, v => *v),
+        Some(Value::SmallInt(_)) => bind!(SmallInt, i16, v => *v),
+        Some(Value::Int(_)) => bind!(Int, i32, v => *v),
+        Some(Value::BigInt(_)) => bind!(BigInt, i64, v => *v),
+        Some(Value::TinyUnsigned(_)) => bind!(TinyUnsigned, i16, v => v.map(i16::from)),
+        Some(Value::SmallUnsigned(_)) => bind!(SmallUnsigned, i32, v => v.map(i32::from)),
+        Some(Value::Unsigned(_)) => bind!(Unsigned, i64, v => v.map(i64::from)),
+        Some(Value::BigUnsigned(_)) => bind!(BigUnsigned, i64, v => v.map(|v| v as i64)),
+        Some(Value::Float(_)) => bind!(Float, f32, v => *v),
+        Some(Value::Double(_)) => bind!(Double, f64, v => *v),
+        Some(Value::String(_)) | None => bind!(String, String, v => v.as_deref().cloned()),
+        Some(Value::Char(_)) => bind!(Char, String, v => v.map(|v| v.to_string())),
+        Some(Value::Bytes(_)) => bind!(Bytes, Vec<u8>, v => v.as_deref().cloned()),
+        #[cfg(feature = "with-json")]
+        Some(Value::Json(_)) => bind!(Json, serde_json::Value, v => v.as_deref().cloned()),
+        #[cfg(feature = "with-chrono")]
+        Some(Value::ChronoDate(_)) => {
+            bind!(ChronoDate, chrono::NaiveDate, v => v.as_deref().cloned())
+        }
+        #[cfg(feature = "with-chrono")]
+        Some(Value::ChronoTime(_)) => {
+            bind!(ChronoTime, chrono::NaiveTime, v => v.as_deref().cloned())
+        }
+        #[cfg(feature = "with-chrono")]
+        Some(Value::ChronoDateTime(_)) => {
+            bind!(ChronoDateTime, chrono::NaiveDateTime, v => v.as_deref().cloned())
+        }
+        #[cfg(feature = "with-chrono")]
+        Some(Value::ChronoDateTimeUtc(_)) => {
+            bind!(ChronoDateTimeUtc, chrono::DateTime<chrono::Utc>, v => v.as_deref().cloned())
+        }
+        #[cfg(feature = "with-chrono")]
+        Some(Value::ChronoDateTimeLocal(_)) => {
+            bind!(ChronoDateTimeLocal, chrono::DateTime<chrono::Local>, v => v.as_deref().cloned())
+        }
+        #[cfg(feature = "with-chrono")]
+        Some(Value::ChronoDateTimeWithTimeZone(_)) => bind!(
+            ChronoDateTimeWithTimeZone,
+            chrono::DateTime<chrono::FixedOffset>,
+            v => v.as_deref().cloned()
+        ),
+        #[cfg(feature = "with-time")]
+        Some(Value::TimeDate(_)) => bind!(TimeDate, time::Date, v => v.as_deref().cloned()),
+        #[cfg(feature = "with-time")]
+        Some(Value::TimeTime(_)) => bind!(TimeTime, time::Time, v => v.as_deref().cloned()),
+        #[cfg(feature = "with-time")]
+        Some(Value::TimeDateTime(_)) => {
+            bind!(TimeDateTime, time::PrimitiveDateTime, v => v.as_deref().cloned())
+        }
+        #[cfg(feature = "with-time")]
+        Some(Value::TimeDateTimeWithTimeZone(_)) => {
+            bind!(TimeDateTimeWithTimeZone, time::OffsetDateTime, v => v.as_deref().cloned())
+        }
+        #[cfg(feature = "with-uuid")]
+        Some(Value::Uuid(_)) => bind!(Uuid, uuid::Uuid, v => v.as_deref().cloned()),
+        #[cfg(feature = "with-rust_decimal")]
+        Some(Value::Decimal(_)) => {
+            bind!(Decimal, rust_decimal::Decimal, v => v.as_deref().cloned())
+        }
+        Some(value) => return Err(format!("Array of {:?} is not supported", value)),
+    })
 }
@@ -2,7 +2,7 @@ use std::{future::Future, pin::Pin, sync::Arc};
 
 use sqlx::{
     sqlite::{SqliteArguments, SqliteConnectOptions, SqliteQueryResult, SqliteRow},
-    Sqlite, SqlitePool,
+    Connection, Executor, Sqlite, SqlitePool,
 };
 
 sea_query::sea_query_driver_sqlite!();
@@ -10,8 +10,8 @@ use sea_query_driver_sqlite::bind_query;
 use tracing::instrument;
 
 use crate::{
-    debug_print, error::*, executor::*, ConnectOptions, DatabaseConnection, DatabaseTransaction,
-    QueryStream, Statement, TransactionError,
+    debug_print, error::*, executor::*, AccessMode, ConnectOptions, DatabaseConnection,
+    DatabaseTransaction, DbBackend, IsolationLevel, QueryStream, Statement, TransactionError,
 };
 
 use super::sqlx_common::*;
@@ -23,7 +23,7 @@ pub struct SqlxSqliteConnector;
 /// Defines a sqlx SQLite pool
 #[derive(Clone)]
 pub struct SqlxSqlitePoolConnection {
-    pool: SqlitePool,
+    pub(crate) pool: SqlitePool,
     metric_callback: Option<crate::metric::Callback>,
 }
 
@@ -43,6 +43,33 @@ impl SqlxSqliteConnector {
     #[instrument(level = "trace")]
     pub async fn connect(options: ConnectOptions) -> Result<DatabaseConnection, DbErr> {
         let mut options = options;
+        let opt = Self::sqlx_options(&mut options)?;
+        match options.pool_options().connect_with(opt).await {
+            Ok(pool) => Ok(DatabaseConnection::SqlxSqlitePoolConnection(
+                SqlxSqlitePoolConnection {
+                    pool,
+                    metric_callback: None,
+                },
+            )),
+            Err(e) => Err(sqlx_error_to_conn_err(e)),
+        }
+    }
+
+    /// Create a pool for the SQLite database without opening it.
+    /// Connections are opened when they are first needed.
+    #[instrument(level = "trace")]
+    pub fn connect_lazy(options: ConnectOptions) -> Result<DatabaseConnection, DbErr> {
+        let mut options = options;
+        let opt = Self::sqlx_options(&mut options)?;
+        Ok(DatabaseConnection::SqlxSqlitePoolConnection(
+            SqlxSqlitePoolConnection {
+                pool: options.pool_options().connect_lazy_with(opt),
+                metric_callback: None,
+            },
+        ))
+    }
+
+    fn sqlx_options(options: &mut ConnectOptions) -> Result<SqliteConnectOptions, DbErr> {
         let mut opt = options
             .url
             .parse::<SqliteConnectOptions>()
@@ -50,24 +77,11 @@ impl SqlxSqliteConnector {
         if options.sqlcipher_key.is_some() {
             opt = opt.pragma("key", options.sqlcipher_key.clone().unwrap());
         }
-        use sqlx::ConnectOptions;
-        if !options.sqlx_logging {
-            opt.disable_statement_logging();
-        } else {
-            opt.log_statements(options.sqlx_logging_level);
-        }
+        options.apply_sqlx_logging(&mut opt);
         if options.get_max_connections().is_none() {
             options.max_connections(1);
         }
-        match options.pool_options().connect_with(opt).await {
-            Ok(pool) => Ok(DatabaseConnection::SqlxSqlitePoolConnection(
-                SqlxSqlitePoolConnection {
-                    pool,
-                    metric_callback: None,
-                },
-            )),
-            Err(e) => Err(sqlx_error_to_conn_err(e)),
-        }
+        Ok(opt)
     }
 }
 
@@ -87,7 +101,7 @@ impl SqlxSqlitePoolConnection {
     pub async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
+        let query = sqlx_query(&stmt).map_err(DbErr::Exec)?;
         if let Ok(conn) = &mut self.pool.acquire().await {
             crate::metric::metric!(self.metric_callback, &stmt, {
                 match query.execute(conn).await {
@@ -96,9 +110,25 @@ impl SqlxSqlitePoolConnection {
                 }
             })
         } else {
-            Err(DbErr::Exec(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+            Err(sqlx_acquire_err(&self.pool, DbErr::Exec))
+        }
+    }
+
+    /// Execute an unprepared SQL statement on a SQLite backend
+    #[instrument(level = "trace")]
+    pub async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        debug_print!("{}", sql);
+
+        let stmt = Statement::from_string(DbBackend::Sqlite, sql.to_owned());
+        if let Ok(conn) = &mut self.pool.acquire().await {
+            crate::metric::metric!(self.metric_callback, &stmt, {
+                match conn.execute(sql).await {
+                    Ok(res) => Ok(res.into()),
+                    Err(err) => Err(sqlx_error_to_exec_err(err)),
+                }
+            })
+        } else {
+            Err(sqlx_acquire_err(&self.pool, DbErr::Exec))
         }
     }
 
@@ -107,7 +137,7 @@ impl SqlxSqlitePoolConnection {
     pub async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
+        let query = sqlx_query(&stmt).map_err(DbErr::Query)?;
         if let Ok(conn) = &mut self.pool.acquire().await {
             crate::metric::metric!(self.metric_callback, &stmt, {
                 match query.fetch_one(conn).await {
@@ -119,9 +149,7 @@ impl SqlxSqlitePoolConnection {
                 }
             })
         } else {
-            Err(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+            Err(sqlx_acquire_err(&self.pool, DbErr::Query))
         }
     }
 
@@ -130,7 +158,7 @@ impl SqlxSqlitePoolConnection {
     pub async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
         debug_print!("{}", stmt);
 
-        let query = sqlx_query(&stmt);
+        let query = sqlx_query(&stmt).map_err(DbErr::Query)?;
         if let Ok(conn) = &mut self.pool.acquire().await {
             crate::metric::metric!(self.metric_callback, &stmt, {
                 match query.fetch_all(conn).await {
@@ -139,9 +167,7 @@ impl SqlxSqlitePoolConnection {
                 }
             })
         } else {
-            Err(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+            Err(sqlx_acquire_err(&self.pool, DbErr::Query))
         }
     }
 
@@ -157,21 +183,33 @@ impl SqlxSqlitePoolConnection {
                 self.metric_callback.clone(),
             )))
         } else {
-            Err(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+            Err(sqlx_acquire_err(&self.pool, DbErr::Query))
         }
     }
 
     /// Bundle a set of SQL statements that execute together.
     #[instrument(level = "trace")]
     pub async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+        self.begin_with_config(None, None).await
+    }
+
+    /// Bundle a set of SQL statements that execute together, with the given isolation level and access mode.
+    #[instrument(level = "trace")]
+    pub async fn begin_with_config(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
         if let Ok(conn) = self.pool.acquire().await {
-            DatabaseTransaction::new_sqlite(conn, self.metric_callback.clone()).await
+            DatabaseTransaction::new_sqlite(
+                conn,
+                self.metric_callback.clone(),
+                isolation_level,
+                access_mode,
+            )
+            .await
         } else {
-            Err(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
-            ))
+            Err(sqlx_acquire_err(&self.pool, DbErr::Conn))
         }
     }
 
@@ -187,13 +225,15 @@ impl SqlxSqlitePoolConnection {
         E: std::error::Error + Send,
     {
         if let Ok(conn) = self.pool.acquire().await {
-            let transaction = DatabaseTransaction::new_sqlite(conn, self.metric_callback.clone())
-                .await
-                .map_err(|e| TransactionError::Connection(e))?;
+            let transaction =
+                DatabaseTransaction::new_sqlite(conn, self.metric_callback.clone(), None, None)
+                    .await
+                    .map_err(|e| TransactionError::Connection(e))?;
             transaction.run(callback).await
         } else {
-            Err(TransactionError::Connection(DbErr::Query(
-                "Failed to acquire connection from pool.".to_owned(),
+            Err(TransactionError::Connection(sqlx_acquire_err(
+                &self.pool,
+                DbErr::Query,
             )))
         }
     }
@@ -204,6 +244,20 @@ impl SqlxSqlitePoolConnection {
     {
         self.metric_callback = Some(Arc::new(callback));
     }
+
+    /// Checks if a connection to the database is still valid.
+    #[instrument(level = "trace")]
+    pub async fn ping(&self) -> Result<(), DbErr> {
+        let conn = &mut self.pool.acquire().await.map_err(sqlx_error_to_conn_err)?;
+        conn.ping().await.map_err(sqlx_error_to_conn_err)
+    }
+
+    /// Close the pool, waiting for the connections in use to be returned.
+    /// Any query on the pool afterwards fails with a [DbErr::Conn].
+    #[instrument(level = "trace")]
+    pub async fn close(self) {
+        self.pool.close().await
+    }
 }
 
 impl From<SqliteRow> for QueryResult {
@@ -222,10 +276,17 @@ impl From<SqliteQueryResult> for ExecResult {
     }
 }
 
-pub(crate) fn sqlx_query(stmt: &Statement) -> sqlx::query::Query<'_, Sqlite, SqliteArguments> {
+/// Bind the values of a [Statement] to an sqlx query, or describe why they cannot be bound
+pub(crate) fn sqlx_query(
+    stmt: &Statement,
+) -> Result<sqlx::query::Query<'_, Sqlite, SqliteArguments>, String> {
     let mut query = sqlx::query(&stmt.sql);
     if let Some(values) = &stmt.values {
+        #[cfg(feature = "postgres-array")]
+        if values.0.iter().any(|value| value.is_array()) {
+            return Err("Arrays are not supported by SQLite".to_owned());
+        }
         query = bind_query(query, values);
     }
-    query
+    Ok(query)
 }
@@ -1,4 +1,6 @@
-use crate::{ColumnDef, DbErr, Iterable, TryGetable};
+use crate::{ColumnDef, ColumnType, DbErr, Iterable, TryGetable};
+#[cfg(feature = "postgres-array")]
+use sea_query::{Alias, Expr, SimpleExpr};
 use sea_query::{Nullable, Value, ValueType};
 
 /// A Rust representation of enum defined in database.
@@ -72,6 +74,14 @@ use sea_query::{Nullable, Value, ValueType};
 ///         ColumnType::String(Some(1)).def()
 ///     }
 /// }
+///
+/// // Will be atomically generated by `DeriveActiveEnum`,
+/// // it allows filtering with a variant, e.g. `Column::Category.eq(Category::Big)`
+/// impl From<Category> for Value {
+///     fn from(source: Category) -> Self {
+///         source.into_value().into()
+///     }
+/// }
 /// ```
 ///
 /// Using [ActiveEnum] on Model.
@@ -117,9 +127,19 @@ pub trait ActiveEnum: Sized + Iterable {
     /// Try to convert the corresponding value into enum variant.
     fn try_from_value(v: &Self::Value) -> Result<Self, DbErr>;
 
+    /// Try to convert the corresponding value into enum variant, returning `None` for unknown value.
+    fn try_from_value_opt(v: &Self::Value) -> Option<Self> {
+        Self::try_from_value(v).ok()
+    }
+
     /// Get the database column definition of this active enum.
     fn db_type() -> ColumnDef;
 
+    /// Get the database column type of this active enum, without the metadata of [ColumnDef].
+    fn as_column_type() -> ColumnType {
+        Self::db_type().get_column_type().to_owned()
+    }
+
     /// Convert an owned enum variant into the corresponding value.
     fn into_value(self) -> Self::Value {
         Self::to_value(&self)
@@ -129,6 +149,126 @@ pub trait ActiveEnum: Sized + Iterable {
     fn values() -> Vec<Self::Value> {
         Self::iter().map(Self::into_value).collect()
     }
+
+    /// Get all enum variants paired with their corresponding values
+    fn iter_with_values() -> Vec<(Self, Self::Value)> {
+        Self::iter()
+            .map(|v| {
+                let value = v.to_value();
+                (v, value)
+            })
+            .collect()
+    }
+
+    /// Get the number of enum variants
+    fn num_variants() -> usize {
+        Self::iter().count()
+    }
+
+    /// Get the variant marked `#[sea_orm(default)]`, which the field of a new ActiveModel
+    /// created by [ActiveModelBehavior::new](crate::ActiveModelBehavior::new) is set to
+    fn default_variant() -> Option<Self> {
+        None
+    }
+
+    /// Convert enum variants into an array value, element-wise, to bind them to a Postgres array column
+    #[cfg(feature = "postgres-array")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "postgres-array")))]
+    fn into_array_value(variants: Vec<Self>) -> Value {
+        Value::Array(Some(Box::new(
+            variants
+                .into_iter()
+                .map(|v| v.into_value().into())
+                .collect(),
+        )))
+    }
+
+    /// Convert enum variants into an array expression to write to a Postgres array column,
+    /// cast to an array of the enum type if the enum is a database enum.
+    ///
+    /// A model cannot hold a `Vec` of enum variants, as SeaQuery does not convert it into a value.
+    /// Write it with this expression instead and read it back as a `Vec`, which is [TryGetable].
+    ///
+    /// ```
+    /// use sea_orm::{
+    ///     entity::prelude::*,
+    ///     sea_query::{Alias, Query},
+    ///     DbBackend, StatementBuilder,
+    /// };
+    ///
+    /// #[derive(Debug, PartialEq, EnumIter, DeriveActiveEnum)]
+    /// #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "category")]
+    /// pub enum Category {
+    ///     #[sea_orm(string_value = "B")]
+    ///     Big,
+    ///     #[sea_orm(string_value = "S")]
+    ///     Small,
+    /// }
+    ///
+    /// assert_eq!(
+    ///     DbBackend::Postgres
+    ///         .build(
+    ///             Query::insert()
+    ///                 .into_table(Alias::new("post"))
+    ///                 .columns([Alias::new("categories")])
+    ///                 .exprs_panic([Category::into_array_expr(vec![Category::Small, Category::Big])])
+    ///         )
+    ///         .sql,
+    ///     r#"INSERT INTO "post" ("categories") VALUES (CAST($1 AS category[]))"#
+    /// );
+    /// ```
+    #[cfg(feature = "postgres-array")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "postgres-array")))]
+    fn into_array_expr(variants: Vec<Self>) -> SimpleExpr {
+        let value = Expr::val(Self::into_array_value(variants));
+        match Self::as_column_type().get_enum_name() {
+            Some(enum_name) => value.as_enum(Alias::new(&format!("{}[]", enum_name))),
+            None => value.into(),
+        }
+    }
+
+    /// Convert enum variant into the corresponding value as JSON,
+    /// i.e. a JSON string for string enums and a JSON number for integer enums.
+    #[cfg(feature = "with-json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "with-json")))]
+    fn to_json_value(&self) -> serde_json::Value
+    where
+        Self::Value: serde::Serialize,
+    {
+        serde_json::to_value(self.to_value()).unwrap_or(serde_json::Value::Null)
+    }
+
+    /// Try to convert the corresponding value as JSON into enum variant.
+    #[cfg(feature = "with-json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "with-json")))]
+    fn from_json_value(v: &serde_json::Value) -> Result<Self, DbErr>
+    where
+        Self::Value: serde::de::DeserializeOwned,
+    {
+        let value = serde_json::from_value(v.clone()).map_err(|e| {
+            DbErr::Type(format!(
+                "unexpected value for {} enum: {}, {}",
+                Self::name(),
+                v,
+                e
+            ))
+        })?;
+        Self::try_from_value(&value)
+    }
+}
+
+/// Read a Postgres array column into a `Vec` of [ActiveEnum], converting element-wise
+#[cfg(feature = "postgres-array")]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgres-array")))]
+impl<T> crate::TryGetableArray for T
+where
+    T: ActiveEnum,
+{
+    type Value = T::Value;
+
+    fn try_from_array_value(v: Self::Value) -> Result<Self, crate::TryGetError> {
+        T::try_from_value(&v).map_err(crate::TryGetError::DbErr)
+    }
 }
 
 #[cfg(test)]
@@ -223,11 +363,892 @@ mod tests {
             Some(DeriveCategory::Small)
         );
 
+        assert_eq!(Category::try_from_value_opt(&"A".to_owned()), None);
+        assert_eq!(
+            Category::try_from_value_opt(&"B".to_owned()),
+            Some(Category::Big)
+        );
+        assert_eq!(DeriveCategory::try_from_value_opt(&"A".to_owned()), None);
+        assert_eq!(
+            DeriveCategory::try_from_value_opt(&"S".to_owned()),
+            Some(DeriveCategory::Small)
+        );
+
         assert_eq!(Category::db_type(), ColumnType::String(Some(1)).def());
         assert_eq!(DeriveCategory::db_type(), ColumnType::String(Some(1)).def());
+        assert_eq!(Category::as_column_type(), ColumnType::String(Some(1)));
+        assert_eq!(
+            DeriveCategory::as_column_type(),
+            ColumnType::String(Some(1))
+        );
 
         assert_eq!(Category::name(), DeriveCategory::name());
         assert_eq!(Category::values(), DeriveCategory::values());
+        assert_eq!(Category::num_variants(), 2);
+        assert_eq!(DeriveCategory::num_variants(), 2);
+        assert_eq!(
+            DeriveCategory::iter_with_values(),
+            vec![
+                (DeriveCategory::Big, "B".to_owned()),
+                (DeriveCategory::Small, "S".to_owned()),
+            ]
+        );
+        assert_eq!(DeriveCategory::VARIANT_COUNT, 2);
+        assert_eq!(DeriveCategory::value_slice(), &["B", "S"]);
+    }
+
+    #[test]
+    fn active_enum_filter() {
+        mod active_enum {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+            #[sea_orm(rs_type = "String", db_type = "String(Some(1))")]
+            pub enum Category {
+                #[sea_orm(string_value = "B")]
+                Big,
+                #[sea_orm(string_value = "S")]
+                Small,
+            }
+
+            #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+            #[sea_orm(rs_type = "i32", db_type = "Integer")]
+            pub enum Color {
+                #[sea_orm(num_value = 0)]
+                Black,
+                #[sea_orm(num_value = 1)]
+                White,
+            }
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+            #[sea_orm(table_name = "active_enum")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub category: Category,
+                pub color: Option<Color>,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+        use active_enum::{Category, Color, Column, Entity};
+
+        let stmt = Entity::find()
+            .filter(Column::Category.eq(Category::Big))
+            .filter(Column::Color.ne(Color::White))
+            .filter(Column::Category.is_in([Category::Big, Category::Small]))
+            .filter(Column::Color.gt(Color::Black))
+            .build(DbBackend::Postgres);
+
+        assert_eq!(
+            stmt.sql,
+            [
+                r#"SELECT "active_enum"."id", "active_enum"."category", "active_enum"."color" FROM "active_enum""#,
+                r#"WHERE "active_enum"."category" = $1 AND "active_enum"."color" <> $2"#,
+                r#"AND "active_enum"."category" IN ($3, $4) AND "active_enum"."color" > $5"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            stmt.values,
+            Some(Values(vec![
+                Category::Big.to_value().into(),
+                Color::White.to_value().into(),
+                "B".into(),
+                "S".into(),
+                0i32.into(),
+            ]))
+        );
+
+        assert_eq!(
+            Entity::find()
+                .filter(Column::Category.is_in(Category::iter()))
+                .filter(Column::Color.is_not_in([Color::White]))
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `active_enum`.`id`, `active_enum`.`category`, `active_enum`.`color` FROM `active_enum`",
+                "WHERE `active_enum`.`category` IN ('B', 'S') AND `active_enum`.`color` NOT IN (1)",
+            ]
+            .join(" ")
+        );
+
+        // An empty list of variants is always false for `IN` and always true for `NOT IN`
+        assert_eq!(
+            Entity::find()
+                .filter(Column::Category.is_in(Vec::<Category>::new()))
+                .build(DbBackend::MySql)
+                .to_string(),
+            "SELECT `active_enum`.`id`, `active_enum`.`category`, `active_enum`.`color` FROM `active_enum` WHERE 1 = 2",
+        );
+        assert_eq!(
+            Entity::find()
+                .filter(Column::Color.is_not_in(Vec::<Color>::new()))
+                .build(DbBackend::MySql)
+                .to_string(),
+            "SELECT `active_enum`.`id`, `active_enum`.`category`, `active_enum`.`color` FROM `active_enum` WHERE 1 = 1",
+        );
+    }
+
+    #[test]
+    fn active_enum_derive_rename_all() {
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(
+            rs_type = "String",
+            db_type = "String(None)",
+            rename_all = "snake_case"
+        )]
+        pub enum SnakeCase {
+            BlackCoffee,
+            GreenTea,
+            #[sea_orm(string_value = "M")]
+            HotChocolate,
+        }
+
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "String", db_type = "String(None)", rename_all = "camelCase")]
+        pub enum CamelCase {
+            BlackCoffee,
+            GreenTea,
+        }
+
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(
+            rs_type = "String",
+            db_type = "String(None)",
+            rename_all = "kebab-case"
+        )]
+        pub enum KebabCase {
+            BlackCoffee,
+            GreenTea,
+        }
+
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(
+            rs_type = "String",
+            db_type = "String(None)",
+            rename_all = "SCREAMING_SNAKE_CASE"
+        )]
+        pub enum ScreamingSnakeCase {
+            BlackCoffee,
+            GreenTea,
+        }
+
+        assert_eq!(SnakeCase::BlackCoffee.to_value(), "black_coffee".to_owned());
+        assert_eq!(SnakeCase::HotChocolate.to_value(), "M".to_owned());
+        assert_eq!(
+            SnakeCase::try_from_value(&"green_tea".to_owned()).ok(),
+            Some(SnakeCase::GreenTea)
+        );
+        assert_eq!(
+            SnakeCase::try_from_value(&"M".to_owned()).ok(),
+            Some(SnakeCase::HotChocolate)
+        );
+        assert_eq!(
+            SnakeCase::try_from_value(&"hot_chocolate".to_owned()).err(),
+            Some(DbErr::Type(
+                "unexpected value for SnakeCase enum: hot_chocolate".to_owned()
+            ))
+        );
+        assert_eq!(
+            CamelCase::values(),
+            vec!["blackCoffee".to_owned(), "greenTea".to_owned()]
+        );
+        assert_eq!(
+            KebabCase::values(),
+            vec!["black-coffee".to_owned(), "green-tea".to_owned()]
+        );
+        assert_eq!(
+            ScreamingSnakeCase::values(),
+            vec!["BLACK_COFFEE".to_owned(), "GREEN_TEA".to_owned()]
+        );
+    }
+
+    #[test]
+    fn active_enum_derive_fallback() {
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "String", db_type = "String(Some(1))")]
+        pub enum Category {
+            #[sea_orm(string_value = "B")]
+            Big,
+            #[sea_orm(string_value = "S")]
+            Small,
+            #[sea_orm(fallback)]
+            Unknown(String),
+        }
+
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "i32", db_type = "Integer")]
+        pub enum Level {
+            #[sea_orm(num_value = 1)]
+            Low,
+            #[strum(disabled)]
+            #[sea_orm(fallback)]
+            Other(i32),
+            #[sea_orm(num_value = 2)]
+            High,
+        }
+
+        assert_eq!(Category::Big.to_value(), "B".to_owned());
+        assert_eq!(Category::Unknown("M".to_owned()).to_value(), "M".to_owned());
+        assert_eq!(
+            Category::try_from_value(&"S".to_owned()).ok(),
+            Some(Category::Small)
+        );
+        assert_eq!(
+            Category::try_from_value(&"M".to_owned()).ok(),
+            Some(Category::Unknown("M".to_owned()))
+        );
+        assert_eq!(Category::values(), vec!["B".to_owned(), "S".to_owned()]);
+        assert_eq!(Category::value_slice(), &["B", "S"]);
+        assert_eq!(Category::num_variants(), 2);
+        assert_eq!(
+            Category::iter_with_values(),
+            vec![
+                (Category::Big, "B".to_owned()),
+                (Category::Small, "S".to_owned()),
+            ]
+        );
+
+        assert_eq!(Level::Other(3).to_value(), 3);
+        assert_eq!(Level::try_from_value(&2).ok(), Some(Level::High));
+        assert_eq!(Level::try_from_value(&3).ok(), Some(Level::Other(3)));
+        assert_eq!(Level::values(), vec![1, 2]);
+        assert_eq!(Level::value_slice(), &[1, 2]);
+        assert_eq!(Level::iter().count(), 2);
+    }
+
+    #[test]
+    fn active_enum_derive_display_from_str() {
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(
+            rs_type = "String",
+            db_type = "String(Some(1))",
+            impl_display,
+            impl_from_str
+        )]
+        pub enum Category {
+            #[sea_orm(string_value = "B")]
+            Big,
+            #[sea_orm(string_value = "S")]
+            Small,
+        }
+
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "i32", db_type = "Integer", impl_from_str)]
+        pub enum Level {
+            #[sea_orm(num_value = 1)]
+            Low,
+            #[sea_orm(num_value = 2)]
+            High,
+        }
+
+        assert_eq!(Category::Big.to_string(), "B");
+        assert_eq!(format!("{}", Category::Small), "S");
+        assert_eq!("B".parse::<Category>(), Ok(Category::Big));
+        assert_eq!(
+            "A".parse::<Category>(),
+            Err(DbErr::Type(
+                "unexpected value for Category enum: A".to_owned()
+            ))
+        );
+
+        assert_eq!("2".parse::<Level>(), Ok(Level::High));
+        assert_eq!(
+            "3".parse::<Level>(),
+            Err(DbErr::Type("unexpected value for Level enum: 3".to_owned()))
+        );
+        assert!("x".parse::<Level>().is_err());
+    }
+
+    #[test]
+    fn active_enum_json_value() {
+        use serde_json::json;
+
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "String", db_type = "String(Some(1))")]
+        pub enum Category {
+            #[sea_orm(string_value = "B")]
+            Big,
+            #[sea_orm(string_value = "S")]
+            Small,
+        }
+
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "i32", db_type = "Integer")]
+        pub enum Level {
+            #[sea_orm(num_value = 1)]
+            Low,
+            #[sea_orm(num_value = 2)]
+            High,
+        }
+
+        let meta = json!({ "category": "S", "level": 2 });
+
+        assert_eq!(Category::Big.to_json_value(), json!("B"));
+        assert_eq!(
+            Category::from_json_value(&meta["category"]).ok(),
+            Some(Category::Small)
+        );
+        assert_eq!(
+            Category::from_json_value(&json!("M")).err(),
+            Some(DbErr::Type(
+                "unexpected value for Category enum: M".to_owned()
+            ))
+        );
+        assert!(Category::from_json_value(&json!(1)).is_err());
+
+        assert_eq!(Level::Low.to_json_value(), json!(1));
+        assert_eq!(
+            Level::from_json_value(&meta["level"]).ok(),
+            Some(Level::High)
+        );
+        assert!(Level::from_json_value(&json!("2")).is_err());
+    }
+
+    #[test]
+    fn active_enum_derive_serde_db_value() {
+        use serde_json::json;
+
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "String", db_type = "String(Some(1))", serde_db_value)]
+        pub enum Category {
+            #[sea_orm(string_value = "B")]
+            Big,
+            #[sea_orm(string_value = "S")]
+            Small,
+        }
+
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "i32", db_type = "Integer", serde_db_value)]
+        pub enum Level {
+            #[sea_orm(num_value = 1)]
+            Low,
+            #[sea_orm(num_value = 2)]
+            High,
+        }
+
+        assert_eq!(serde_json::to_value(Category::Big).unwrap(), json!("B"));
+        assert_eq!(
+            serde_json::from_value::<Category>(json!("S")).unwrap(),
+            Category::Small
+        );
+        assert_eq!(
+            serde_json::from_value::<Category>(json!("M"))
+                .unwrap_err()
+                .to_string(),
+            "unexpected value for Category enum: M"
+        );
+
+        assert_eq!(serde_json::to_string(&Level::High).unwrap(), "2");
+        assert_eq!(serde_json::from_str::<Level>("1").unwrap(), Level::Low);
+        assert_eq!(
+            serde_json::from_str::<Level>("3").unwrap_err().to_string(),
+            "unexpected value for Level enum: 3"
+        );
+    }
+
+    #[test]
+    fn active_enum_derive_default() {
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "String", db_type = "String(Some(1))")]
+        pub enum Category {
+            #[sea_orm(string_value = "B")]
+            Big,
+            #[sea_orm(string_value = "S", default)]
+            Small,
+        }
+
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "i32", db_type = "Integer")]
+        pub enum Level {
+            #[sea_orm(num_value = 1)]
+            #[sea_orm(default)]
+            Low,
+            #[sea_orm(num_value = 2)]
+            High,
+        }
+
+        assert_eq!(Category::default(), Category::Small);
+        assert_eq!(
+            Category::db_type(),
+            ColumnType::String(Some(1)).def().default_value("S")
+        );
+        assert_eq!(Level::default(), Level::Low);
+        assert_eq!(Level::db_type(), ColumnType::Integer.def().default_value(1));
+        assert_eq!(Level::as_column_type(), ColumnType::Integer);
+        assert_eq!(Level::default_variant(), Some(Level::Low));
+    }
+
+    #[test]
+    fn active_enum_default_active_model() {
+        mod tea {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+            #[sea_orm(rs_type = "String", db_type = "String(Some(1))")]
+            pub enum Size {
+                #[sea_orm(string_value = "S")]
+                Small,
+                #[sea_orm(string_value = "M", default)]
+                Medium,
+            }
+
+            #[derive(Clone, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+            #[sea_orm(rs_type = "String", db_type = "String(Some(1))")]
+            pub enum Temperature {
+                #[sea_orm(string_value = "H")]
+                Hot,
+                #[sea_orm(string_value = "C")]
+                Cold,
+            }
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+            #[sea_orm(table_name = "tea")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub name: String,
+                pub size: Size,
+                pub cup_size: Option<Size>,
+                pub temperature: Temperature,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        assert_eq!(
+            tea::ActiveModel::new(),
+            tea::ActiveModel {
+                id: NotSet,
+                name: NotSet,
+                size: Set(tea::Size::Medium),
+                cup_size: Set(Some(tea::Size::Medium)),
+                temperature: NotSet,
+            }
+        );
+        assert_eq!(
+            <tea::ActiveModel as Default>::default(),
+            tea::ActiveModel::new()
+        );
+        assert_eq!(
+            <tea::ActiveModel as ActiveModelTrait>::default(),
+            tea::ActiveModel {
+                id: NotSet,
+                name: NotSet,
+                size: NotSet,
+                cup_size: NotSet,
+                temperature: NotSet,
+            }
+        );
+    }
+
+    #[test]
+    fn active_enum_derive_special_string_values() {
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "String", db_type = "String(None)")]
+        pub enum Status {
+            #[sea_orm(string_value = "En cours")]
+            InProgress,
+            #[sea_orm(string_value = "Terminé")]
+            Done,
+            #[sea_orm(string_value = "It's \"late\"")]
+            Late,
+        }
+
+        assert_eq!(Status::InProgress.to_value(), "En cours".to_owned());
+        assert_eq!(Status::Done.to_value(), "Terminé".to_owned());
+        assert_eq!(Status::Late.to_value(), "It's \"late\"".to_owned());
+        assert_eq!(
+            Status::value_slice(),
+            &["En cours", "Terminé", "It's \"late\""]
+        );
+        for (variant, value) in Status::iter_with_values() {
+            assert_eq!(Status::try_from_value(&value).unwrap(), variant);
+        }
+
+        let query = sea_query::Query::select()
+            .column(sea_query::Alias::new("id"))
+            .from(sea_query::Alias::new("task"))
+            .and_where(sea_query::Expr::col(sea_query::Alias::new("status")).eq(Status::Late))
+            .to_owned();
+        assert_eq!(
+            crate::DbBackend::Sqlite.build(&query).to_string(),
+            r#"SELECT "id" FROM "task" WHERE "status" = 'It''s "late"'"#
+        );
+        assert_eq!(
+            crate::DbBackend::MySql.build(&query).to_string(),
+            r#"SELECT `id` FROM `task` WHERE `status` = 'It\'s \"late\"'"#
+        );
+    }
+
+    #[test]
+    fn active_enum_derive_case_insensitive() {
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "String", db_type = "String(None)", case_insensitive)]
+        pub enum Category {
+            #[sea_orm(string_value = "Big")]
+            Big,
+            #[sea_orm(string_value = "Small")]
+            Small,
+        }
+
+        assert_eq!(Category::Big.to_value(), "Big".to_owned());
+        assert_eq!(
+            Category::try_from_value(&"big".to_owned()).ok(),
+            Some(Category::Big)
+        );
+        assert_eq!(
+            Category::try_from_value(&"BIG".to_owned()).ok(),
+            Some(Category::Big)
+        );
+        assert_eq!(
+            Category::try_from_value(&"Small".to_owned()).ok(),
+            Some(Category::Small)
+        );
+        assert_eq!(
+            Category::try_from_value(&"Medium".to_owned()).err(),
+            Some(DbErr::Type(
+                "unexpected value for Category enum: Medium".to_owned()
+            ))
+        );
+    }
+
+    #[smol_potat::test]
+    async fn active_enum_derive_char() -> Result<(), DbErr> {
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "String", db_type = "Char(Some(2))")]
+        pub enum Category {
+            #[sea_orm(string_value = "B")]
+            Big,
+            #[sea_orm(string_value = "S")]
+            Small,
+        }
+
+        #[derive(Debug, PartialEq, FromQueryResult)]
+        struct Row {
+            category: Category,
+        }
+
+        assert_eq!(Category::Big.to_value(), "B".to_owned());
+        assert_eq!(
+            Category::try_from_value(&"S ".to_owned()).ok(),
+            Some(Category::Small)
+        );
+        assert_eq!(Category::db_type(), ColumnType::Char(Some(2)).def());
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![maplit::btreemap! {
+                "category" => Value::from("B "),
+            }]])
+            .into_connection();
+
+        assert_eq!(
+            Row::find_by_statement(Statement::from_string(
+                DbBackend::Postgres,
+                r#"SELECT "category" FROM "char_category""#.to_owned(),
+            ))
+            .one(&db)
+            .await?,
+            Some(Row {
+                category: Category::Big
+            })
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn active_enum_from_query_result() -> Result<(), DbErr> {
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "String", db_type = "String(Some(1))")]
+        pub enum Category {
+            #[sea_orm(string_value = "B")]
+            Big,
+            #[sea_orm(string_value = "S")]
+            Small,
+        }
+
+        #[derive(Debug, PartialEq, FromQueryResult)]
+        struct Row {
+            category: Category,
+            previous_category: Option<Category>,
+        }
+
+        let row = |category: &str, previous_category: Option<&str>| {
+            maplit::btreemap! {
+                "category" => Value::from(category),
+                "previous_category" => Value::from(previous_category),
+            }
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![
+                vec![row("B", None), row("S", Some("B"))],
+                vec![row("M", None)],
+                vec![row("B", Some("M"))],
+            ])
+            .into_connection();
+
+        let find = || {
+            Row::find_by_statement(Statement::from_string(
+                DbBackend::Postgres,
+                r#"SELECT "category", "previous_category" FROM "cake""#.to_owned(),
+            ))
+        };
+
+        assert_eq!(
+            find().all(&db).await?,
+            [
+                Row {
+                    category: Category::Big,
+                    previous_category: None,
+                },
+                Row {
+                    category: Category::Small,
+                    previous_category: Some(Category::Big),
+                },
+            ]
+        );
+        for _ in 0..2 {
+            assert_eq!(
+                find().one(&db).await,
+                Err(DbErr::Type(
+                    "unexpected value for Category enum: M".to_owned()
+                ))
+            );
+        }
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn active_enum_expr_as_enum() -> Result<(), DbErr> {
+        use crate::tests_cfg::cake;
+        use sea_query::Expr;
+
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "String", db_type = "String(Some(1))")]
+        pub enum Category {
+            #[sea_orm(string_value = "B")]
+            Big,
+            #[sea_orm(string_value = "S")]
+            Small,
+        }
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![
+                vec![
+                    maplit::btreemap! {
+                        "id" => Value::from(11), "category" => Value::from("B"),
+                    },
+                    maplit::btreemap! {
+                        "id" => Value::from(2), "category" => Value::from("S"),
+                    },
+                ],
+                vec![maplit::btreemap! {
+                    "id" => Value::from(3), "category" => Value::from("M"),
+                }],
+            ])
+            .into_connection();
+
+        let select = || {
+            cake::Entity::find()
+                .select_only()
+                .column(cake::Column::Id)
+                .expr_as_enum(
+                    Expr::cust("CASE WHEN \"id\" > 10 THEN 'B' ELSE 'S' END"),
+                    "category",
+                )
+        };
+
+        assert_eq!(
+            select().build(DbBackend::Postgres).to_string(),
+            [
+                r#"SELECT "cake"."id","#,
+                r#"CAST(CASE WHEN "id" > 10 THEN 'B' ELSE 'S' END AS text) AS "category""#,
+                r#"FROM "cake""#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            select().build(DbBackend::MySql).to_string(),
+            [
+                "SELECT `cake`.`id`,",
+                r#"CASE WHEN "id" > 10 THEN 'B' ELSE 'S' END AS `category`"#,
+                "FROM `cake`",
+            ]
+            .join(" ")
+        );
+
+        #[derive(Debug, PartialEq, FromQueryResult)]
+        struct Row {
+            id: i32,
+            category: Category,
+        }
+
+        assert_eq!(
+            select().into_model::<Row>().all(&db).await?,
+            [
+                Row {
+                    id: 11,
+                    category: Category::Big
+                },
+                Row {
+                    id: 2,
+                    category: Category::Small
+                },
+            ]
+        );
+
+        assert_eq!(
+            db.query_one(select().build(DbBackend::Postgres))
+                .await?
+                .unwrap()
+                .try_get_enum::<Category>("", "category"),
+            Err(DbErr::Type(
+                "unexpected value for Category enum: M".to_owned()
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn active_enum_derive_native_enum() {
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "tea")]
+        pub enum Tea {
+            #[sea_orm(string_value = "EverydayTea")]
+            EverydayTea,
+            #[sea_orm(string_value = "BreakfastTea")]
+            BreakfastTea,
+        }
+
+        assert_eq!(
+            Tea::db_type(),
+            ColumnType::Enum(
+                "tea".to_owned(),
+                vec!["EverydayTea".to_owned(), "BreakfastTea".to_owned()]
+            )
+            .def()
+        );
+        assert_eq!(
+            Tea::as_column_type(),
+            ColumnType::Enum(
+                "tea".to_owned(),
+                vec!["EverydayTea".to_owned(), "BreakfastTea".to_owned()]
+            )
+        );
+
+        let db_postgres = DbBackend::Postgres;
+        assert_eq!(
+            db_postgres
+                .build(&Schema::new(db_postgres).create_enum_from_active_enum::<Tea>())
+                .to_string(),
+            r#"CREATE TYPE "tea" AS ENUM ('EverydayTea', 'BreakfastTea')"#
+        );
+    }
+
+    #[test]
+    fn active_enum_derive_json() {
+        use serde_json::json;
+
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "String", db_type = "JsonBinary")]
+        pub enum Category {
+            #[sea_orm(string_value = "B")]
+            Big,
+            #[sea_orm(string_value = "S")]
+            Small,
+        }
+
+        assert_eq!(Category::Big.to_value(), json!("B"));
+        assert_eq!(Category::Big.to_value().to_string(), r#""B""#);
+        assert_eq!(
+            Into::<Value>::into(Category::Small),
+            Value::Json(Some(Box::new(json!("S"))))
+        );
+        assert_eq!(
+            Category::try_from_value(&json!("S")).ok(),
+            Some(Category::Small)
+        );
+        assert_eq!(
+            <Category as sea_query::ValueType>::try_from(Value::Json(Some(Box::new(json!("B")))))
+                .ok(),
+            Some(Category::Big)
+        );
+        assert_eq!(
+            Category::try_from_value(&json!("M")).err(),
+            Some(DbErr::Type(
+                "unexpected value for Category enum: M".to_owned()
+            ))
+        );
+        assert_eq!(
+            Category::try_from_value(&json!(1)).err(),
+            Some(DbErr::Type(
+                "unexpected value for Category enum: 1".to_owned()
+            ))
+        );
+        assert_eq!(Category::db_type(), ColumnType::JsonBinary.def());
+        assert_eq!(Category::values(), vec![json!("B"), json!("S")]);
+    }
+
+    #[cfg(feature = "postgres-array")]
+    #[smol_potat::test]
+    async fn active_enum_postgres_array() -> Result<(), DbErr> {
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "category")]
+        pub enum Category {
+            #[sea_orm(string_value = "B")]
+            Big,
+            #[sea_orm(string_value = "S")]
+            Small,
+        }
+
+        #[derive(Debug, PartialEq, FromQueryResult)]
+        struct Categories {
+            categories: Vec<Category>,
+        }
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![
+                vec![maplit::btreemap! {
+                    "categories" => Value::Array(Some(Box::new(vec!["S".into(), "B".into(), "S".into()]))),
+                }],
+                vec![maplit::btreemap! {
+                    "categories" => Value::Array(Some(Box::new(vec!["M".into()]))),
+                }],
+            ])
+            .into_connection();
+
+        let stmt = Statement::from_string(
+            DbBackend::Postgres,
+            r#"SELECT "categories" FROM "category_array""#.to_owned(),
+        );
+
+        assert_eq!(
+            Categories::find_by_statement(stmt.clone()).one(&db).await?,
+            Some(Categories {
+                categories: vec![Category::Small, Category::Big, Category::Small],
+            })
+        );
+        assert_eq!(
+            Categories::find_by_statement(stmt).one(&db).await.err(),
+            Some(DbErr::Type(
+                "unexpected value for Category enum: M".to_owned()
+            ))
+        );
+
+        Ok(())
     }
 
     #[test]
@@ -281,7 +1302,13 @@ mod tests {
                     )))
                 );
 
+                assert_eq!($ident::try_from_value_opt(&-10), Some($ident::Negative));
+                assert_eq!($ident::try_from_value_opt(&2), None);
+
+                assert_eq!($ident::value_slice(), $ident::values().as_slice());
+
                 assert_eq!($ident::db_type(), ColumnType::$col_def.def());
+                assert_eq!($ident::as_column_type(), ColumnType::$col_def);
             };
         }
 
@@ -294,6 +1321,64 @@ mod tests {
         test_fallback_int!(I16Fallback, i16, "i16", "SmallInteger", SmallInteger);
         test_fallback_int!(I32Fallback, i32, "i32", "Integer", Integer);
         test_fallback_int!(I64Fallback, i64, "i64", "BigInteger", BigInteger);
+
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "i128", db_type = "String(Some(40))")]
+        pub enum I128 {
+            #[sea_orm(num_value = -170141183460469231731687303715884105728)]
+            Min,
+            #[sea_orm(num_value = 1)]
+            Big,
+            #[sea_orm(num_value = 0)]
+            Small,
+            #[strum(disabled)]
+            #[sea_orm(fallback)]
+            Other(i128),
+        }
+
+        assert_eq!(I128::Min.to_value(), i128::MIN);
+        assert_eq!(I128::Big.to_value(), 1);
+        assert_eq!(I128::Other(2).to_value(), 2);
+        assert_eq!(
+            Into::<Value>::into(I128::Min),
+            Value::from("-170141183460469231731687303715884105728")
+        );
+        assert_eq!(
+            I128::try_from_value(&crate::I128(i128::MIN)).ok(),
+            Some(I128::Min)
+        );
+        assert_eq!(
+            I128::try_from_value(&crate::I128(2)).ok(),
+            Some(I128::Other(2))
+        );
+        assert_eq!(
+            <I128 as sea_query::ValueType>::try_from(Value::from("0")).ok(),
+            Some(I128::Small)
+        );
+        assert_eq!(I128::value_slice(), I128::values().as_slice());
+        assert_eq!(I128::db_type(), ColumnType::String(Some(40)).def());
+
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "i128", db_type = "Text")]
+        #[repr(i128)]
+        pub enum I128Fallback {
+            Big = 1267650600228229401496703205376,
+            Small = 0,
+            Negative = -10,
+        }
+
+        assert_eq!(I128Fallback::Big.to_value(), 1 << 100);
+        assert_eq!(
+            I128Fallback::try_from_value(&crate::I128(-10)).ok(),
+            Some(I128Fallback::Negative)
+        );
+        assert_eq!(
+            I128Fallback::try_from_value(&crate::I128(2)).err(),
+            Some(DbErr::Type(
+                "unexpected value for I128Fallback enum: 2".to_owned()
+            ))
+        );
+        assert_eq!(I128Fallback::db_type(), ColumnType::Text.def());
     }
 
     #[test]
@@ -342,6 +1427,9 @@ mod tests {
                     )))
                 );
 
+                assert_eq!($ident::try_from_value_opt(&1), Some($ident::Big));
+                assert_eq!($ident::try_from_value_opt(&2), None);
+
                 assert_eq!($ident::db_type(), ColumnType::$col_def.def());
             };
         }
@@ -355,5 +1443,32 @@ mod tests {
         test_fallback_uint!(U16Fallback, u16, "u16", "SmallInteger", SmallInteger);
         test_fallback_uint!(U32Fallback, u32, "u32", "Integer", Integer);
         test_fallback_uint!(U64Fallback, u64, "u64", "BigInteger", BigInteger);
+
+        #[derive(Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "u128", db_type = "Char(Some(39))", impl_from_str)]
+        pub enum U128 {
+            #[sea_orm(num_value = 340282366920938463463374607431768211455)]
+            Max,
+            #[sea_orm(num_value = 0)]
+            Small,
+        }
+
+        assert_eq!(U128::Max.to_value(), u128::MAX);
+        assert_eq!(
+            Into::<Value>::into(U128::Max),
+            Value::from("340282366920938463463374607431768211455")
+        );
+        assert_eq!(
+            U128::try_from_value(&crate::U128(u128::MAX)).ok(),
+            Some(U128::Max)
+        );
+        assert_eq!(
+            U128::try_from_value(&crate::U128(2)).err(),
+            Some(DbErr::Type("unexpected value for U128 enum: 2".to_owned()))
+        );
+        assert_eq!("0".parse::<U128>().ok(), Some(U128::Small));
+        assert!("-1".parse::<U128>().is_err());
+        assert_eq!(U128::value_slice(), U128::values().as_slice());
+        assert_eq!(U128::db_type(), ColumnType::Char(Some(39)).def());
     }
 }
@@ -1,9 +1,10 @@
 use crate::{
-    error::*, ConnectionTrait, DeleteResult, EntityTrait, Iterable, PrimaryKeyToColumn, Value,
+    error::*, ActiveEnum, ConnectionTrait, DeleteResult, EntityTrait, IdenStatic, Insert, Iterable,
+    ModelTrait, PrimaryKeyToColumn, TransactionTrait, Value,
 };
 use async_trait::async_trait;
 use sea_query::{Nullable, ValueTuple};
-use std::fmt::Debug;
+use std::{fmt::Debug, marker::PhantomData};
 
 pub use ActiveValue::NotSet;
 
@@ -105,6 +106,12 @@ pub trait ActiveModelTrait: Clone + Debug {
     /// The default implementation of the ActiveModel
     fn default() -> Self;
 
+    /// Create an ActiveModel with the default values of its fields, i.e. the variant marked
+    /// `#[sea_orm(default)]` of an [ActiveEnum] field is `Set`, and other fields are `NotSet`
+    fn default_values() -> Self {
+        Self::default()
+    }
+
     /// Get the primary key of the ActiveModel
     #[allow(clippy::question_mark)]
     fn get_primary_key_value(&self) -> Option<ValueTuple> {
@@ -285,6 +292,82 @@ pub trait ActiveModelTrait: Clone + Debug {
         Self::after_save(model, true)
     }
 
+    /// Insert the ActiveModels yielded by an iterator, and get the number of rows affected.
+    ///
+    /// The models are taken from the iterator one chunk at a time, each chunk as large as the
+    /// bind parameter limit of the backend allows, see [Insert::exec_without_returning](crate::Insert::exec_without_returning).
+    /// Every chunk is inserted within one transaction, so only a single chunk is held in memory.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_exec_results(vec![
+    /// #         MockExecResult {
+    /// #             last_insert_id: 0,
+    /// #             rows_affected: 2,
+    /// #         },
+    /// #     ])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let cakes = ["Apple Pie", "Orange Scone"].into_iter().map(|name| cake::ActiveModel {
+    ///     name: Set(name.to_owned()),
+    ///     ..Default::default()
+    /// });
+    ///
+    /// assert_eq!(cake::ActiveModel::insert_many_from_iter(cakes, &db).await?, 2);
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     vec![Transaction::many(vec![
+    ///         Statement::from_string(DbBackend::Postgres, "BEGIN".to_owned()),
+    ///         Statement::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"INSERT INTO "cake" ("name") VALUES ($1), ($2)"#,
+    ///             vec!["Apple Pie".into(), "Orange Scone".into()]
+    ///         ),
+    ///         Statement::from_string(DbBackend::Postgres, "COMMIT".to_owned()),
+    ///     ])]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn insert_many_from_iter<'a, C, I>(models: I, db: &'a C) -> Result<u64, DbErr>
+    where
+        Self: Send + 'a,
+        C: ConnectionTrait + TransactionTrait,
+        I: IntoIterator<Item = Self> + Send + 'a,
+        I::IntoIter: Send,
+    {
+        let db_backend = db.get_database_backend();
+        let mut models = models.into_iter().peekable();
+        if models.peek().is_none() {
+            return Ok(0);
+        }
+        let txn = db.begin().await?;
+        let mut rows_affected = 0;
+        while let Some(model) = models.next() {
+            let mut insert = Insert::one(model);
+            let rows_per_chunk = insert.rows_per_chunk(db_backend);
+            insert = insert.add_many(models.by_ref().take(rows_per_chunk - 1));
+            rows_affected += txn
+                .execute(db_backend.build(&insert.query))
+                .await?
+                .rows_affected();
+        }
+        txn.commit().await?;
+        Ok(rows_affected)
+    }
+
     /// Perform the `UPDATE` operation on an ActiveModel
     ///
     /// # Example (Postgres)
@@ -405,26 +488,34 @@ pub trait ActiveModelTrait: Clone + Debug {
         Self::after_save(model, false)
     }
 
-    /// Insert the model if primary key is `NotSet`, update otherwise.
-    /// Only works if the entity has auto increment primary key.
+    /// Insert the model if its primary key is `NotSet`, update it otherwise.
+    ///
+    /// For a composite primary key, the model is inserted if all of its columns are `NotSet`
+    /// and updated if none of them is. A primary key with only some of its columns `NotSet`
+    /// is ambiguous, and is an error. A new model with its primary key set by the application
+    /// would be updated, insert it with [ActiveModelTrait::insert] instead.
     async fn save<'a, C>(self, db: &'a C) -> Result<Self, DbErr>
     where
         <Self::Entity as EntityTrait>::Model: IntoActiveModel<Self>,
         Self: ActiveModelBehavior + 'a,
         C: ConnectionTrait,
     {
-        let mut is_update = true;
-        for key in <Self::Entity as EntityTrait>::PrimaryKey::iter() {
-            let col = key.into_column();
-            if self.is_not_set(col) {
-                is_update = false;
-                break;
+        let (not_set, set): (Vec<_>, Vec<_>) = <Self::Entity as EntityTrait>::PrimaryKey::iter()
+            .map(|key| key.into_column())
+            .partition(|col| self.is_not_set(*col));
+        let res = match (not_set.is_empty(), set.is_empty()) {
+            (true, _) => self.update(db).await,
+            (false, true) => self.insert(db).await,
+            (false, false) => {
+                return Err(DbErr::Custom(format!(
+                    "Cannot save a model with a partially set primary key, {} NotSet",
+                    not_set
+                        .iter()
+                        .map(|col| format!("`{}`", col.as_str()))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )))
             }
-        }
-        let res = if !is_update {
-            self.insert(db).await
-        } else {
-            self.update(db).await
         }?;
         Ok(res.into_active_model())
     }
@@ -549,11 +640,76 @@ pub trait ActiveModelTrait: Clone + Debug {
         Ok(am)
     }
 
+    /// Create ActiveModel from the difference between two Models.
+    /// Fields that differ are `Set` to the values of `new`, others are `Unchanged`,
+    /// so updating the ActiveModel only writes the changed columns.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// let old = fruit::Model {
+    ///     id: 1,
+    ///     name: "Apple".to_owned(),
+    ///     cake_id: Some(1),
+    /// };
+    /// let new = fruit::Model {
+    ///     name: "Orange".to_owned(),
+    ///     ..old.clone()
+    /// };
+    ///
+    /// let fruit = fruit::ActiveModel::from_model_diff(&old, &new);
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::update(fruit)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"UPDATE "fruit" SET "name" = 'Orange' WHERE "fruit"."id" = 1"#
+    /// );
+    /// ```
+    fn from_model_diff(
+        old: &<Self::Entity as EntityTrait>::Model,
+        new: &<Self::Entity as EntityTrait>::Model,
+    ) -> Self
+    where
+        <Self::Entity as EntityTrait>::Model: IntoActiveModel<Self>,
+    {
+        let mut am = old.clone().into_active_model();
+        for col in <Self::Entity as EntityTrait>::Column::iter() {
+            let value = new.get(col);
+            if value != old.get(col) {
+                am.set(col, value);
+            }
+        }
+        am
+    }
+
     /// Return `true` if any field of `ActiveModel` is `Set`
     fn is_changed(&self) -> bool {
         <Self::Entity as EntityTrait>::Column::iter()
             .any(|col| self.get(col).is_set() && !self.get(col).is_unchanged())
     }
+
+    /// Copy the fields that are `Set` in `other` onto this ActiveModel, as `Set`.
+    /// Fields that are `Unchanged` or `NotSet` in `other` are left untouched.
+    fn set_from(&mut self, mut other: Self) {
+        for col in <Self::Entity as EntityTrait>::Column::iter() {
+            if other.get(col).is_set() {
+                self.set(col, other.take(col).unwrap());
+            }
+        }
+    }
+
+    /// Mark every field holding a value as `Set`, so that updating the ActiveModel
+    /// writes all of them. Fields that are `NotSet` stay `NotSet`.
+    fn reset_all(mut self) -> Self {
+        for col in <Self::Entity as EntityTrait>::Column::iter() {
+            match self.take(col) {
+                ActiveValue::Set(v) | ActiveValue::Unchanged(v) => self.set(col, v),
+                NotSet => {}
+            }
+        }
+        self
+    }
 }
 
 /// A Trait for overriding the ActiveModel behavior
@@ -586,8 +742,11 @@ pub trait ActiveModelTrait: Clone + Debug {
 #[allow(unused_variables)]
 pub trait ActiveModelBehavior: ActiveModelTrait {
     /// Create a new ActiveModel with default values. Also used by `Default::default()`.
+    ///
+    /// A field holding an [ActiveEnum] with a variant marked `#[sea_orm(default)]` is `Set`
+    /// to it, see [ActiveModelTrait::default_values].
     fn new() -> Self {
-        <Self as ActiveModelTrait>::default()
+        <Self as ActiveModelTrait>::default_values()
     }
 
     /// Will be called before saving
@@ -649,6 +808,21 @@ macro_rules! impl_into_active_value {
             }
         }
 
+        impl IntoActiveValue<Option<$ty>> for $ty {
+            fn into_active_value(self) -> ActiveValue<Option<$ty>> {
+                Set(Some(self))
+            }
+        }
+
+        impl IntoActiveValue<$ty> for Option<$ty> {
+            fn into_active_value(self) -> ActiveValue<$ty> {
+                match self {
+                    Some(value) => Set(value),
+                    None => NotSet,
+                }
+            }
+        }
+
         impl IntoActiveValue<Option<$ty>> for Option<$ty> {
             fn into_active_value(self) -> ActiveValue<Option<$ty>> {
                 match self {
@@ -729,6 +903,66 @@ where
     }
 }
 
+/// Picks the default [ActiveValue] of a field in [ActiveModelTrait::default_values],
+/// which is `Set` to the default variant of an [ActiveEnum] and `NotSet` otherwise.
+///
+/// The impl of [DefaultActiveEnumValue] is found before the impl of [DefaultNotSetValue],
+/// which takes one more reference, if the type of the field is an [ActiveEnum].
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct DefaultActiveValue<V>(PhantomData<V>);
+
+impl<V> DefaultActiveValue<V> {
+    #[doc(hidden)]
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait DefaultActiveEnumValue<V>
+where
+    V: Into<Value>,
+{
+    fn default_active_value(&self) -> ActiveValue<V>;
+}
+
+impl<V> DefaultActiveEnumValue<V> for DefaultActiveValue<V>
+where
+    V: ActiveEnum + Into<Value>,
+{
+    fn default_active_value(&self) -> ActiveValue<V> {
+        V::default_variant().map_or(ActiveValue::NotSet, ActiveValue::Set)
+    }
+}
+
+impl<V> DefaultActiveEnumValue<Option<V>> for DefaultActiveValue<Option<V>>
+where
+    V: ActiveEnum,
+    Option<V>: Into<Value>,
+{
+    fn default_active_value(&self) -> ActiveValue<Option<V>> {
+        V::default_variant().map_or(ActiveValue::NotSet, |v| ActiveValue::Set(Some(v)))
+    }
+}
+
+#[doc(hidden)]
+pub trait DefaultNotSetValue<V>
+where
+    V: Into<Value>,
+{
+    fn default_active_value(&self) -> ActiveValue<V>;
+}
+
+impl<V> DefaultNotSetValue<V> for &DefaultActiveValue<V>
+where
+    V: Into<Value>,
+{
+    fn default_active_value(&self) -> ActiveValue<V> {
+        ActiveValue::NotSet
+    }
+}
+
 impl<V> ActiveValue<V>
 where
     V: Into<Value>,
@@ -839,7 +1073,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::{entity::*, tests_cfg::*, DbErr};
+    use crate::{entity::*, tests_cfg::*, DbBackend, DbErr, QueryTrait};
     use pretty_assertions::assert_eq;
 
     #[cfg(feature = "with-json")]
@@ -876,6 +1110,43 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "macros")]
+    fn test_derive_into_active_model_3() {
+        mod my_fruit {
+            pub use super::fruit::*;
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(DeriveIntoActiveModel)]
+            pub struct PatchFruit {
+                // only set when present
+                pub name: Option<String>,
+            }
+        }
+
+        assert_eq!(
+            my_fruit::PatchFruit {
+                name: Some("Apple".to_owned()),
+            }
+            .into_active_model(),
+            fruit::ActiveModel {
+                id: NotSet,
+                name: Set("Apple".to_owned()),
+                cake_id: NotSet,
+            }
+        );
+
+        assert_eq!(
+            my_fruit::PatchFruit { name: None }.into_active_model(),
+            fruit::ActiveModel {
+                id: NotSet,
+                name: NotSet,
+                cake_id: NotSet,
+            }
+        );
+    }
+
     #[test]
     #[cfg(feature = "macros")]
     fn test_derive_into_active_model_2() {
@@ -924,6 +1195,113 @@ mod tests {
         );
     }
 
+    #[smol_potat::test]
+    #[cfg(all(feature = "macros", feature = "mock"))]
+    async fn test_derive_value_type() -> Result<(), DbErr> {
+        use crate as sea_orm;
+        use crate::{entity::prelude::*, *};
+
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, DeriveValueType)]
+        #[sea_orm(column_type = "Integer")]
+        #[cfg_attr(feature = "postgres-array", sea_orm(array_type = "Int"))]
+        pub struct Money(pub i64);
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "invoice")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub amount: Money,
+            pub discount: Option<Money>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+
+        assert_eq!(Column::Amount.def(), ColumnType::Integer.def());
+        assert_eq!(<Money as sea_query::ValueType>::type_name(), "Money");
+        assert_eq!(Value::from(Money(1250)), Value::BigInt(Some(1250)));
+        assert_eq!(<Money as sea_query::Nullable>::null(), Value::BigInt(None));
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![
+                vec![Model {
+                    id: 1,
+                    amount: Money(1250),
+                    discount: None,
+                }],
+                vec![Model {
+                    id: 1,
+                    amount: Money(1250),
+                    discount: Some(Money(250)),
+                }],
+            ])
+            .into_connection();
+
+        let model = ActiveModel {
+            amount: Set(Money(1250)),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await?;
+        assert_eq!(model.amount, Money(1250));
+        assert_eq!(model.discount, None);
+
+        assert_eq!(
+            Entity::find_by_id(1).one(&db).await?,
+            Some(Model {
+                id: 1,
+                amount: Money(1250),
+                discount: Some(Money(250)),
+            })
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "invoice" ("amount") VALUES ($1) RETURNING "id", "amount", "discount""#,
+                    vec![1250i64.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "invoice"."id", "invoice"."amount", "invoice"."discount" FROM "invoice" WHERE "invoice"."id" = $1 LIMIT $2"#,
+                    vec![1i32.into(), 1u64.into()]
+                ),
+            ]
+        );
+
+        #[cfg(feature = "postgres-array")]
+        {
+            #[derive(Debug, PartialEq, FromQueryResult)]
+            struct Prices {
+                prices: Vec<Money>,
+            }
+
+            let db = MockDatabase::new(DbBackend::Postgres)
+                .append_query_results(vec![vec![maplit::btreemap! {
+                    "prices" => Value::from(vec![100i32, 250]),
+                }]])
+                .into_connection();
+
+            let stmt = Statement::from_string(
+                DbBackend::Postgres,
+                r#"SELECT "prices" FROM "price_list""#.to_owned(),
+            );
+            assert_eq!(
+                Prices::find_by_statement(stmt).one(&db).await?,
+                Some(Prices {
+                    prices: vec![Money(100), Money(250)],
+                })
+            );
+        }
+
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "with-json")]
     #[should_panic(
@@ -1095,6 +1473,266 @@ mod tests {
         Ok(())
     }
 
+    #[smol_potat::test]
+    #[cfg(feature = "mock")]
+    async fn test_active_model_save_single_key() -> Result<(), DbErr> {
+        use crate::*;
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![
+                vec![fruit::Model {
+                    id: 1,
+                    name: "Apple".to_owned(),
+                    cake_id: None,
+                }],
+                vec![fruit::Model {
+                    id: 1,
+                    name: "Orange".to_owned(),
+                    cake_id: None,
+                }],
+            ])
+            .into_connection();
+
+        let mut apple = fruit::ActiveModel {
+            name: Set("Apple".to_owned()),
+            ..Default::default()
+        }
+        .save(&db)
+        .await?;
+        assert_eq!(apple.id, Unchanged(1));
+
+        apple.name = Set("Orange".to_owned());
+        let orange = apple.save(&db).await?;
+        assert_eq!(orange.name, Unchanged("Orange".to_owned()));
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "fruit" ("name") VALUES ($1) RETURNING "id", "name", "cake_id""#,
+                    vec!["Apple".into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"UPDATE "fruit" SET "name" = $1 WHERE "fruit"."id" = $2 RETURNING "id", "name", "cake_id""#,
+                    vec!["Orange".into(), 1i32.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    #[cfg(feature = "mock")]
+    async fn test_active_model_insert_many_from_iter() -> Result<(), DbErr> {
+        use crate::*;
+
+        let cakes = || {
+            (0..2000).map(|i| cake::ActiveModel {
+                name: Set(format!("Cake {}", i)),
+                ..Default::default()
+            })
+        };
+
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_exec_results(
+                [999, 999, 2]
+                    .map(|rows_affected| MockExecResult {
+                        last_insert_id: 0,
+                        rows_affected,
+                    })
+                    .to_vec(),
+            )
+            .into_connection();
+
+        assert_eq!(
+            cake::ActiveModel::insert_many_from_iter(cakes(), &db).await?,
+            2000
+        );
+
+        let log = db.into_transaction_log();
+        assert_eq!(log.len(), 1);
+        assert!(log[0].is_committed());
+        let statements = log[0].statements();
+        assert_eq!(
+            statements
+                .iter()
+                .map(|stmt| stmt.values.as_ref().map_or(0, |values| values.0.len()))
+                .collect::<Vec<_>>(),
+            [0, 999, 999, 2, 0]
+        );
+        assert_eq!(
+            statements[3],
+            Statement::from_sql_and_values(
+                DbBackend::Sqlite,
+                r#"INSERT INTO "cake" ("name") VALUES (?), (?)"#,
+                vec!["Cake 1998".into(), "Cake 1999".into()]
+            )
+        );
+
+        // The models are only taken from the iterator once the previous chunk is inserted
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 999,
+            }])
+            .into_connection();
+
+        let mut iter = cakes();
+        assert!(cake::ActiveModel::insert_many_from_iter(&mut iter, &db)
+            .await
+            .is_err());
+        assert_eq!(iter.count(), 2);
+
+        assert_eq!(
+            cake::ActiveModel::insert_many_from_iter(Vec::new(), &db).await?,
+            0
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    #[cfg(all(feature = "mock", feature = "macros"))]
+    async fn test_active_model_save_composite_key() -> Result<(), DbErr> {
+        use crate as sea_orm;
+        use crate::{entity::prelude::*, *};
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "order_line")]
+        pub struct Model {
+            #[sea_orm(primary_key, auto_increment = false)]
+            pub order_id: i32,
+            #[sea_orm(primary_key, auto_increment = false)]
+            pub line_no: i32,
+            pub quantity: i32,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![
+                vec![Model {
+                    order_id: 1,
+                    line_no: 1,
+                    quantity: 2,
+                }],
+                vec![Model {
+                    order_id: 1,
+                    line_no: 1,
+                    quantity: 3,
+                }],
+            ])
+            .into_connection();
+
+        // All primary key columns are NotSet, e.g. generated by the database
+        ActiveModel {
+            quantity: Set(2),
+            ..Default::default()
+        }
+        .save(&db)
+        .await?;
+
+        // All primary key columns are set
+        ActiveModel {
+            order_id: Unchanged(1),
+            line_no: Set(1),
+            quantity: Set(3),
+        }
+        .save(&db)
+        .await?;
+
+        // Only some of the primary key columns are set
+        assert_eq!(
+            ActiveModel {
+                order_id: Set(1),
+                quantity: Set(4),
+                ..Default::default()
+            }
+            .save(&db)
+            .await,
+            Err(DbErr::Custom(
+                "Cannot save a model with a partially set primary key, `line_no` NotSet".to_owned()
+            ))
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "order_line" ("quantity") VALUES ($1) RETURNING "order_id", "line_no", "quantity""#,
+                    vec![2i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"UPDATE "order_line" SET "quantity" = $1 WHERE "order_line"."order_id" = $2 AND "order_line"."line_no" = $3 RETURNING "order_id", "line_no", "quantity""#,
+                    vec![3i32.into(), 1i32.into(), 1i32.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_active_model_from_model_diff() {
+        use crate::{DbBackend, QueryTrait};
+
+        let old = fruit::Model {
+            id: 1,
+            name: "Apple".to_owned(),
+            cake_id: Some(1),
+        };
+
+        let unchanged = fruit::ActiveModel::from_model_diff(&old, &old.clone());
+        assert_eq!(
+            unchanged,
+            fruit::ActiveModel {
+                id: ActiveValue::unchanged(1),
+                name: ActiveValue::unchanged("Apple".to_owned()),
+                cake_id: ActiveValue::unchanged(Some(1)),
+            }
+        );
+        assert!(!unchanged.is_changed());
+
+        let new = fruit::Model {
+            id: 1,
+            name: "Orange".to_owned(),
+            cake_id: None,
+        };
+
+        let changed = fruit::ActiveModel::from_model_diff(&old, &new);
+        assert_eq!(
+            changed,
+            fruit::ActiveModel {
+                id: ActiveValue::unchanged(1),
+                name: ActiveValue::set("Orange".to_owned()),
+                cake_id: ActiveValue::set(None),
+            }
+        );
+        assert_eq!(
+            fruit::Entity::update(changed)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"UPDATE "fruit" SET "name" = 'Orange', "cake_id" = NULL WHERE "fruit"."id" = 1"#
+        );
+
+        let mut changed = fruit::ActiveModel::from_model_diff(&old, &new);
+        changed.not_set(fruit::Column::CakeId);
+        assert_eq!(
+            fruit::Entity::update(changed)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"UPDATE "fruit" SET "name" = 'Orange' WHERE "fruit"."id" = 1"#
+        );
+    }
+
     #[test]
     fn test_active_model_is_changed() {
         let mut fruit: fruit::ActiveModel = Default::default();
@@ -1103,4 +1741,85 @@ mod tests {
         fruit.set(fruit::Column::Name, "apple".into());
         assert!(fruit.is_changed());
     }
+
+    #[test]
+    fn test_active_model_set_from() {
+        let mut fruit = fruit::Model {
+            id: 1,
+            name: "Apple".to_owned(),
+            cake_id: Some(1),
+        }
+        .into_active_model();
+
+        fruit.set_from(fruit::ActiveModel {
+            id: Unchanged(2),
+            name: Set("Orange".to_owned()),
+            cake_id: NotSet,
+        });
+
+        assert_eq!(
+            fruit,
+            fruit::ActiveModel {
+                id: Unchanged(1),
+                name: Set("Orange".to_owned()),
+                cake_id: Unchanged(Some(1)),
+            }
+        );
+
+        fruit.set_from(fruit::ActiveModel {
+            cake_id: Set(None),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            fruit,
+            fruit::ActiveModel {
+                id: Unchanged(1),
+                name: Set("Orange".to_owned()),
+                cake_id: Set(None),
+            }
+        );
+        assert_eq!(
+            fruit::Entity::update(fruit)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"UPDATE "fruit" SET "name" = 'Orange', "cake_id" = NULL WHERE "fruit"."id" = 1"#,
+        );
+    }
+
+    #[test]
+    fn test_active_model_reset_all() {
+        let fruit = fruit::ActiveModel {
+            id: Unchanged(1),
+            name: Set("Apple".to_owned()),
+            cake_id: NotSet,
+        }
+        .reset_all();
+
+        assert_eq!(
+            fruit,
+            fruit::ActiveModel {
+                id: Set(1),
+                name: Set("Apple".to_owned()),
+                cake_id: NotSet,
+            }
+        );
+
+        let fruit = fruit::Model {
+            id: 1,
+            name: "Apple".to_owned(),
+            cake_id: Some(1),
+        }
+        .into_active_model();
+        assert!(!fruit.is_changed());
+
+        let fruit = fruit.reset_all();
+        assert!(fruit.is_changed());
+        assert_eq!(
+            fruit::Entity::update(fruit)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"UPDATE "fruit" SET "name" = 'Apple', "cake_id" = 1 WHERE "fruit"."id" = 1"#,
+        );
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{
-    ActiveModelTrait, ColumnTrait, Delete, DeleteMany, DeleteOne, FromQueryResult, Insert,
-    ModelTrait, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, Related, RelationBuilder,
+    ActiveModelTrait, ColumnTrait, Delete, DeleteMany, DeleteOne, FromQueryResult, IndexDef,
+    Insert, ModelTrait, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, Related, RelationBuilder,
     RelationTrait, RelationType, Select, Update, UpdateMany, UpdateOne,
 };
 use sea_query::{Alias, Iden, IntoIden, IntoTableRef, IntoValueTuple, TableRef};
@@ -62,6 +62,42 @@ pub trait EntityTrait: EntityName {
     #[allow(missing_docs)]
     type PrimaryKey: PrimaryKeyTrait + PrimaryKeyToColumn<Column = Self::Column>;
 
+    /// The column marking a row as soft deleted, defaults to [Option::None] if not set.
+    ///
+    /// On a soft deletable entity, [EntityTrait::find] only selects rows where this column is `NULL`,
+    /// and [EntityTrait::delete] / [EntityTrait::delete_many] set it to the current timestamp
+    /// instead of deleting the rows. Use [EntityTrait::find_with_deleted] and
+    /// [DeleteMany::hard_delete] to bypass it. The rows marked as deleted are the ones matched by
+    /// [QueryFilter::filter](crate::QueryFilter::filter), conditions added directly to the
+    /// [DeleteStatement](sea_query::DeleteStatement) with `QueryTrait::query` are not applied.
+    ///
+    /// It can be derived with `#[sea_orm(soft_delete_column = "deleted_at")]`.
+    fn soft_delete_column() -> Option<Self::Column> {
+        None
+    }
+
+    /// The column holding the version of a row for optimistic locking, defaults to [Option::None] if not set.
+    ///
+    /// When set, [EntityTrait::update] only updates the row if its version still matches the one
+    /// of the model, and bumps the version: integer columns are incremented, other columns are
+    /// set to the current timestamp. If the row was changed in the meantime, the update
+    /// returns [DbErr::RecordNotUpdated](crate::DbErr::RecordNotUpdated).
+    ///
+    /// It can be derived with `#[sea_orm(version)]` on the column.
+    fn version_column() -> Option<Self::Column> {
+        None
+    }
+
+    /// The indexes spanning multiple columns or carrying a condition, defaults to none.
+    /// Indexes on a single column can be set with [ColumnDef::indexed](crate::ColumnDef::indexed) instead.
+    ///
+    /// They are created by [Schema::create_index_from_entity](crate::Schema::create_index_from_entity),
+    /// and can be derived with `#[sea_orm(index(name = "idx-name", columns = ["a", "b"], unique))]`
+    /// on the Entity, one attribute per index.
+    fn indexes() -> Vec<IndexDef<Self::Column>> {
+        Vec::new()
+    }
+
     /// Check if the relation belongs to an Entity
     fn belongs_to<R>(related: R) -> RelationBuilder<Self, R>
     where
@@ -166,6 +202,16 @@ pub trait EntityTrait: EntityName {
     /// # }
     /// ```
     fn find() -> Select<Self> {
+        match Self::soft_delete_column() {
+            Some(col) => Select::new().filter(col.is_null()),
+            None => Select::new(),
+        }
+    }
+
+    /// Construct select statement to find one / all models, including the soft deleted ones
+    ///
+    /// This is the same as [EntityTrait::find] for entities which are not soft deletable.
+    fn find_with_deleted() -> Select<Self> {
         Select::new()
     }
 
@@ -839,6 +885,34 @@ pub trait EntityTrait: EntityName {
 
 #[cfg(test)]
 mod tests {
+    #[test]
+    fn test_find_by_id_1() {
+        use crate::tests_cfg::cake;
+        use crate::{entity::*, query::*, DbBackend};
+        assert_eq!(
+            cake::Entity::find_by_id(1)
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = 1"#,
+        );
+    }
+
+    #[test]
+    fn test_find_by_id_2() {
+        use crate::tests_cfg::cake_filling;
+        use crate::{entity::*, query::*, DbBackend};
+        assert_eq!(
+            cake_filling::Entity::find_by_id((1, 2))
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake_filling`.`cake_id`, `cake_filling`.`filling_id` FROM `cake_filling`",
+                "WHERE `cake_filling`.`cake_id` = 1 AND `cake_filling`.`filling_id` = 2",
+            ]
+            .join(" ")
+        );
+    }
+
     #[test]
     fn test_delete_by_id_1() {
         use crate::tests_cfg::cake;
@@ -914,4 +988,235 @@ mod tests {
         assert_eq!(hello::Entity.table_name(), "hello");
         assert_eq!(hello::Entity.schema_name(), Some("world"));
     }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn entity_model_schema_name() {
+        use crate::{entity::*, query::*, DbBackend};
+
+        mod invoice {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+            #[sea_orm(schema_name = "accounting", table_name = "invoices")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub amount: i32,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        assert_eq!(
+            invoice::Entity::find_by_id(1)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "invoices"."id", "invoices"."amount""#,
+                r#"FROM "accounting"."invoices" WHERE "invoices"."id" = 1"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            invoice::Entity::insert(invoice::ActiveModel {
+                id: Set(1),
+                amount: Set(100),
+            })
+            .build(DbBackend::Postgres)
+            .to_string(),
+            r#"INSERT INTO "accounting"."invoices" ("id", "amount") VALUES (1, 100)"#
+        );
+        assert_eq!(
+            invoice::Entity::update(invoice::ActiveModel {
+                id: Unchanged(1),
+                amount: Set(200),
+            })
+            .build(DbBackend::Postgres)
+            .to_string(),
+            r#"UPDATE "accounting"."invoices" SET "amount" = 200 WHERE "invoices"."id" = 1"#
+        );
+        assert_eq!(
+            invoice::Entity::delete_by_id(1)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"DELETE FROM "accounting"."invoices" WHERE "invoices"."id" = 1"#
+        );
+    }
+
+    #[cfg(all(feature = "macros", feature = "with-chrono"))]
+    mod post {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "post", soft_delete_column = "deleted_at")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub title: String,
+            pub deleted_at: Option<DateTimeUtc>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[test]
+    #[cfg(all(feature = "macros", feature = "with-chrono"))]
+    fn soft_delete_find() {
+        use crate::{entity::*, query::*, DbBackend};
+
+        assert!(matches!(
+            post::Entity::soft_delete_column(),
+            Some(post::Column::DeletedAt)
+        ));
+        assert!(crate::tests_cfg::cake::Entity::soft_delete_column().is_none());
+
+        assert_eq!(
+            post::Entity::find()
+                .filter(post::Column::Title.contains("Sea"))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"SELECT "post"."id", "post"."title", "post"."deleted_at" FROM "post" WHERE "post"."deleted_at" IS NULL AND "post"."title" LIKE '%Sea%'"#,
+        );
+        assert_eq!(
+            post::Entity::find_by_id(1)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"SELECT "post"."id", "post"."title", "post"."deleted_at" FROM "post" WHERE "post"."deleted_at" IS NULL AND "post"."id" = 1"#,
+        );
+        assert_eq!(
+            post::Entity::find_with_deleted()
+                .filter(post::Column::Title.contains("Sea"))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"SELECT "post"."id", "post"."title", "post"."deleted_at" FROM "post" WHERE "post"."title" LIKE '%Sea%'"#,
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "macros", feature = "with-chrono"))]
+    fn soft_delete_build() {
+        use crate::{entity::*, query::*, DbBackend};
+
+        assert_eq!(
+            post::Entity::delete_many()
+                .filter(
+                    Condition::any()
+                        .add(post::Column::Title.contains("Sea"))
+                        .add(post::Column::Id.eq(1))
+                )
+                .filter(post::Column::Id.lt(10))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"UPDATE "post" SET "deleted_at" = CURRENT_TIMESTAMP WHERE "post"."deleted_at" IS NULL"#,
+                r#"AND (("post"."title" LIKE '%Sea%' OR "post"."id" = 1) AND "post"."id" < 10)"#,
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            post::Entity::delete_by_id(1)
+                .filter(post::Column::Title.is_not_null())
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"UPDATE "post" SET "deleted_at" = CURRENT_TIMESTAMP WHERE "post"."deleted_at" IS NULL"#,
+                r#"AND ("post"."id" = 1 AND "post"."title" IS NOT NULL)"#,
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            post::Entity::delete_many()
+                .filter(post::Column::Title.eq("a ' LIMIT "))
+                .returning([post::Column::Id])
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"UPDATE "post" SET "deleted_at" = CURRENT_TIMESTAMP WHERE "post"."deleted_at" IS NULL"#,
+                r#"AND "post"."title" = E'a \' LIMIT ' RETURNING "id""#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            post::Entity::delete_many()
+                .hard_delete()
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"DELETE FROM "post""#,
+        );
+    }
+
+    #[smol_potat::test]
+    #[cfg(all(feature = "macros", feature = "with-chrono", feature = "mock"))]
+    async fn soft_delete_exec() -> Result<(), crate::DbErr> {
+        use crate::{entity::*, query::*, DbBackend, MockDatabase, MockExecResult, Transaction};
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results(vec![
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 2,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+            ])
+            .into_connection();
+
+        let delete_result = post::Entity::delete_many()
+            .filter(post::Column::Title.contains("Sea"))
+            .exec(&db)
+            .await?;
+        assert_eq!(delete_result.rows_affected, 2);
+
+        post::ActiveModel {
+            id: Set(1),
+            ..Default::default()
+        }
+        .delete(&db)
+        .await?;
+
+        post::Entity::delete_by_id(1)
+            .hard_delete()
+            .exec(&db)
+            .await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"UPDATE "post" SET "deleted_at" = CURRENT_TIMESTAMP WHERE "post"."deleted_at" IS NULL AND "post"."title" LIKE $1"#,
+                    vec!["%Sea%".into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"UPDATE "post" SET "deleted_at" = CURRENT_TIMESTAMP WHERE "post"."deleted_at" IS NULL AND "post"."id" = $1"#,
+                    vec![1i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"DELETE FROM "post" WHERE "post"."id" = $1"#,
+                    vec![1i32.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
 }
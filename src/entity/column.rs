@@ -1,5 +1,8 @@
-use crate::{EntityName, IdenStatic, Iterable};
-use sea_query::{Alias, BinOper, DynIden, Expr, SeaRc, SelectStatement, SimpleExpr, Value};
+use crate::{is_range_type, DbBackend, EntityName, IdenStatic, Iterable};
+use sea_query::{
+    Alias, BinOper, DynIden, Expr, Func, IntoIden, LikeExpr, PostgresQueryBuilder, SeaRc,
+    SelectStatement, SimpleExpr, SqlWriter, TableBuilder, Value,
+};
 use std::str::FromStr;
 
 /// Defines a Column for an Entity
@@ -10,6 +13,9 @@ pub struct ColumnDef {
     pub(crate) unique: bool,
     pub(crate) indexed: bool,
     pub(crate) default_value: Option<Value>,
+    pub(crate) check_values: Option<Vec<String>>,
+    pub(crate) generated: Option<String>,
+    pub(crate) generated_stored: bool,
 }
 
 /// The type of column as defined in the SQL format
@@ -103,8 +109,8 @@ macro_rules! bind_oper_with_enum_casting {
             let val = Expr::val(v);
             let col_def = self.def();
             let col_type = col_def.get_column_type();
-            let expr = match col_type.get_enum_name() {
-                Some(enum_name) => val.as_enum(Alias::new(enum_name)),
+            let expr = match col_type.get_cast_name() {
+                Some(type_name) => val.as_enum(Alias::new(type_name)),
                 None => val.into(),
             };
             Expr::tbl(self.entity_name(), *self).binary(BinOper::$bin_op, expr)
@@ -164,6 +170,32 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
         (self.entity_name(), SeaRc::new(*self) as DynIden)
     }
 
+    /// Refer to the column of a table joined under an alias, e.g. by [QuerySelect::join_as](crate::QuerySelect::join_as)
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::Alias, tests_cfg::{cake, fruit}, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .join_as(JoinType::InnerJoin, cake::Relation::Fruit.def(), Alias::new("f"))
+    ///         .filter(fruit::Column::Name.with_table_alias(Alias::new("f")).eq("Apple"))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     [
+    ///         "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+    ///         "INNER JOIN `fruit` AS `f` ON `cake`.`id` = `f`.`cake_id`",
+    ///         "WHERE `f`.`name` = 'Apple'",
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn with_table_alias<I>(&self, alias: I) -> Expr
+    where
+        I: IntoIden,
+    {
+        Expr::tbl(alias, *self)
+    }
+
     bind_oper_with_enum_casting!(eq, Equal);
     bind_oper_with_enum_casting!(ne, NotEqual);
     bind_oper!(gt);
@@ -285,6 +317,77 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
         Expr::tbl(self.entity_name(), *self).like(pattern)
     }
 
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Name.starts_with_escaped("100%_"))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     r"SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` LIKE '100\\%\\_%' ESCAPE '\\'"
+    /// );
+    /// ```
+    fn starts_with_escaped(&self, s: &str) -> SimpleExpr {
+        let pattern = format!("{}%", escape_like(s));
+        Expr::tbl(self.entity_name(), *self).like(LikeExpr::new(pattern).escape(LIKE_ESCAPE))
+    }
+
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Name.ends_with_escaped("100%_"))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     r"SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` LIKE '%100\\%\\_' ESCAPE '\\'"
+    /// );
+    /// ```
+    fn ends_with_escaped(&self, s: &str) -> SimpleExpr {
+        let pattern = format!("%{}", escape_like(s));
+        Expr::tbl(self.entity_name(), *self).like(LikeExpr::new(pattern).escape(LIKE_ESCAPE))
+    }
+
+    /// Match the values containing `s`, with any `%` and `_` in `s` matched literally
+    /// rather than as wildcards
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Name.contains_escaped("100%_"))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     r"SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` LIKE '%100\\%\\_%' ESCAPE '\\'"
+    /// );
+    /// ```
+    fn contains_escaped(&self, s: &str) -> SimpleExpr {
+        let pattern = format!("%{}%", escape_like(s));
+        Expr::tbl(self.entity_name(), *self).like(LikeExpr::new(pattern).escape(LIKE_ESCAPE))
+    }
+
+    /// Case insensitive [ColumnTrait::contains_escaped], with the `ILIKE` operator of Postgres.
+    /// The expression is only valid on Postgres.
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Name.icontains_escaped("100%_"))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" ILIKE E'%100\\%\\_%' ESCAPE E'\\'"#
+    /// );
+    /// ```
+    fn icontains_escaped(&self, s: &str) -> SimpleExpr {
+        let pattern = format!("%{}%", escape_like(s));
+        Expr::cust_with_values(
+            &format!("{} ILIKE $1 ESCAPE E'\\\\'", quoted_column_ref(self)),
+            [pattern],
+        )
+    }
+
     bind_func_no_params!(max);
     bind_func_no_params!(min);
     bind_func_no_params!(sum);
@@ -293,6 +396,22 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     bind_func_no_params!(is_not_null);
 
     /// Perform an operation if the column is null
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// let select = fruit::Entity::find()
+    ///     .select_only()
+    ///     .column_as(fruit::Column::CakeId.if_null(0), "cake_id");
+    ///
+    /// assert_eq!(
+    ///     select.clone().build(DbBackend::MySql).to_string(),
+    ///     "SELECT IFNULL(`fruit`.`cake_id`, 0) AS `cake_id` FROM `fruit`"
+    /// );
+    /// assert_eq!(
+    ///     select.build(DbBackend::Postgres).to_string(),
+    ///     r#"SELECT COALESCE("fruit"."cake_id", 0) AS "cake_id" FROM "fruit""#
+    /// );
+    /// ```
     fn if_null<V>(&self, v: V) -> SimpleExpr
     where
         V: Into<Value>,
@@ -300,6 +419,63 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
         Expr::tbl(self.entity_name(), *self).if_null(v)
     }
 
+    /// Take the first non null value of this column and the given expressions, in order
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::Expr, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .select_only()
+    ///         .column_as(
+    ///             fruit::Column::CakeId.coalesce([Expr::col(fruit::Column::Id), Expr::val(0)]),
+    ///             "cake_id"
+    ///         )
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT COALESCE("fruit"."cake_id", "id", 0) AS "cake_id" FROM "fruit""#
+    /// );
+    /// ```
+    fn coalesce<V, I>(&self, v: I) -> SimpleExpr
+    where
+        V: Into<SimpleExpr>,
+        I: IntoIterator<Item = V>,
+    {
+        Func::coalesce(
+            std::iter::once(Expr::tbl(self.entity_name(), *self).into())
+                .chain(v.into_iter().map(Into::into)),
+        )
+    }
+
+    /// Cast this column to another type, given by name or as a [ColumnType]
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Name.cast_as("integer").eq(5))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE CAST("cake"."name" AS integer) = 5"#
+    /// );
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .select_only()
+    ///         .column_as(cake::Column::Id.cast_as(ColumnType::Text), "id")
+    ///         .build(DbBackend::Sqlite)
+    ///         .to_string(),
+    ///     r#"SELECT CAST("cake"."id" AS text) AS "id" FROM "cake""#
+    /// );
+    /// ```
+    fn cast_as<T>(&self, type_name: T) -> Expr
+    where
+        T: IntoCastType,
+    {
+        Expr::expr(
+            SimpleExpr::from(Expr::tbl(self.entity_name(), *self))
+                .cast_as(Alias::new(&type_name.into_cast_type())),
+        )
+    }
+
     bind_vec_func!(is_in);
     bind_vec_func!(is_not_in);
 
@@ -307,6 +483,171 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     bind_subquery_func!(not_in_subquery);
 }
 
+/// Query the members of a JSON column. As the JSON operators differ between databases,
+/// each method takes the [DbBackend] the expression is built for.
+///
+/// ```
+/// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+///
+/// assert_eq!(
+///     cake::Entity::find()
+///         .filter(
+///             cake::Column::Name
+///                 .json_get_text(DbBackend::Postgres, "flavor")
+///                 .eq("chocolate")
+///         )
+///         .build(DbBackend::Postgres)
+///         .to_string(),
+///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" ->> 'flavor' = 'chocolate'"#
+/// );
+/// ```
+pub trait JsonColumnTrait: ColumnTrait {
+    /// Get the JSON value of a top-level key, i.e. `col -> 'key'` on Postgres
+    /// and `JSON_EXTRACT(col, '$.key')` on MySQL and SQLite
+    fn json_get(&self, backend: DbBackend, key: &str) -> Expr {
+        Expr::expr(match backend {
+            DbBackend::Postgres => {
+                Expr::cust_with_values(&format!("{} -> $1", quoted_column_ref(self)), [key])
+            }
+            DbBackend::MySql | DbBackend::Sqlite => json_extract(self, key),
+        })
+    }
+
+    /// Get the value of a top-level key as text, i.e. `col ->> 'key'` on Postgres,
+    /// `JSON_UNQUOTE(JSON_EXTRACT(col, '$.key'))` on MySQL and `JSON_EXTRACT(col, '$.key')` on SQLite
+    fn json_get_text(&self, backend: DbBackend, key: &str) -> Expr {
+        Expr::expr(match backend {
+            DbBackend::Postgres => {
+                Expr::cust_with_values(&format!("{} ->> $1", quoted_column_ref(self)), [key])
+            }
+            DbBackend::MySql => Func::cust(Alias::new("JSON_UNQUOTE")).arg(json_extract(self, key)),
+            DbBackend::Sqlite => json_extract(self, key),
+        })
+    }
+
+    /// Check if the JSON column contains the given JSON document,
+    /// i.e. `col @> value` on Postgres (`jsonb` only) and `JSON_CONTAINS(col, value)` on MySQL
+    ///
+    /// SQLite has no JSON containment function, there the column contains the document if it is
+    /// equal to it, or is an array with an element equal to it. This is the same as on the other
+    /// backends for a scalar document, but an object is not matched against a part of another.
+    fn json_contains<V>(&self, backend: DbBackend, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        match backend {
+            DbBackend::Postgres => Expr::tbl(self.entity_name(), *self)
+                .binary(BinOper::Contains, SimpleExpr::Value(v.into())),
+            DbBackend::MySql => Func::cust(Alias::new("JSON_CONTAINS"))
+                .args([Expr::tbl(self.entity_name(), *self), Expr::val(v)]),
+            DbBackend::Sqlite => {
+                let col = quoted_column_ref(self);
+                let v = v.into();
+                Expr::cust_with_values(
+                    &format!(
+                        "(JSON({col}) = JSON(?) OR EXISTS (SELECT 1 FROM JSON_EACH({col}) AS e \
+                         WHERE JSON_TYPE({col}) = 'array' AND e.value = JSON_EXTRACT(?, '$')))",
+                        col = col
+                    ),
+                    [v.clone(), v],
+                )
+            }
+        }
+    }
+
+    /// Check if the JSON column has a top-level key, i.e. `col ? 'key'` on Postgres,
+    /// `JSON_CONTAINS_PATH(col, 'one', '$.key')` on MySQL and `JSON_TYPE(col, '$.key') IS NOT NULL` on SQLite
+    fn json_has_key(&self, backend: DbBackend, key: &str) -> SimpleExpr {
+        match backend {
+            DbBackend::Postgres => {
+                Expr::cust_with_values(&format!("{} ? $1", quoted_column_ref(self)), [key])
+            }
+            DbBackend::MySql => Func::cust(Alias::new("JSON_CONTAINS_PATH")).args([
+                Expr::tbl(self.entity_name(), *self),
+                Expr::val("one"),
+                Expr::val(json_path(key)),
+            ]),
+            DbBackend::Sqlite => Expr::expr(Func::cust(Alias::new("JSON_TYPE")).args([
+                Expr::tbl(self.entity_name(), *self),
+                Expr::val(json_path(key)),
+            ]))
+            .is_not_null(),
+        }
+    }
+}
+
+impl<C> JsonColumnTrait for C where C: ColumnTrait {}
+
+/// A type to cast an expression to, see [ColumnTrait::cast_as]
+///
+/// A [ColumnType] is named as on Postgres, e.g. `integer` or `varchar`, which SQLite understands too.
+/// MySQL can only cast to a few types such as `SIGNED`, `CHAR` or `DECIMAL`, pass them by name instead.
+pub trait IntoCastType {
+    /// The name of the type in SQL
+    fn into_cast_type(self) -> String;
+}
+
+impl IntoCastType for &str {
+    fn into_cast_type(self) -> String {
+        self.to_owned()
+    }
+}
+
+impl IntoCastType for String {
+    fn into_cast_type(self) -> String {
+        self
+    }
+}
+
+impl IntoCastType for ColumnType {
+    fn into_cast_type(self) -> String {
+        let mut sql = SqlWriter::new();
+        PostgresQueryBuilder.prepare_column_type(&self.into(), &mut sql);
+        sql.result()
+    }
+}
+
+const LIKE_ESCAPE: char = '\\';
+
+/// Escape the wildcards of a `LIKE` pattern, and the escape character itself
+fn escape_like(s: &str) -> String {
+    let mut pattern = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '%' | '_' | LIKE_ESCAPE) {
+            pattern.push(LIKE_ESCAPE);
+        }
+        pattern.push(c);
+    }
+    pattern
+}
+
+/// The column quoted with double quotes, as on Postgres and SQLite
+fn quoted_column_ref<C>(col: &C) -> String
+where
+    C: ColumnTrait,
+{
+    let quote = |s: String| format!("\"{}\"", s.replace('"', "\"\""));
+    format!(
+        "{}.{}",
+        quote(col.entity_name().to_string()),
+        quote(col.to_string())
+    )
+}
+
+fn json_path(key: &str) -> String {
+    format!("$.{}", key)
+}
+
+fn json_extract<C>(col: &C, key: &str) -> SimpleExpr
+where
+    C: ColumnTrait,
+{
+    Func::cust(Alias::new("JSON_EXTRACT")).args([
+        Expr::tbl(col.entity_name(), *col),
+        Expr::val(json_path(key)),
+    ])
+}
+
 impl ColumnType {
     /// instantiate a new [ColumnDef]
     pub fn def(self) -> ColumnDef {
@@ -316,6 +657,9 @@ impl ColumnType {
             unique: false,
             indexed: false,
             default_value: None,
+            check_values: None,
+            generated: None,
+            generated_stored: false,
         }
     }
 
@@ -325,6 +669,16 @@ impl ColumnType {
             _ => None,
         }
     }
+
+    /// The type to cast a value to when writing it to or comparing it with a column of this type,
+    /// for the enums and the [PgRange](crate::PgRange) which are bound as text
+    pub(crate) fn get_cast_name(&self) -> Option<&String> {
+        match self {
+            ColumnType::Enum(s, _) => Some(s),
+            ColumnType::Custom(s) if is_range_type(s) => Some(s),
+            _ => None,
+        }
+    }
 }
 
 impl ColumnDef {
@@ -360,6 +714,32 @@ impl ColumnDef {
         self
     }
 
+    /// Restrict the column to the given values with a `CHECK` constraint on table creation
+    pub fn check_in<I, S>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.check_values = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Mark the column as `GENERATED ALWAYS AS (expr)`, computed by the database on write
+    /// if `stored` or on read otherwise. Inserts and updates leave the column out.
+    pub fn generated<T>(mut self, expr: T, stored: bool) -> Self
+    where
+        T: Into<String>,
+    {
+        self.generated = Some(expr.into());
+        self.generated_stored = stored;
+        self
+    }
+
+    /// Check if the column is generated by the database
+    pub fn is_generated(&self) -> bool {
+        self.generated.is_some()
+    }
+
     /// Get [ColumnType] as reference
     pub fn get_column_type(&self) -> &ColumnType {
         &self.col_type
@@ -447,10 +827,181 @@ impl From<sea_query::ColumnType> for ColumnType {
 #[cfg(test)]
 mod tests {
     use crate::{
-        tests_cfg::*, ColumnTrait, Condition, DbBackend, EntityTrait, QueryFilter, QueryTrait,
+        tests_cfg::*, ColumnTrait, ColumnType, Condition, DbBackend, EntityTrait, JsonColumnTrait,
+        QueryFilter, QuerySelect, QueryTrait,
     };
     use sea_query::Query;
 
+    #[test]
+    fn json_column_postgres() {
+        let find = || cake::Entity::find().select_only().column(cake::Column::Id);
+        let sql =
+            |select: crate::Select<cake::Entity>| select.build(DbBackend::Postgres).to_string();
+
+        assert_eq!(
+            sql(find().filter(cake::Column::Name.json_get(DbBackend::Postgres, "a").eq(1))),
+            r#"SELECT "cake"."id" FROM "cake" WHERE "cake"."name" -> 'a' = 1"#
+        );
+        assert_eq!(
+            sql(find().filter(
+                cake::Column::Name
+                    .json_get_text(DbBackend::Postgres, "a")
+                    .eq("b")
+            )),
+            r#"SELECT "cake"."id" FROM "cake" WHERE "cake"."name" ->> 'a' = 'b'"#
+        );
+        assert_eq!(
+            sql(find().filter(cake::Column::Name.json_contains(DbBackend::Postgres, r#"{"a":1}"#))),
+            r#"SELECT "cake"."id" FROM "cake" WHERE "cake"."name" @> E'{\"a\":1}'"#
+        );
+        assert_eq!(
+            sql(find().filter(cake::Column::Name.json_has_key(DbBackend::Postgres, "a"))),
+            r#"SELECT "cake"."id" FROM "cake" WHERE "cake"."name" ? 'a'"#
+        );
+    }
+
+    #[test]
+    fn json_column_mysql() {
+        let find = || cake::Entity::find().select_only().column(cake::Column::Id);
+        let sql = |select: crate::Select<cake::Entity>| select.build(DbBackend::MySql).to_string();
+
+        assert_eq!(
+            sql(find().filter(cake::Column::Name.json_get(DbBackend::MySql, "a").eq(1))),
+            "SELECT `cake`.`id` FROM `cake` WHERE JSON_EXTRACT(`cake`.`name`, '$.a') = 1"
+        );
+        assert_eq!(
+            sql(find().filter(cake::Column::Name.json_get_text(DbBackend::MySql, "a").eq("b"))),
+            "SELECT `cake`.`id` FROM `cake` WHERE JSON_UNQUOTE(JSON_EXTRACT(`cake`.`name`, '$.a')) = 'b'"
+        );
+        assert_eq!(
+            sql(find().filter(cake::Column::Name.json_contains(DbBackend::MySql, r#"{"a":1}"#))),
+            r#"SELECT `cake`.`id` FROM `cake` WHERE JSON_CONTAINS(`cake`.`name`, '{\"a\":1}')"#
+        );
+        assert_eq!(
+            sql(find().filter(cake::Column::Name.json_has_key(DbBackend::MySql, "a"))),
+            "SELECT `cake`.`id` FROM `cake` WHERE JSON_CONTAINS_PATH(`cake`.`name`, 'one', '$.a')"
+        );
+    }
+
+    #[test]
+    fn json_column_sqlite() {
+        let find = || cake::Entity::find().select_only().column(cake::Column::Id);
+        let sql = |select: crate::Select<cake::Entity>| select.build(DbBackend::Sqlite).to_string();
+
+        assert_eq!(
+            sql(find().filter(
+                cake::Column::Name
+                    .json_get_text(DbBackend::Sqlite, "a")
+                    .eq("b")
+            )),
+            r#"SELECT "cake"."id" FROM "cake" WHERE JSON_EXTRACT("cake"."name", '$.a') = 'b'"#
+        );
+        assert_eq!(
+            sql(find().filter(cake::Column::Name.json_has_key(DbBackend::Sqlite, "a"))),
+            r#"SELECT "cake"."id" FROM "cake" WHERE JSON_TYPE("cake"."name", '$.a') IS NOT NULL"#
+        );
+        assert_eq!(
+            sql(find().filter(cake::Column::Name.json_contains(DbBackend::Sqlite, "1"))),
+            [
+                r#"SELECT "cake"."id" FROM "cake" WHERE (JSON("cake"."name") = JSON('1')"#,
+                r#"OR EXISTS (SELECT 1 FROM JSON_EACH("cake"."name") AS e"#,
+                r#"WHERE JSON_TYPE("cake"."name") = 'array' AND e.value = JSON_EXTRACT('1', '$')))"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn cast_as() {
+        let select = |ty: ColumnType| {
+            cake::Entity::find()
+                .select_only()
+                .column_as(cake::Column::Name.cast_as("SIGNED"), "name")
+                .filter(cake::Column::Id.cast_as(ty).eq("5"))
+        };
+        assert_eq!(
+            select(ColumnType::String(Some(10)))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT CAST("cake"."name" AS SIGNED) AS "name" FROM "cake""#,
+                r#"WHERE CAST("cake"."id" AS varchar(10)) = '5'"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            select(ColumnType::Text)
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            [
+                r#"SELECT CAST("cake"."name" AS SIGNED) AS "name" FROM "cake""#,
+                r#"WHERE CAST("cake"."id" AS text) = '5'"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            select(ColumnType::Char(None))
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT CAST(`cake`.`name` AS SIGNED) AS `name` FROM `cake`",
+                "WHERE CAST(`cake`.`id` AS char) = '5'",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            cake::Entity::find()
+                .filter(cake::Column::Id.cast_as(ColumnType::BigInteger).gt(1))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE CAST("cake"."id" AS bigint) > 1"#
+        );
+    }
+
+    #[test]
+    fn like_escaped() {
+        use crate::Statement;
+
+        let find = || cake::Entity::find().select_only().column(cake::Column::Id);
+        let like = |pattern: &str| {
+            Statement::from_sql_and_values(
+                DbBackend::Sqlite,
+                r#"SELECT "cake"."id" FROM "cake" WHERE "cake"."name" LIKE ? ESCAPE '\'"#,
+                [pattern.into()],
+            )
+        };
+
+        assert_eq!(
+            find()
+                .filter(cake::Column::Name.contains_escaped(r"50%_off\"))
+                .build(DbBackend::Sqlite),
+            like(r"%50\%\_off\\%")
+        );
+        assert_eq!(
+            find()
+                .filter(cake::Column::Name.starts_with_escaped("_"))
+                .build(DbBackend::Sqlite),
+            like(r"\_%")
+        );
+        assert_eq!(
+            find()
+                .filter(cake::Column::Name.ends_with_escaped("%"))
+                .build(DbBackend::Sqlite),
+            like(r"%\%")
+        );
+        assert_eq!(
+            find()
+                .filter(cake::Column::Name.icontains_escaped(r"50%_off\"))
+                .filter(cake::Column::Id.gt(1))
+                .build(DbBackend::Postgres),
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"SELECT "cake"."id" FROM "cake" WHERE "cake"."name" ILIKE $1 ESCAPE E'\\' AND "cake"."id" > $2"#,
+                [r"%50\%\_off\\%".into(), 1i32.into()],
+            )
+        );
+    }
+
     #[test]
     fn test_in_subquery_1() {
         assert_eq!(
@@ -525,6 +1076,48 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_coalesce() {
+        use sea_query::Expr;
+
+        let select = fruit::Entity::find()
+            .select_only()
+            .column_as(fruit::Column::CakeId.coalesce([Expr::val(0)]), "cake_id")
+            .filter(
+                fruit::Column::CakeId
+                    .coalesce([Expr::col(fruit::Column::Id)])
+                    .equals(Expr::tbl(fruit::Entity, fruit::Column::Id)),
+            );
+
+        assert_eq!(
+            select.clone().build(DbBackend::MySql).to_string(),
+            [
+                "SELECT COALESCE(`fruit`.`cake_id`, 0) AS `cake_id` FROM `fruit`",
+                "WHERE COALESCE(`fruit`.`cake_id`, `id`) = `fruit`.`id`",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            select.clone().build(DbBackend::Postgres).to_string(),
+            [
+                r#"SELECT COALESCE("fruit"."cake_id", 0) AS "cake_id" FROM "fruit""#,
+                r#"WHERE COALESCE("fruit"."cake_id", "id") = "fruit"."id""#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            fruit::Entity::find()
+                .select_only()
+                .column_as(
+                    fruit::Column::CakeId.coalesce(Vec::<Expr>::new()),
+                    "cake_id"
+                )
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            r#"SELECT COALESCE("fruit"."cake_id") AS "cake_id" FROM "fruit""#
+        );
+    }
+
     #[test]
     #[cfg(feature = "macros")]
     fn entity_model_column_1() {
@@ -0,0 +1,70 @@
+use crate::ColumnTrait;
+
+/// Defines an index spanning one or more columns of an Entity
+#[derive(Debug, Clone)]
+pub struct IndexDef<C>
+where
+    C: ColumnTrait,
+{
+    pub(crate) name: Option<String>,
+    pub(crate) columns: Vec<C>,
+    pub(crate) unique: bool,
+    pub(crate) condition: Option<String>,
+}
+
+impl<C> IndexDef<C>
+where
+    C: ColumnTrait,
+{
+    /// Create an index on the given columns, in order
+    pub fn new<I>(columns: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+    {
+        Self {
+            name: None,
+            columns: columns.into_iter().collect(),
+            unique: false,
+            condition: None,
+        }
+    }
+
+    /// Set the name of the index, defaults to `idx-{table}-{column1}-{column2}...`
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_owned());
+        self
+    }
+
+    /// Marks the index as `UNIQUE`
+    pub fn unique(mut self) -> Self {
+        self.unique = true;
+        self
+    }
+
+    /// Make it a partial index, only covering the rows matching the SQL `condition`.
+    /// Partial indexes are supported by Postgres and SQLite only.
+    pub fn partial(mut self, condition: &str) -> Self {
+        self.condition = Some(condition.to_owned());
+        self
+    }
+
+    /// Get the name of the index, if set
+    pub fn get_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Get the columns of the index
+    pub fn get_columns(&self) -> &[C] {
+        &self.columns
+    }
+
+    /// Get whether the index is unique
+    pub fn is_unique(&self) -> bool {
+        self.unique
+    }
+
+    /// Get the condition of a partial index
+    pub fn get_condition(&self) -> Option<&str> {
+        self.condition.as_deref()
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{DbErr, QueryResult, TryGetError, TryGetable};
+use sea_query::{ColumnType, Nullable, Value, ValueType, ValueTypeErr};
+use serde::{Deserialize, Serialize};
+use std::{fmt, num::ParseIntError, str::FromStr};
+
+macro_rules! int128 {
+    ( $name: ident, $type: ty, $doc: literal ) => {
+        #[doc = $doc]
+        ///
+        /// No database backend can bind a 128-bit integer, so it is stored as its decimal string,
+        /// in a `String`, `Char` or `Text` column. It is the [crate::ActiveEnum::Value] of an
+        /// ActiveEnum deriving `DeriveActiveEnum` with a 128-bit `rs_type`.
+        #[derive(
+            Clone,
+            Copy,
+            Debug,
+            Default,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Hash,
+            Serialize,
+            Deserialize,
+        )]
+        #[serde(transparent)]
+        pub struct $name(pub $type);
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseIntError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.trim().parse().map(Self)
+            }
+        }
+
+        impl From<$type> for $name {
+            fn from(v: $type) -> Self {
+                Self(v)
+            }
+        }
+
+        impl From<$name> for $type {
+            fn from(v: $name) -> Self {
+                v.0
+            }
+        }
+
+        impl PartialEq<$type> for $name {
+            fn eq(&self, other: &$type) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl From<$name> for Value {
+            fn from(v: $name) -> Self {
+                Value::String(Some(Box::new(v.to_string())))
+            }
+        }
+
+        impl Nullable for $name {
+            fn null() -> Value {
+                Value::String(None)
+            }
+        }
+
+        impl ValueType for $name {
+            fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+                match v {
+                    Value::String(Some(s)) => s.parse().map_err(|_| ValueTypeErr),
+                    _ => Err(ValueTypeErr),
+                }
+            }
+
+            fn type_name() -> String {
+                stringify!($name).to_owned()
+            }
+
+            fn column_type() -> ColumnType {
+                ColumnType::String(None)
+            }
+        }
+
+        impl TryGetable for $name {
+            fn try_get(res: &QueryResult, pre: &str, col: &str) -> Result<Self, TryGetError> {
+                let s = String::try_get(res, pre, col)?;
+                s.parse().map_err(|e| {
+                    TryGetError::DbErr(DbErr::Type(format!(
+                        "{} is not a valid {}: {}",
+                        s,
+                        stringify!($type),
+                        e
+                    )))
+                })
+            }
+        }
+    };
+}
+
+int128!(
+    I128,
+    i128,
+    "An `i128`, which can be written to and read from a database"
+);
+int128!(
+    U128,
+    u128,
+    "A `u128`, which can be written to and read from a database"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn int128_value() {
+        let value: Value = I128(i128::MIN).into();
+        assert_eq!(
+            value,
+            Value::from("-170141183460469231731687303715884105728")
+        );
+        assert_eq!(<I128 as ValueType>::try_from(value).unwrap(), i128::MIN);
+
+        let value: Value = U128(u128::MAX).into();
+        assert_eq!(
+            value,
+            Value::from("340282366920938463463374607431768211455")
+        );
+        assert_eq!(<U128 as ValueType>::try_from(value).unwrap(), u128::MAX);
+
+        assert_eq!(" 12 ".parse::<U128>(), Ok(U128(12)));
+        assert!(<U128 as ValueType>::try_from(Value::from("-1")).is_err());
+        assert!(<I128 as ValueType>::try_from(Value::BigInt(Some(1))).is_err());
+    }
+}
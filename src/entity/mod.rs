@@ -100,8 +100,11 @@ mod active_model;
 mod base_entity;
 mod column;
 mod identity;
+mod index;
+mod int128;
 mod link;
 mod model;
+mod pg_range;
 /// Re-export common types from the entity
 pub mod prelude;
 mod primary_key;
@@ -112,8 +115,11 @@ pub use active_model::*;
 pub use base_entity::*;
 pub use column::*;
 pub use identity::*;
+pub use index::*;
+pub use int128::*;
 pub use link::*;
 pub use model::*;
+pub use pg_range::*;
 // pub use prelude::*;
 pub use primary_key::*;
 pub use relation::*;
@@ -37,6 +37,28 @@ pub trait ModelTrait: Clone + Send + Debug {
         l.find_linked().belongs_to_tbl_alias(self, tbl_alias)
     }
 
+    /// Load the related Model through a has-one or belongs-to relation,
+    /// filtered by the key values of this Model
+    async fn load_one<'a, R, C>(&self, r: R, db: &'a C) -> Result<Option<R::Model>, DbErr>
+    where
+        R: EntityTrait,
+        Self::Entity: Related<R>,
+        C: ConnectionTrait,
+    {
+        self.find_related(r).one(db).await
+    }
+
+    /// Load the related Models through a has-many relation,
+    /// filtered by the key values of this Model
+    async fn load_many<'a, R, C>(&self, r: R, db: &'a C) -> Result<Vec<R::Model>, DbErr>
+    where
+        R: EntityTrait,
+        Self::Entity: Related<R>,
+        C: ConnectionTrait,
+    {
+        self.find_related(r).all(db).await
+    }
+
     /// Delete an model
     async fn delete<'a, A, C>(self, db: &'a C) -> Result<DeleteResult, DbErr>
     where
@@ -115,3 +137,107 @@ pub trait FromQueryResult: Sized {
         SelectorRaw::<SelectModel<Self>>::from_statement(stmt)
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{entity::prelude::*, tests_cfg::*, DbBackend, MockDatabase, Transaction};
+    use pretty_assertions::assert_eq;
+
+    #[smol_potat::test]
+    async fn load_one() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![cake::Model {
+                id: 1,
+                name: "Chocolate Forest".to_owned(),
+            }]])
+            .into_connection();
+
+        let apple = fruit::Model {
+            id: 2,
+            name: "Apple".to_owned(),
+            cake_id: Some(1),
+        };
+
+        assert_eq!(
+            apple.load_one(cake::Entity, &db).await?,
+            Some(cake::Model {
+                id: 1,
+                name: "Chocolate Forest".to_owned(),
+            })
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                    r#"INNER JOIN "fruit" ON "fruit"."cake_id" = "cake"."id""#,
+                    r#"WHERE "fruit"."id" = $1 LIMIT $2"#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec![2i32.into(), 1u64.into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn load_many() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                fruit::Model {
+                    id: 2,
+                    name: "Apple".to_owned(),
+                    cake_id: Some(1),
+                },
+                fruit::Model {
+                    id: 3,
+                    name: "Orange".to_owned(),
+                    cake_id: Some(1),
+                },
+            ]])
+            .into_connection();
+
+        let cake = cake::Model {
+            id: 1,
+            name: "Chocolate Forest".to_owned(),
+        };
+
+        assert_eq!(
+            cake.load_many(fruit::Entity, &db).await?,
+            vec![
+                fruit::Model {
+                    id: 2,
+                    name: "Apple".to_owned(),
+                    cake_id: Some(1),
+                },
+                fruit::Model {
+                    id: 3,
+                    name: "Orange".to_owned(),
+                    cake_id: Some(1),
+                },
+            ]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                    r#"INNER JOIN "cake" ON "cake"."id" = "fruit"."cake_id""#,
+                    r#"WHERE "cake"."id" = $1"#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec![1i32.into()]
+            )]
+        );
+
+        Ok(())
+    }
+}
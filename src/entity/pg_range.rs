@@ -0,0 +1,287 @@
+use sea_query::{Alias, ColumnType, Nullable, SeaRc, Value, ValueType, ValueTypeErr};
+use std::{
+    fmt,
+    ops::{Bound, Range, RangeInclusive},
+};
+
+/// A Postgres range, e.g. an `int4range` or a `tsrange`, of the element types in [PgRangeElement]
+///
+/// A range is bound as text in its range literal form, e.g. `[1,10)`. It is cast to the range type
+/// when it is written to or compared with a column of that type, which is the column type
+/// [ValueType::column_type] gives to a `PgRange` field of a model, e.g. `Custom("int4range")`.
+///
+/// An empty range is read as unbounded on both ends, as SQLx does.
+///
+/// ```
+/// use sea_orm::{sea_query::Value, PgRange};
+/// use std::ops::Bound;
+///
+/// let range = PgRange::from(1..10);
+/// assert_eq!(range.start, Bound::Included(1));
+/// assert_eq!(range.end, Bound::Excluded(10));
+/// assert_eq!(Value::from(range), Value::from(r#"["1","10")"#));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PgRange<T> {
+    /// The lower bound of the range
+    pub start: Bound<T>,
+    /// The upper bound of the range
+    pub end: Bound<T>,
+}
+
+/// An element type of a [PgRange], with the name of its Postgres range type
+pub trait PgRangeElement: Sized {
+    /// The Postgres range type of this element, e.g. `int4range`
+    fn range_type() -> &'static str;
+
+    /// Write the element as a bound of a range literal
+    fn to_bound(&self) -> String;
+
+    /// Parse the element from a bound of a range literal
+    fn from_bound(s: &str) -> Option<Self>;
+}
+
+macro_rules! pg_range_element_from_str {
+    ( $type: ty, $range_type: literal ) => {
+        impl PgRangeElement for $type {
+            fn range_type() -> &'static str {
+                $range_type
+            }
+
+            fn to_bound(&self) -> String {
+                self.to_string()
+            }
+
+            fn from_bound(s: &str) -> Option<Self> {
+                s.parse().ok()
+            }
+        }
+    };
+}
+
+pg_range_element_from_str!(i32, "int4range");
+pg_range_element_from_str!(i64, "int8range");
+
+#[cfg(feature = "with-rust_decimal")]
+pg_range_element_from_str!(rust_decimal::Decimal, "numrange");
+
+#[cfg(feature = "with-chrono")]
+pg_range_element_from_str!(chrono::NaiveDate, "daterange");
+
+#[cfg(feature = "with-chrono")]
+impl PgRangeElement for chrono::NaiveDateTime {
+    fn range_type() -> &'static str {
+        "tsrange"
+    }
+
+    fn to_bound(&self) -> String {
+        self.format("%Y-%m-%d %H:%M:%S%.f").to_string()
+    }
+
+    fn from_bound(s: &str) -> Option<Self> {
+        Self::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f").ok()
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl PgRangeElement for chrono::DateTime<chrono::FixedOffset> {
+    fn range_type() -> &'static str {
+        "tstzrange"
+    }
+
+    fn to_bound(&self) -> String {
+        self.format("%Y-%m-%d %H:%M:%S%.f%:z").to_string()
+    }
+
+    fn from_bound(s: &str) -> Option<Self> {
+        Self::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f%:z").ok()
+    }
+}
+
+impl<T> PgRange<T>
+where
+    T: PgRangeElement,
+{
+    /// Parse a range literal, e.g. `[1,10)` or `(,"2022-01-01")`
+    fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("empty") {
+            return Some(Self {
+                start: Bound::Unbounded,
+                end: Bound::Unbounded,
+            });
+        }
+        let (start, end) = s.get(1..s.len().checked_sub(1)?)?.split_once(',')?;
+        let bound = |inclusive: bool, element: &str| {
+            let element = element.trim_matches('"');
+            Some(if element.is_empty() {
+                Bound::Unbounded
+            } else if inclusive {
+                Bound::Included(T::from_bound(element)?)
+            } else {
+                Bound::Excluded(T::from_bound(element)?)
+            })
+        };
+        Some(Self {
+            start: bound(s.starts_with('['), start)?,
+            end: bound(s.ends_with(']'), end)?,
+        })
+    }
+}
+
+impl<T> fmt::Display for PgRange<T>
+where
+    T: PgRangeElement,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.start {
+            Bound::Included(v) => write!(f, "[\"{}\"", v.to_bound())?,
+            Bound::Excluded(v) => write!(f, "(\"{}\"", v.to_bound())?,
+            Bound::Unbounded => write!(f, "(")?,
+        }
+        match &self.end {
+            Bound::Included(v) => write!(f, ",\"{}\"]", v.to_bound()),
+            Bound::Excluded(v) => write!(f, ",\"{}\")", v.to_bound()),
+            Bound::Unbounded => write!(f, ",)"),
+        }
+    }
+}
+
+impl<T> From<Range<T>> for PgRange<T> {
+    fn from(range: Range<T>) -> Self {
+        Self {
+            start: Bound::Included(range.start),
+            end: Bound::Excluded(range.end),
+        }
+    }
+}
+
+impl<T> From<RangeInclusive<T>> for PgRange<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        Self {
+            start: Bound::Included(start),
+            end: Bound::Included(end),
+        }
+    }
+}
+
+impl<T> From<(Bound<T>, Bound<T>)> for PgRange<T> {
+    fn from((start, end): (Bound<T>, Bound<T>)) -> Self {
+        Self { start, end }
+    }
+}
+
+#[cfg(feature = "sqlx-postgres")]
+impl<T> From<sqlx::postgres::types::PgRange<T>> for PgRange<T> {
+    fn from(range: sqlx::postgres::types::PgRange<T>) -> Self {
+        Self {
+            start: range.start,
+            end: range.end,
+        }
+    }
+}
+
+#[cfg(feature = "sqlx-postgres")]
+impl<T> From<PgRange<T>> for sqlx::postgres::types::PgRange<T> {
+    fn from(range: PgRange<T>) -> Self {
+        Self {
+            start: range.start,
+            end: range.end,
+        }
+    }
+}
+
+impl<T> From<PgRange<T>> for Value
+where
+    T: PgRangeElement,
+{
+    fn from(range: PgRange<T>) -> Self {
+        Value::String(Some(Box::new(range.to_string())))
+    }
+}
+
+impl<T> Nullable for PgRange<T>
+where
+    T: PgRangeElement,
+{
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+impl<T> ValueType for PgRange<T>
+where
+    T: PgRangeElement,
+{
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::String(Some(s)) => Self::parse(&s).ok_or(ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        format!("PgRange<{}>", T::range_type())
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Custom(SeaRc::new(Alias::new(T::range_type())))
+    }
+}
+
+/// Whether a custom column type is a Postgres range type, to cast a [PgRange] bound as text to
+pub(crate) fn is_range_type(name: &str) -> bool {
+    name.to_ascii_lowercase().ends_with("range")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn pg_range_value() {
+        let range = PgRange::from(1..10);
+        let value: Value = range.clone().into();
+        assert_eq!(value, Value::from(r#"["1","10")"#));
+        assert_eq!(<PgRange<i32> as ValueType>::try_from(value).unwrap(), range);
+
+        let range: PgRange<i64> = (Bound::Unbounded, Bound::Included(5)).into();
+        assert_eq!(range.to_string(), r#"(,"5"]"#);
+        assert_eq!(PgRange::<i64>::parse("(,5]"), Some(range));
+        assert_eq!(
+            PgRange::<i64>::parse("empty"),
+            Some((Bound::Unbounded, Bound::Unbounded).into())
+        );
+        assert_eq!(PgRange::<i64>::parse("[a,b)"), None);
+        assert!(<PgRange<i32> as ValueType>::try_from(Value::Int(Some(1))).is_err());
+        assert_eq!(
+            crate::ColumnType::from(<PgRange<i32> as ValueType>::column_type()),
+            crate::ColumnType::Custom("int4range".to_owned())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-chrono")]
+    fn pg_range_chrono_value() {
+        use chrono::{NaiveDate, NaiveDateTime};
+
+        let start = NaiveDate::from_ymd_opt(2022, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2022, 12, 31).unwrap();
+        let range =
+            PgRange::from(start.and_hms_opt(0, 0, 0).unwrap()..end.and_hms_opt(12, 30, 0).unwrap());
+        assert_eq!(
+            range.to_string(),
+            r#"["2022-01-01 00:00:00","2022-12-31 12:30:00")"#
+        );
+        assert_eq!(
+            <PgRange<NaiveDateTime> as ValueType>::try_from(range.clone().into()).unwrap(),
+            range
+        );
+        assert_eq!(
+            <PgRange<NaiveDate> as ValueType>::try_from(PgRange::from(start..=end).into()).unwrap(),
+            PgRange::from(start..=end)
+        );
+    }
+}
@@ -9,6 +9,10 @@ pub enum DbErr {
     Query(String),
     /// The record was not found in the database
     RecordNotFound(String),
+    /// The record was not inserted, e.g. skipped by `ON CONFLICT DO NOTHING`
+    RecordNotInserted(String),
+    /// The record was not updated, e.g. its version was changed by another writer
+    RecordNotUpdated(String),
     /// A custom error
     Custom(String),
     /// Error occurred while parsing value as target type
@@ -17,6 +21,90 @@ pub enum DbErr {
     Json(String),
     /// A migration error
     Migration(String),
+    /// An error reported by the database for a statement, e.g. a violated constraint.
+    /// Before 0.10, these were returned as [DbErr::Exec] or [DbErr::Query]
+    Database(DatabaseErr),
+}
+
+/// An error reported by the database, with the details provided by the driver
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DatabaseErr {
+    pub(crate) message: String,
+    pub(crate) sql_state: Option<String>,
+    pub(crate) sql_err: Option<SqlErr>,
+}
+
+/// A common class of errors reported by the database.
+/// Each carries the name of the violated constraint, or of the column for
+/// [SqlErr::NotNullViolation], if the driver provides it. Only Postgres does.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SqlErr {
+    /// A `UNIQUE` or `PRIMARY KEY` constraint was violated, e.g. by inserting a duplicate key
+    UniqueViolation(Option<String>),
+    /// A `FOREIGN KEY` constraint was violated
+    ForeignKeyViolation(Option<String>),
+    /// A `NULL` value was stored in a `NOT NULL` column
+    NotNullViolation(Option<String>),
+    /// A `CHECK` constraint was violated
+    CheckViolation(Option<String>),
+}
+
+impl DatabaseErr {
+    /// Create an error reported by the database, e.g. to be returned by a [crate::MockDatabase]
+    pub fn new(message: &str) -> Self {
+        Self {
+            message: message.to_owned(),
+            sql_state: None,
+            sql_err: None,
+        }
+    }
+
+    /// Set the SQLSTATE code of the error
+    pub fn sql_state(mut self, sql_state: &str) -> Self {
+        self.sql_state = Some(sql_state.to_owned());
+        self
+    }
+
+    /// Set the class of the error
+    pub fn sql_err(mut self, sql_err: SqlErr) -> Self {
+        self.sql_err = Some(sql_err);
+        self
+    }
+
+    /// Get the error message
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+
+    /// Get the SQLSTATE code of the error, reported by Postgres and MySQL but not by SQLite
+    pub fn get_sql_state(&self) -> Option<&str> {
+        self.sql_state.as_deref()
+    }
+
+    /// Get the class of the error, if it is one of the common cases of [SqlErr]
+    pub fn get_sql_err(&self) -> Option<&SqlErr> {
+        self.sql_err.as_ref()
+    }
+}
+
+impl DbErr {
+    /// Get the SQLSTATE code of an error reported by the database, e.g. `23505` for
+    /// a unique violation in Postgres. SQLite does not report SQLSTATE codes.
+    pub fn sql_state(&self) -> Option<&str> {
+        match self {
+            Self::Database(err) => err.get_sql_state(),
+            _ => None,
+        }
+    }
+
+    /// Classify an error reported by the database, e.g. to tell a duplicate key apart
+    /// from other errors of an insert
+    pub fn sql_err(&self) -> Option<&SqlErr> {
+        match self {
+            Self::Database(err) => err.get_sql_err(),
+            _ => None,
+        }
+    }
 }
 
 impl std::error::Error for DbErr {}
@@ -28,10 +116,13 @@ impl std::fmt::Display for DbErr {
             Self::Exec(s) => write!(f, "Execution Error: {}", s),
             Self::Query(s) => write!(f, "Query Error: {}", s),
             Self::RecordNotFound(s) => write!(f, "RecordNotFound Error: {}", s),
+            Self::RecordNotInserted(s) => write!(f, "RecordNotInserted Error: {}", s),
+            Self::RecordNotUpdated(s) => write!(f, "RecordNotUpdated Error: {}", s),
             Self::Custom(s) => write!(f, "Custom Error: {}", s),
             Self::Type(s) => write!(f, "Type Error: {}", s),
             Self::Json(s) => write!(f, "Json Error: {}", s),
             Self::Migration(s) => write!(f, "Migration Error: {}", s),
+            Self::Database(err) => write!(f, "Database Error: {}", err.message),
         }
     }
 }
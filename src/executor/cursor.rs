@@ -4,7 +4,7 @@ use crate::{
 };
 use sea_query::{
     Condition, DynIden, Expr, IntoValueTuple, Order, OrderedStatement, SeaRc, SelectStatement,
-    SimpleExpr, Value, ValueTuple,
+    SimpleExpr, Value,
 };
 use std::marker::PhantomData;
 
@@ -63,22 +63,39 @@ where
         self
     }
 
+    /// Build a keyset condition on the order by columns, comparing them lexicographically
+    /// so that later columns only break ties of the preceding ones, e.g.
+    /// `(c1 > v1) OR (c1 = v1 AND c2 > v2)`
     fn apply_filter<V, F>(&self, values: V, f: F) -> Condition
     where
         V: IntoValueTuple,
         F: Fn(&DynIden, Value) -> SimpleExpr,
     {
-        match (&self.order_columns, values.into_value_tuple()) {
-            (Identity::Unary(c1), ValueTuple::One(v1)) => Condition::all().add(f(c1, v1)),
-            (Identity::Binary(c1, c2), ValueTuple::Two(v1, v2)) => {
-                Condition::all().add(f(c1, v1)).add(f(c2, v2))
+        let columns = match &self.order_columns {
+            Identity::Unary(c1) => vec![c1],
+            Identity::Binary(c1, c2) => vec![c1, c2],
+            Identity::Ternary(c1, c2, c3) => vec![c1, c2, c3],
+        };
+        let values: Vec<Value> = values.into_value_tuple().into_iter().collect();
+        if columns.len() != values.len() {
+            panic!("column arity mismatch");
+        }
+        if columns.len() == 1 {
+            return Condition::all().add(f(columns[0], values[0].clone()));
+        }
+        let mut condition = Condition::any();
+        for (i, (col, value)) in columns.iter().zip(values.iter()).enumerate() {
+            let mut cond = Condition::all();
+            for (prev_col, prev_value) in columns.iter().zip(values.iter()).take(i) {
+                cond = cond.add(
+                    Expr::tbl(SeaRc::clone(&self.table), SeaRc::clone(prev_col))
+                        .eq(prev_value.clone()),
+                );
             }
-            (Identity::Ternary(c1, c2, c3), ValueTuple::Three(v1, v2, v3)) => Condition::all()
-                .add(f(c1, v1))
-                .add(f(c2, v2))
-                .add(f(c3, v3)),
-            _ => panic!("column arity mismatch"),
+            condition = condition.add(cond.add(f(col, value.clone())));
         }
+        // Wrapped so that `cond_where` combines it with other conditions using `AND`
+        Condition::all().add(condition)
     }
 
     /// Limit result set to only first N rows in ascending order of the order by column
@@ -413,19 +430,21 @@ mod tests {
                 [
                     r#"SELECT "cake_filling"."cake_id", "cake_filling"."filling_id""#,
                     r#"FROM "cake_filling""#,
-                    r#"WHERE "cake_filling"."cake_id" > $1"#,
-                    r#"AND "cake_filling"."filling_id" > $2"#,
-                    r#"AND ("cake_filling"."cake_id" < $3"#,
-                    r#"AND "cake_filling"."filling_id" < $4)"#,
+                    r#"WHERE ("cake_filling"."cake_id" > $1"#,
+                    r#"OR ("cake_filling"."cake_id" = $2 AND "cake_filling"."filling_id" > $3))"#,
+                    r#"AND ("cake_filling"."cake_id" < $4"#,
+                    r#"OR ("cake_filling"."cake_id" = $5 AND "cake_filling"."filling_id" < $6))"#,
                     r#"ORDER BY "cake_filling"."cake_id" ASC, "cake_filling"."filling_id" ASC"#,
-                    r#"LIMIT $5"#,
+                    r#"LIMIT $7"#,
                 ]
                 .join(" ")
                 .as_str(),
                 vec![
+                    0_i32.into(),
                     0_i32.into(),
                     1_i32.into(),
                     10_i32.into(),
+                    10_i32.into(),
                     11_i32.into(),
                     3_u64.into()
                 ]
@@ -434,4 +453,61 @@ mod tests {
 
         Ok(())
     }
+
+    #[smol_potat::test]
+    async fn composite_keys_last_2_before() -> Result<(), DbErr> {
+        use cake_filling::*;
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                Model {
+                    cake_id: 2,
+                    filling_id: 1,
+                },
+                Model {
+                    cake_id: 1,
+                    filling_id: 3,
+                },
+            ]])
+            .into_connection();
+
+        assert_eq!(
+            Entity::find()
+                .cursor_by((Column::CakeId, Column::FillingId))
+                .before((2, 2))
+                .last(2)
+                .all(&db)
+                .await?,
+            vec![
+                Model {
+                    cake_id: 1,
+                    filling_id: 3,
+                },
+                Model {
+                    cake_id: 2,
+                    filling_id: 1,
+                },
+            ]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::many(vec![Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "cake_filling"."cake_id", "cake_filling"."filling_id""#,
+                    r#"FROM "cake_filling""#,
+                    r#"WHERE "cake_filling"."cake_id" < $1"#,
+                    r#"OR ("cake_filling"."cake_id" = $2 AND "cake_filling"."filling_id" < $3)"#,
+                    r#"ORDER BY "cake_filling"."cake_id" DESC, "cake_filling"."filling_id" DESC"#,
+                    r#"LIMIT $4"#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec![2_i32.into(), 2_i32.into(), 2_i32.into(), 2_u64.into()]
+            ),])]
+        );
+
+        Ok(())
+    }
 }
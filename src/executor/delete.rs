@@ -1,5 +1,10 @@
 use crate::{
-    error::*, ActiveModelTrait, ConnectionTrait, DeleteMany, DeleteOne, EntityTrait, Statement,
+    error::*,
+    executor::insert::{query_returning, returning_all_columns},
+    query::build_delete,
+    ActiveModelTrait, ConnectionTrait, DeleteMany, DeleteOne, EntityTrait, FromQueryResult,
+    QueryTrait, SelectGetableTuple, SelectModel, SelectorRaw, SelectorTrait, Statement,
+    TryGetableMany,
 };
 use sea_query::DeleteStatement;
 use std::future::Future;
@@ -22,12 +27,14 @@ where
     A: ActiveModelTrait,
 {
     /// Execute a DELETE operation on one ActiveModel
+    ///
+    /// On a soft deletable entity, the row is marked as deleted instead.
     pub fn exec<C>(self, db: &'a C) -> impl Future<Output = Result<DeleteResult, DbErr>> + '_
     where
         C: ConnectionTrait,
     {
         // so that self is dropped before entering await
-        exec_delete_only(self.query, db)
+        exec_delete(self.build(db.get_database_backend()), db)
     }
 }
 
@@ -36,12 +43,76 @@ where
     E: EntityTrait,
 {
     /// Execute a DELETE operation on many ActiveModels
+    ///
+    /// On a soft deletable entity, the rows are marked as deleted instead.
     pub fn exec<C>(self, db: &'a C) -> impl Future<Output = Result<DeleteResult, DbErr>> + '_
     where
         C: ConnectionTrait,
     {
         // so that self is dropped before entering await
-        exec_delete_only(self.query, db)
+        exec_delete(self.build(db.get_database_backend()), db)
+    }
+
+    /// Execute a DELETE operation on many ActiveModels and return the deleted models.
+    ///
+    /// This requires `RETURNING` syntax, which is only used on Postgres. Other backends
+    /// return a [DbErr::Exec], as the deleted rows cannot be selected after the fact.
+    pub fn exec_with_returning<C>(
+        self,
+        db: &'a C,
+    ) -> impl Future<Output = Result<Vec<E::Model>, DbErr>> + 'a
+    where
+        C: ConnectionTrait,
+    {
+        // so that self is dropped before entering await
+        exec_delete_with_returning(self, db)
+    }
+
+    /// Execute a DELETE operation and read the columns set by [DeleteMany::returning], or all
+    /// columns if there are none, of every deleted row into a partial model.
+    ///
+    /// This requires `RETURNING` syntax, which is only used on Postgres. Other backends
+    /// return a [DbErr::Exec].
+    pub async fn exec_with_returning_as<M, C>(self, db: &C) -> Result<Vec<M>, DbErr>
+    where
+        M: FromQueryResult,
+        C: ConnectionTrait,
+    {
+        self.exec_returning::<SelectModel<M>, _>(db).await
+    }
+
+    /// Execute a DELETE operation and read the columns set by [DeleteMany::returning], or all
+    /// columns if there are none, of every deleted row into a tuple, by column position.
+    ///
+    /// This requires `RETURNING` syntax, which is only used on Postgres. Other backends
+    /// return a [DbErr::Exec].
+    pub async fn exec_with_returning_tuple<T, C>(self, db: &C) -> Result<Vec<T>, DbErr>
+    where
+        T: TryGetableMany,
+        C: ConnectionTrait,
+    {
+        self.exec_returning::<SelectGetableTuple<T>, _>(db).await
+    }
+
+    async fn exec_returning<S, C>(self, db: &C) -> Result<Vec<S::Item>, DbErr>
+    where
+        S: SelectorTrait,
+        C: ConnectionTrait,
+    {
+        let returning = self
+            .returning
+            .clone()
+            .unwrap_or_else(returning_all_columns::<E>);
+        let mut query = self.query;
+        query.returning(returning.clone());
+        let stmt = build_delete::<E>(
+            db.get_database_backend(),
+            &query,
+            &self.conditions,
+            Some(&returning),
+            self.hard_delete,
+        );
+        query_returning::<S, _>(stmt, db).await
     }
 }
 
@@ -61,11 +132,32 @@ impl Deleter {
     }
 }
 
-async fn exec_delete_only<C>(query: DeleteStatement, db: &C) -> Result<DeleteResult, DbErr>
+async fn exec_delete_with_returning<E, C>(
+    delete: DeleteMany<E>,
+    db: &C,
+) -> Result<Vec<E::Model>, DbErr>
 where
+    E: EntityTrait,
     C: ConnectionTrait,
 {
-    Deleter::new(query).exec(db).await
+    if !db.support_returning() {
+        return Err(DbErr::Exec(
+            "`RETURNING` is not supported by the database backend".to_owned(),
+        ));
+    }
+    let returning = returning_all_columns::<E>();
+    let mut query = delete.query;
+    query.returning(returning.clone());
+    let stmt = build_delete::<E>(
+        db.get_database_backend(),
+        &query,
+        &delete.conditions,
+        Some(&returning),
+        delete.hard_delete,
+    );
+    SelectorRaw::<SelectModel<E::Model>>::from_statement(stmt)
+        .all(db)
+        .await
 }
 
 async fn exec_delete<C>(statement: Statement, db: &C) -> Result<DeleteResult, DbErr>
@@ -77,3 +169,102 @@ where
         rows_affected: result.rows_affected(),
     })
 }
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{
+        entity::prelude::*, tests_cfg::*, DbBackend, MockDatabase, QueryFilter, Transaction,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[smol_potat::test]
+    async fn delete_many_exec_with_returning() -> Result<(), DbErr> {
+        let models = vec![
+            cake::Model {
+                id: 2,
+                name: "New York Cheese".to_owned(),
+            },
+            cake::Model {
+                id: 3,
+                name: "Chocolate Forest".to_owned(),
+            },
+        ];
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![models.clone()])
+            .into_connection();
+
+        assert_eq!(
+            cake::Entity::delete_many()
+                .filter(cake::Column::Id.gt(1))
+                .exec_with_returning(&db)
+                .await?,
+            models
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"DELETE FROM "cake" WHERE "cake"."id" > $1 RETURNING "id", "name""#,
+                vec![1i32.into()]
+            )]
+        );
+
+        let db = MockDatabase::new(DbBackend::MySql).into_connection();
+
+        assert_eq!(
+            cake::Entity::delete_many()
+                .filter(cake::Column::Id.gt(1))
+                .exec_with_returning(&db)
+                .await,
+            Err(DbErr::Exec(
+                "`RETURNING` is not supported by the database backend".to_owned()
+            ))
+        );
+        assert!(db.into_transaction_log().is_empty());
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    #[cfg(feature = "macros")]
+    async fn delete_many_exec_with_returning_as() -> Result<(), DbErr> {
+        use crate as sea_orm;
+        use crate::{FromQueryResult, Value};
+
+        #[derive(Debug, PartialEq, FromQueryResult)]
+        struct CakeName {
+            name: String,
+        }
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![maplit::btreemap! {
+                "name" => Into::<Value>::into("New York Cheese"),
+            }]])
+            .into_connection();
+
+        assert_eq!(
+            cake::Entity::delete_many()
+                .filter(cake::Column::Id.gt(1))
+                .returning([cake::Column::Name])
+                .exec_with_returning_as::<CakeName, _>(&db)
+                .await?,
+            [CakeName {
+                name: "New York Cheese".to_owned()
+            }]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"DELETE FROM "cake" WHERE "cake"."id" > $1 RETURNING "name""#,
+                vec![1i32.into()]
+            )]
+        );
+
+        Ok(())
+    }
+}
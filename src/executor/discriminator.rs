@@ -0,0 +1,251 @@
+use crate::{ConnectionTrait, DbErr, FromQueryResult, QueryResult, Statement, TryGetable};
+use std::fmt::Debug;
+
+type Variant<T> = Box<dyn Fn(&QueryResult, &str) -> Result<T, DbErr> + Send + Sync>;
+
+/// Turn rows of different shapes, e.g. from a `UNION ALL` over several tables,
+/// into variants of `T`. The value of a discriminator column decides which
+/// [FromQueryResult] implementation reads each row.
+///
+/// ```
+/// # use sea_orm::{error::*, tests_cfg::*, *};
+/// #
+/// # #[smol_potat::main]
+/// # #[cfg(feature = "mock")]
+/// # pub async fn main() -> Result<(), DbErr> {
+/// #
+/// # let db = MockDatabase::new(DbBackend::Postgres)
+/// #     .append_query_results(vec![vec![
+/// #         maplit::btreemap! {
+/// #             "kind" => Into::<Value>::into("cake"),
+/// #             "id" => Into::<Value>::into(1),
+/// #             "name" => Into::<Value>::into("Chocolate Forest"),
+/// #         },
+/// #         maplit::btreemap! {
+/// #             "kind" => Into::<Value>::into("fruit"),
+/// #             "id" => Into::<Value>::into(2),
+/// #             "name" => Into::<Value>::into("Apple"),
+/// #             "cake_id" => Into::<Value>::into(Some(1)),
+/// #         },
+/// #     ]])
+/// #     .into_connection();
+/// #
+/// use sea_orm::{tests_cfg::{cake, fruit}, Discriminator};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Item {
+///     Cake(cake::Model),
+///     Fruit(fruit::Model),
+/// }
+///
+/// let items: Vec<Item> = Discriminator::new("kind")
+///     .variant("cake".to_owned(), Item::Cake)
+///     .variant("fruit".to_owned(), Item::Fruit)
+///     .all(
+///         &db,
+///         Statement::from_string(
+///             DbBackend::Postgres,
+///             [
+///                 r#"SELECT 'cake' AS "kind", "id", "name", NULL AS "cake_id" FROM "cake""#,
+///                 r#"UNION ALL"#,
+///                 r#"SELECT 'fruit' AS "kind", "id", "name", "cake_id" FROM "fruit""#,
+///             ]
+///             .join(" "),
+///         ),
+///     )
+///     .await?;
+///
+/// assert_eq!(
+///     items,
+///     [
+///         Item::Cake(cake::Model {
+///             id: 1,
+///             name: "Chocolate Forest".to_owned(),
+///         }),
+///         Item::Fruit(fruit::Model {
+///             id: 2,
+///             name: "Apple".to_owned(),
+///             cake_id: Some(1),
+///         }),
+///     ]
+/// );
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub struct Discriminator<D, T> {
+    column: String,
+    variants: Vec<(D, Variant<T>)>,
+}
+
+impl<D, T> Debug for Discriminator<D, T>
+where
+    D: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Discriminator")
+            .field("column", &self.column)
+            .field(
+                "variants",
+                &self.variants.iter().map(|(v, _)| v).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl<D, T> Discriminator<D, T>
+where
+    D: TryGetable + PartialEq + Debug,
+{
+    /// Dispatch rows on the value of `column`
+    pub fn new(column: &str) -> Self {
+        Self {
+            column: column.to_owned(),
+            variants: Vec::new(),
+        }
+    }
+
+    /// Read the rows whose discriminator equals `value` as `M`, then wrap it with `variant`
+    pub fn variant<M, F>(mut self, value: D, variant: F) -> Self
+    where
+        M: FromQueryResult,
+        F: Fn(M) -> T + Send + Sync + 'static,
+    {
+        self.variants.push((
+            value,
+            Box::new(move |res, pre| M::from_query_result(res, pre).map(&variant)),
+        ));
+        self
+    }
+
+    /// Instantiate a `T` from a [QueryResult], by the variant matching its discriminator
+    pub fn from_query_result(&self, res: &QueryResult, pre: &str) -> Result<T, DbErr> {
+        let value: D = res.try_get(pre, &self.column)?;
+        match self.variants.iter().find(|(v, _)| v == &value) {
+            Some((_, variant)) => variant(res, pre),
+            None => Err(DbErr::Type(format!(
+                "unexpected value for discriminator column `{}`: {:?}",
+                self.column, value
+            ))),
+        }
+    }
+
+    /// Execute the [Statement] and instantiate a `T` from every row
+    pub async fn all<C>(&self, db: &C, stmt: Statement) -> Result<Vec<T>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        db.query_all(stmt)
+            .await?
+            .iter()
+            .map(|res| self.from_query_result(res, ""))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use super::Discriminator;
+    use crate::{
+        tests_cfg::{cake, fruit},
+        DbBackend, DbErr, MockDatabase, Statement, Transaction,
+    };
+    use pretty_assertions::assert_eq;
+    use sea_query::Value;
+
+    #[derive(Debug, PartialEq)]
+    enum Item {
+        Cake(cake::Model),
+        Fruit(fruit::Model),
+    }
+
+    fn item_discriminator() -> Discriminator<String, Item> {
+        Discriminator::new("kind")
+            .variant("cake".to_owned(), Item::Cake)
+            .variant("fruit".to_owned(), Item::Fruit)
+    }
+
+    fn union_all() -> Statement {
+        Statement::from_string(
+            DbBackend::Postgres,
+            [
+                r#"SELECT 'cake' AS "kind", "id", "name", NULL AS "cake_id" FROM "cake""#,
+                r#"UNION ALL"#,
+                r#"SELECT 'fruit' AS "kind", "id", "name", "cake_id" FROM "fruit""#,
+            ]
+            .join(" "),
+        )
+    }
+
+    #[smol_potat::test]
+    async fn discriminator() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                maplit::btreemap! {
+                    "kind" => Into::<Value>::into("fruit"),
+                    "id" => Into::<Value>::into(1),
+                    "name" => Into::<Value>::into("Apple"),
+                    "cake_id" => Into::<Value>::into(Option::<i32>::None),
+                },
+                maplit::btreemap! {
+                    "kind" => Into::<Value>::into("cake"),
+                    "id" => Into::<Value>::into(1),
+                    "name" => Into::<Value>::into("Chocolate Forest"),
+                    "cake_id" => Into::<Value>::into(Option::<i32>::None),
+                },
+                maplit::btreemap! {
+                    "kind" => Into::<Value>::into("fruit"),
+                    "id" => Into::<Value>::into(2),
+                    "name" => Into::<Value>::into("Orange"),
+                    "cake_id" => Into::<Value>::into(Some(1)),
+                },
+            ]])
+            .into_connection();
+
+        assert_eq!(
+            item_discriminator().all(&db, union_all()).await?,
+            [
+                Item::Fruit(fruit::Model {
+                    id: 1,
+                    name: "Apple".to_owned(),
+                    cake_id: None,
+                }),
+                Item::Cake(cake::Model {
+                    id: 1,
+                    name: "Chocolate Forest".to_owned(),
+                }),
+                Item::Fruit(fruit::Model {
+                    id: 2,
+                    name: "Orange".to_owned(),
+                    cake_id: Some(1),
+                }),
+            ]
+        );
+
+        assert_eq!(db.into_transaction_log(), [Transaction::one(union_all())]);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn discriminator_unexpected_value() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![maplit::btreemap! {
+                "kind" => Into::<Value>::into("filling"),
+                "id" => Into::<Value>::into(1),
+                "name" => Into::<Value>::into("Lemon"),
+                "cake_id" => Into::<Value>::into(Option::<i32>::None),
+            }]])
+            .into_connection();
+
+        assert_eq!(
+            item_discriminator().all(&db, union_all()).await,
+            Err(DbErr::Type(
+                r#"unexpected value for discriminator column `kind`: "filling""#.to_owned()
+            ))
+        );
+
+        Ok(())
+    }
+}
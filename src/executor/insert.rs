@@ -1,9 +1,12 @@
 use crate::{
-    error::*, ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, Insert, IntoActiveModel,
-    Iterable, PrimaryKeyTrait, SelectModel, SelectorRaw, Statement, TryFromU64,
+    error::*, ActiveModelTrait, ColumnTrait, ConnectionTrait, DbBackend, EntityTrait,
+    FromQueryResult, Insert, IntoActiveModel, Iterable, PrimaryKeyToColumn, PrimaryKeyTrait,
+    SelectGetableTuple, SelectModel, SelectorRaw, SelectorTrait, Statement, TransactionTrait,
+    TryFromU64, TryGetableMany,
 };
 use sea_query::{
-    Alias, Expr, FromValueTuple, Iden, InsertStatement, IntoColumnRef, Query, ValueTuple,
+    Alias, Expr, FromValueTuple, Iden, InsertStatement, IntoColumnRef, Query, ReturningClause,
+    ValueTuple,
 };
 use std::{future::Future, marker::PhantomData};
 
@@ -33,6 +36,9 @@ where
     A: ActiveModelTrait,
 {
     /// Execute an insert operation
+    ///
+    /// The rows are inserted by a single statement, use [Insert::exec_without_returning] to
+    /// insert more rows than the bind parameter limit of the backend allows.
     #[allow(unused_mut)]
     pub fn exec<'a, C>(self, db: &'a C) -> impl Future<Output = Result<InsertResult<A>, DbErr>> + '_
     where
@@ -51,6 +57,9 @@ where
     }
 
     /// Execute an insert operation and return the inserted model (use `RETURNING` syntax if database supported)
+    ///
+    /// On backends without `RETURNING` support, i.e. MySQL and SQLite, the model is
+    /// selected again by its primary key or `last_insert_id` after the insert.
     pub fn exec_with_returning<'a, C>(
         self,
         db: &'a C,
@@ -62,6 +71,165 @@ where
     {
         Inserter::<A>::new(self.primary_key, self.query).exec_with_returning(db)
     }
+
+    /// Execute an insert operation without returning the inserted rows, and get the number of rows affected.
+    ///
+    /// Inserting many rows at once can exceed the maximum number of bind parameters of the backend,
+    /// i.e. 65535 on Postgres and MySQL and 999 on SQLite. The rows are therefore split into
+    /// multiple `INSERT` statements executed within one transaction, see [Insert::chunk_size]
+    /// to override the number of rows per statement.
+    pub async fn exec_without_returning<C>(self, db: &C) -> Result<u64, DbErr>
+    where
+        C: ConnectionTrait + TransactionTrait,
+    {
+        let db_backend = db.get_database_backend();
+        let rows_per_chunk = self.rows_per_chunk(db_backend);
+        let mut chunks = self.into_chunks(rows_per_chunk);
+        if chunks.len() == 1 {
+            let res = db.execute(db_backend.build(&chunks.remove(0))).await?;
+            return Ok(res.rows_affected());
+        }
+        let txn = db.begin().await?;
+        let mut rows_affected = 0;
+        for query in chunks {
+            rows_affected += txn.execute(db_backend.build(&query)).await?.rows_affected();
+        }
+        txn.commit().await?;
+        Ok(rows_affected)
+    }
+
+    /// Execute an insert operation and return all inserted models in one round trip.
+    ///
+    /// This requires `RETURNING` syntax, which is only used on Postgres. Other backends
+    /// return a [DbErr::Exec], as the inserted rows cannot be re-selected reliably
+    /// by `last_insert_id` when many rows are inserted at once.
+    pub fn exec_with_returning_many<'a, C>(
+        self,
+        db: &'a C,
+    ) -> impl Future<Output = Result<Vec<<A::Entity as EntityTrait>::Model>, DbErr>> + 'a
+    where
+        C: ConnectionTrait,
+        A: 'a,
+    {
+        Inserter::<A>::new(self.primary_key, self.query).exec_with_returning_many(db)
+    }
+
+    /// Execute an insert operation and read the columns set by [Insert::returning], or all
+    /// columns if there are none, of every inserted row into a partial model.
+    ///
+    /// This requires `RETURNING` syntax, which is only used on Postgres. Other backends
+    /// return a [DbErr::Exec].
+    pub async fn exec_with_returning_as<M, C>(self, db: &C) -> Result<Vec<M>, DbErr>
+    where
+        M: FromQueryResult,
+        C: ConnectionTrait,
+    {
+        self.exec_returning::<SelectModel<M>, _>(db).await
+    }
+
+    /// Execute an insert operation and read the columns set by [Insert::returning], or all
+    /// columns if there are none, of every inserted row into a tuple, by column position.
+    ///
+    /// This requires `RETURNING` syntax, which is only used on Postgres. Other backends
+    /// return a [DbErr::Exec].
+    pub async fn exec_with_returning_tuple<T, C>(self, db: &C) -> Result<Vec<T>, DbErr>
+    where
+        T: TryGetableMany,
+        C: ConnectionTrait,
+    {
+        self.exec_returning::<SelectGetableTuple<T>, _>(db).await
+    }
+
+    async fn exec_returning<S, C>(self, db: &C) -> Result<Vec<S::Item>, DbErr>
+    where
+        S: SelectorTrait,
+        C: ConnectionTrait,
+    {
+        let mut query = self.query;
+        query.returning(
+            self.returning
+                .unwrap_or_else(returning_all_columns::<A::Entity>),
+        );
+        query_returning::<S, _>(db.get_database_backend().build(&query), db).await
+    }
+
+    /// Execute an insert operation and return the primary key of every inserted row, in the
+    /// order of the inserted models.
+    ///
+    /// On Postgres, the keys are returned by `RETURNING` syntax. On MySQL and SQLite, the
+    /// keys of an auto increment primary key are reconstructed from `last_insert_id` and the
+    /// number of inserted rows, which assumes they are consecutive. It holds on SQLite, but
+    /// on MySQL it requires `auto_increment_increment = 1` and an `innodb_autoinc_lock_mode`
+    /// of 0 or 1 if other rows can be inserted concurrently. Other primary keys, or inserts
+    /// with an `ON CONFLICT` clause, return a [DbErr::Exec] on these backends.
+    pub async fn exec_with_returning_keys<C>(
+        self,
+        db: &C,
+    ) -> Result<Vec<<<A::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        type PrimaryKey<A> = <<A as ActiveModelTrait>::Entity as EntityTrait>::PrimaryKey;
+        type ValueTypeOf<A> = <PrimaryKey<A> as PrimaryKeyTrait>::ValueType;
+
+        if self.rows.is_empty() && !self.from_select {
+            return Ok(Vec::new());
+        }
+        let db_backend = db.get_database_backend();
+        let mut query = self.query;
+        if db.support_returning() {
+            query.returning(
+                Query::returning().columns(PrimaryKey::<A>::iter().map(|c| c.into_column_ref())),
+            );
+            let cols = PrimaryKey::<A>::iter()
+                .map(|col| col.to_string())
+                .collect::<Vec<_>>();
+            return db
+                .query_all(db_backend.build(&query))
+                .await?
+                .iter()
+                .map(|res| res.try_get_many("", cols.as_ref()))
+                .collect();
+        }
+
+        if self.from_select {
+            return Err(DbErr::Exec(
+                "The inserted keys of an INSERT ... SELECT can only be returned with RETURNING"
+                    .to_owned(),
+            ));
+        }
+        let primary_key_set = <A::Entity as EntityTrait>::Column::iter()
+            .zip(self.columns.iter())
+            .any(|(col, has_val)| {
+                *has_val
+                    && PrimaryKey::<A>::iter()
+                        .any(|key| key.into_column().to_string() == col.to_string())
+            });
+        if !PrimaryKey::<A>::auto_increment() || primary_key_set || self.on_conflict.is_some() {
+            return Err(DbErr::Exec(
+                "The inserted keys can only be reconstructed for auto increment primary keys"
+                    .to_owned(),
+            ));
+        }
+        let num_rows = self.rows.len() as u64;
+        let res = db.execute(db_backend.build(&query)).await?;
+        if res.rows_affected() != num_rows {
+            return Err(DbErr::Exec(format!(
+                "Expected {} inserted rows, but {} were affected",
+                num_rows,
+                res.rows_affected()
+            )));
+        }
+        // MySQL returns the id of the first inserted row, SQLite the id of the last one
+        let first_id = match db_backend {
+            DbBackend::MySql | DbBackend::Postgres => Some(res.last_insert_id()),
+            DbBackend::Sqlite => (res.last_insert_id() + 1).checked_sub(num_rows),
+        }
+        .ok_or_else(|| DbErr::Exec("Fail to unpack last_insert_id".to_owned()))?;
+        (first_id..first_id + num_rows)
+            .map(ValueTypeOf::<A>::try_from_u64)
+            .collect()
+    }
 }
 
 impl<A> Inserter<A>
@@ -99,6 +267,18 @@ where
     {
         exec_insert_with_returning::<A, _>(self.primary_key, self.query, db)
     }
+
+    /// Execute an insert operation and return all inserted models (require `RETURNING` syntax support)
+    pub fn exec_with_returning_many<'a, C>(
+        self,
+        db: &'a C,
+    ) -> impl Future<Output = Result<Vec<<A::Entity as EntityTrait>::Model>, DbErr>> + 'a
+    where
+        C: ConnectionTrait,
+        A: 'a,
+    {
+        exec_insert_with_returning_many::<A, _>(self.query, db)
+    }
 }
 
 #[allow(unused_variables, unreachable_code)]
@@ -118,7 +298,14 @@ where
             let cols = PrimaryKey::<A>::iter()
                 .map(|col| col.to_string())
                 .collect::<Vec<_>>();
-            let res = db.query_one(statement).await?.unwrap();
+            let res = match db.query_one(statement).await? {
+                Some(res) => res,
+                None => {
+                    return Err(DbErr::RecordNotInserted(
+                        "None of the records are inserted".to_owned(),
+                    ))
+                }
+            };
             res.try_get_many("", cols.as_ref()).ok()
         }
         false => {
@@ -149,17 +336,7 @@ where
     let db_backend = db.get_database_backend();
     let found = match db.support_returning() {
         true => {
-            let returning =
-                Query::returning().exprs(<A::Entity as EntityTrait>::Column::iter().map(|c| {
-                    let col = Expr::col(c);
-                    let col_def = ColumnTrait::def(&c);
-                    let col_type = col_def.get_column_type();
-                    match col_type.get_enum_name() {
-                        Some(_) => col.as_enum(Alias::new("text")),
-                        None => col.into(),
-                    }
-                }));
-            insert_statement.returning(returning);
+            insert_statement.returning(returning_all_columns::<A::Entity>());
             SelectorRaw::<SelectModel<<A::Entity as EntityTrait>::Model>>::from_statement(
                 db_backend.build(&insert_statement),
             )
@@ -176,6 +353,652 @@ where
     };
     match found {
         Some(model) => Ok(model),
+        None if db.support_returning() => Err(DbErr::RecordNotInserted(
+            "None of the records are inserted".to_owned(),
+        )),
         None => Err(DbErr::Exec("Failed to find inserted item".to_owned())),
     }
 }
+
+async fn exec_insert_with_returning_many<A, C>(
+    mut insert_statement: InsertStatement,
+    db: &C,
+) -> Result<Vec<<A::Entity as EntityTrait>::Model>, DbErr>
+where
+    C: ConnectionTrait,
+    A: ActiveModelTrait,
+{
+    if !db.support_returning() {
+        return Err(DbErr::Exec(
+            "`RETURNING` is not supported by the database backend".to_owned(),
+        ));
+    }
+    let db_backend = db.get_database_backend();
+    insert_statement.returning(returning_all_columns::<A::Entity>());
+    SelectorRaw::<SelectModel<<A::Entity as EntityTrait>::Model>>::from_statement(
+        db_backend.build(&insert_statement),
+    )
+    .all(db)
+    .await
+}
+
+pub(crate) fn returning_all_columns<E>() -> ReturningClause
+where
+    E: EntityTrait,
+{
+    returning_columns(E::Column::iter())
+}
+
+/// A `RETURNING` clause of the given columns, with enum columns cast to text
+pub(crate) fn returning_columns<C, I>(cols: I) -> ReturningClause
+where
+    C: ColumnTrait,
+    I: IntoIterator<Item = C>,
+{
+    Query::returning().exprs(cols.into_iter().map(|c| {
+        let col = Expr::col(c);
+        let col_def = ColumnTrait::def(&c);
+        let col_type = col_def.get_column_type();
+        match col_type.get_enum_name() {
+            Some(_) => col.as_enum(Alias::new("text")),
+            None => col.into(),
+        }
+    }))
+}
+
+/// Execute a statement with a `RETURNING` clause and read every returned row with `S`
+pub(crate) async fn query_returning<S, C>(stmt: Statement, db: &C) -> Result<Vec<S::Item>, DbErr>
+where
+    S: SelectorTrait,
+    C: ConnectionTrait,
+{
+    if !db.support_returning() {
+        return Err(DbErr::Exec(
+            "`RETURNING` is not supported by the database backend".to_owned(),
+        ));
+    }
+    db.query_all(stmt)
+        .await?
+        .into_iter()
+        .map(S::from_raw_query_result)
+        .collect()
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{
+        entity::prelude::*, sea_query::OnConflict, tests_cfg::*, ActiveValue, DbBackend,
+        IntoActiveModel, MockDatabase, MockExecResult, Statement, Transaction,
+    };
+    use pretty_assertions::assert_eq;
+    use std::collections::BTreeMap;
+
+    #[smol_potat::test]
+    async fn insert_on_conflict_do_nothing() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![Vec::<cake::Model>::new()])
+            .into_connection();
+
+        let res = cake::Entity::insert(cake::ActiveModel {
+            id: ActiveValue::set(2),
+            name: ActiveValue::set("Orange".to_owned()),
+        })
+        .on_conflict(OnConflict::column(cake::Column::Id).do_nothing().to_owned())
+        .exec(&db)
+        .await;
+
+        assert_eq!(
+            res.err(),
+            Some(DbErr::RecordNotInserted(
+                "None of the records are inserted".to_owned()
+            ))
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO "cake" ("id", "name") VALUES ($1, $2) ON CONFLICT ("id") DO NOTHING RETURNING "id""#,
+                vec![2.into(), "Orange".into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_on_conflict_update() -> Result<(), DbErr> {
+        let apple = fruit::Model {
+            id: 1,
+            name: "Apple".to_owned(),
+            cake_id: Some(3),
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![apple.clone()], vec![apple.clone()]])
+            .into_connection();
+
+        let insert = || {
+            fruit::Entity::insert(apple.clone().into_active_model()).on_conflict(
+                OnConflict::column(fruit::Column::Id)
+                    .update_columns([fruit::Column::Name])
+                    .to_owned(),
+            )
+        };
+
+        assert_eq!(insert().exec(&db).await?.last_insert_id, 1);
+        assert_eq!(insert().exec_with_returning(&db).await?, apple);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    [
+                        r#"INSERT INTO "fruit" ("id", "name", "cake_id") VALUES ($1, $2, $3)"#,
+                        r#"ON CONFLICT ("id") DO UPDATE SET "name" = "excluded"."name""#,
+                        r#"RETURNING "id""#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    vec![1.into(), "Apple".into(), Some(3).into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    [
+                        r#"INSERT INTO "fruit" ("id", "name", "cake_id") VALUES ($1, $2, $3)"#,
+                        r#"ON CONFLICT ("id") DO UPDATE SET "name" = "excluded"."name""#,
+                        r#"RETURNING "id", "name", "cake_id""#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    vec![1.into(), "Apple".into(), Some(3).into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_exec_with_returning_many() -> Result<(), DbErr> {
+        let apple = cake::Model {
+            id: 1,
+            name: "Apple Pie".to_owned(),
+        };
+        let orange = cake::Model {
+            id: 2,
+            name: "Orange Scone".to_owned(),
+        };
+        let insert = || {
+            cake::Entity::insert_many([
+                cake::ActiveModel {
+                    id: ActiveValue::not_set(),
+                    name: ActiveValue::set("Apple Pie".to_owned()),
+                },
+                cake::ActiveModel {
+                    id: ActiveValue::not_set(),
+                    name: ActiveValue::set("Orange Scone".to_owned()),
+                },
+            ])
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![apple.clone(), orange.clone()]])
+            .into_connection();
+
+        assert_eq!(
+            insert().exec_with_returning_many(&db).await?,
+            vec![apple, orange]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO "cake" ("name") VALUES ($1), ($2) RETURNING "id", "name""#,
+                vec!["Apple Pie".into(), "Orange Scone".into()]
+            )]
+        );
+
+        let db = MockDatabase::new(DbBackend::MySql).into_connection();
+
+        assert_eq!(
+            insert().exec_with_returning_many(&db).await.err(),
+            Some(DbErr::Exec(
+                "`RETURNING` is not supported by the database backend".to_owned()
+            ))
+        );
+        assert_eq!(db.into_transaction_log(), vec![]);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    #[cfg(feature = "macros")]
+    async fn insert_exec_with_returning_as() -> Result<(), DbErr> {
+        use crate as sea_orm;
+        use crate::{FromQueryResult, Value};
+
+        #[derive(Debug, PartialEq, FromQueryResult)]
+        struct FruitKey {
+            id: i32,
+            cake_id: Option<i32>,
+        }
+
+        let insert = || {
+            fruit::Entity::insert_many([
+                fruit::ActiveModel {
+                    name: ActiveValue::set("Apple".to_owned()),
+                    cake_id: ActiveValue::set(Some(1)),
+                    ..Default::default()
+                },
+                fruit::ActiveModel {
+                    name: ActiveValue::set("Orange".to_owned()),
+                    cake_id: ActiveValue::set(None),
+                    ..Default::default()
+                },
+            ])
+            .returning([fruit::Column::Id, fruit::Column::CakeId])
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                maplit::btreemap! {
+                    "id" => Into::<Value>::into(1),
+                    "cake_id" => Into::<Value>::into(Some(1)),
+                },
+                maplit::btreemap! {
+                    "id" => Into::<Value>::into(2),
+                    "cake_id" => Into::<Value>::into(Option::<i32>::None),
+                },
+            ]])
+            .append_query_results(vec![vec![
+                maplit::btreemap! {
+                    "id" => Into::<Value>::into(3),
+                    "cake_id" => Into::<Value>::into(Some(1)),
+                },
+                maplit::btreemap! {
+                    "id" => Into::<Value>::into(4),
+                    "cake_id" => Into::<Value>::into(Option::<i32>::None),
+                },
+            ]])
+            .into_connection();
+
+        assert_eq!(
+            insert().exec_with_returning_as::<FruitKey, _>(&db).await?,
+            [
+                FruitKey {
+                    id: 1,
+                    cake_id: Some(1)
+                },
+                FruitKey {
+                    id: 2,
+                    cake_id: None
+                },
+            ]
+        );
+        // The columns of a mock row are ordered by name
+        assert_eq!(
+            insert()
+                .exec_with_returning_tuple::<(Option<i32>, i32), _>(&db)
+                .await?,
+            [(Some(1), 3), (None, 4)]
+        );
+
+        let stmt = Transaction::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"INSERT INTO "fruit" ("name", "cake_id") VALUES ($1, $2), ($3, $4) RETURNING "id", "cake_id""#,
+            vec![
+                "Apple".into(),
+                Some(1).into(),
+                "Orange".into(),
+                Option::<i32>::None.into(),
+            ],
+        );
+        assert_eq!(db.into_transaction_log(), vec![stmt.clone(), stmt]);
+
+        let db = MockDatabase::new(DbBackend::MySql).into_connection();
+
+        assert_eq!(
+            insert()
+                .exec_with_returning_as::<FruitKey, _>(&db)
+                .await
+                .err(),
+            Some(DbErr::Exec(
+                "`RETURNING` is not supported by the database backend".to_owned()
+            ))
+        );
+        assert_eq!(db.into_transaction_log(), vec![]);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_exec_with_returning_keys() -> Result<(), DbErr> {
+        let insert = || {
+            cake::Entity::insert_many(["Apple Pie", "Orange Scone", "Lemon Tart"].map(|name| {
+                cake::ActiveModel {
+                    id: ActiveValue::not_set(),
+                    name: ActiveValue::set(name.to_owned()),
+                }
+            }))
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![[7, 3, 5]
+                .into_iter()
+                .map(|id| BTreeMap::from([("id", Value::from(id))]))
+                .collect::<Vec<_>>()])
+            .into_connection();
+        assert_eq!(insert().exec_with_returning_keys(&db).await?, vec![7, 3, 5]);
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO "cake" ("name") VALUES ($1), ($2), ($3) RETURNING "id""#,
+                vec![
+                    "Apple Pie".into(),
+                    "Orange Scone".into(),
+                    "Lemon Tart".into()
+                ]
+            )]
+        );
+
+        // MySQL returns the id of the first inserted row
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 10,
+                rows_affected: 3,
+            }])
+            .into_connection();
+        assert_eq!(
+            insert().exec_with_returning_keys(&db).await?,
+            vec![10, 11, 12]
+        );
+
+        // SQLite returns the id of the last inserted row
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 12,
+                rows_affected: 3,
+            }])
+            .into_connection();
+        assert_eq!(
+            insert().exec_with_returning_keys(&db).await?,
+            vec![10, 11, 12]
+        );
+
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 10,
+                rows_affected: 2,
+            }])
+            .into_connection();
+        assert_eq!(
+            insert().exec_with_returning_keys(&db).await.err(),
+            Some(DbErr::Exec(
+                "Expected 3 inserted rows, but 2 were affected".to_owned()
+            ))
+        );
+
+        let db = MockDatabase::new(DbBackend::MySql).into_connection();
+        assert_eq!(
+            cake::Entity::insert(cake::ActiveModel {
+                id: ActiveValue::set(1),
+                name: ActiveValue::set("Apple Pie".to_owned()),
+            })
+            .exec_with_returning_keys(&db)
+            .await
+            .err(),
+            Some(DbErr::Exec(
+                "The inserted keys can only be reconstructed for auto increment primary keys"
+                    .to_owned()
+            ))
+        );
+        assert!(db.into_transaction_log().is_empty());
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_exec_without_returning_chunked() -> Result<(), DbErr> {
+        let insert = || {
+            cake::Entity::insert_many(["Apple Pie", "Orange Scone", "Lemon Tart"].map(|name| {
+                cake::ActiveModel {
+                    id: ActiveValue::not_set(),
+                    name: ActiveValue::set(name.to_owned()),
+                }
+            }))
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 3,
+            }])
+            .into_connection();
+
+        assert_eq!(insert().exec_without_returning(&db).await?, 3);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO "cake" ("name") VALUES ($1), ($2), ($3)"#,
+                vec![
+                    "Apple Pie".into(),
+                    "Orange Scone".into(),
+                    "Lemon Tart".into()
+                ]
+            )]
+        );
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results(vec![
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 2,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+            ])
+            .into_connection();
+
+        assert_eq!(insert().chunk_size(2).exec_without_returning(&db).await?, 3);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::many(vec![
+                Statement::from_string(DbBackend::Postgres, "BEGIN".to_owned()),
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "cake" ("name") VALUES ($1), ($2)"#,
+                    vec!["Apple Pie".into(), "Orange Scone".into()]
+                ),
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "cake" ("name") VALUES ($1)"#,
+                    vec!["Lemon Tart".into()]
+                ),
+                Statement::from_string(DbBackend::Postgres, "COMMIT".to_owned()),
+            ])]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_exec_without_returning_chunked_on_conflict() -> Result<(), DbErr> {
+        let insert = || {
+            cake::Entity::insert_many(["Apple Pie", "Orange Scone", "Lemon Tart"].map(|name| {
+                cake::ActiveModel {
+                    id: ActiveValue::not_set(),
+                    name: ActiveValue::set(name.to_owned()),
+                }
+            }))
+            .on_conflict(
+                OnConflict::column(cake::Column::Name)
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .chunk_size(2)
+        };
+
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_exec_results(vec![
+                MockExecResult {
+                    last_insert_id: 2,
+                    rows_affected: 2,
+                },
+                MockExecResult {
+                    last_insert_id: 3,
+                    rows_affected: 1,
+                },
+            ])
+            .into_connection();
+
+        assert_eq!(insert().exec_without_returning(&db).await?, 3);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::many(vec![
+                Statement::from_string(DbBackend::Sqlite, "BEGIN".to_owned()),
+                Statement::from_sql_and_values(
+                    DbBackend::Sqlite,
+                    r#"INSERT INTO "cake" ("name") VALUES (?), (?) ON CONFLICT ("name") DO NOTHING"#,
+                    vec!["Apple Pie".into(), "Orange Scone".into()]
+                ),
+                Statement::from_sql_and_values(
+                    DbBackend::Sqlite,
+                    r#"INSERT INTO "cake" ("name") VALUES (?) ON CONFLICT ("name") DO NOTHING"#,
+                    vec!["Lemon Tart".into()]
+                ),
+                Statement::from_string(DbBackend::Sqlite, "COMMIT".to_owned()),
+            ])]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_composite_primary_key() -> Result<(), DbErr> {
+        let cake_filling = || cake_filling::ActiveModel {
+            cake_id: ActiveValue::set(2),
+            filling_id: ActiveValue::set(3),
+        };
+
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        let res = cake_filling::Entity::insert(cake_filling())
+            .exec(&db)
+            .await?;
+        assert_eq!(res.last_insert_id, (2, 3));
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![cake_filling::Model {
+                cake_id: 2,
+                filling_id: 3,
+            }]])
+            .into_connection();
+
+        let res = cake_filling::Entity::insert(cake_filling())
+            .exec(&db)
+            .await?;
+        assert_eq!(res.last_insert_id, (2, 3));
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO "cake_filling" ("cake_id", "filling_id") VALUES ($1, $2) RETURNING "cake_id", "filling_id""#,
+                vec![2.into(), 3.into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    #[cfg(all(feature = "macros", feature = "with-uuid"))]
+    async fn insert_uuid_primary_key() -> Result<(), DbErr> {
+        mod tag {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+            #[sea_orm(table_name = "tag")]
+            pub struct Model {
+                #[sea_orm(primary_key, auto_increment = false)]
+                pub id: Uuid,
+                pub name: String,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        let id = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        let tag = tag::Model {
+            id,
+            name: "Cheese".to_owned(),
+        };
+
+        // the key is taken from the inserted values, not the integer `last_insert_id`
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        let res = tag::Entity::insert(tag.clone().into_active_model())
+            .exec(&db)
+            .await?;
+        assert_eq!(res.last_insert_id, id);
+
+        // the key is returned by the database
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![tag.clone()]])
+            .into_connection();
+
+        let res = tag::Entity::insert(tag::ActiveModel {
+            name: ActiveValue::set("Cheese".to_owned()),
+            ..Default::default()
+        })
+        .exec(&db)
+        .await?;
+        assert_eq!(res.last_insert_id, id);
+
+        // there is no key to fall back on
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 1,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        let res = tag::Entity::insert(tag::ActiveModel {
+            name: ActiveValue::set("Cheese".to_owned()),
+            ..Default::default()
+        })
+        .exec(&db)
+        .await;
+        assert_eq!(
+            res.err(),
+            Some(DbErr::Exec("Fail to unpack last_insert_id".to_owned()))
+        );
+
+        Ok(())
+    }
+}
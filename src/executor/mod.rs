@@ -1,5 +1,6 @@
 mod cursor;
 mod delete;
+mod discriminator;
 mod execute;
 mod insert;
 mod paginator;
@@ -9,6 +10,7 @@ mod update;
 
 pub use cursor::*;
 pub use delete::*;
+pub use discriminator::*;
 pub use execute::*;
 pub use insert::*;
 pub use paginator::*;
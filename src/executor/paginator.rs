@@ -1,10 +1,11 @@
 use crate::{
-    error::*, ConnectionTrait, DbBackend, EntityTrait, FromQueryResult, Select, SelectModel,
-    SelectTwo, SelectTwoModel, Selector, SelectorRaw, SelectorTrait,
+    error::*, ConnectionTrait, DbBackend, EntityTrait, FromQueryResult, IdenStatic, Iterable,
+    PrimaryKeyToColumn, PrimaryKeyTrait, QueryResult, Select, SelectModel, SelectTwo,
+    SelectTwoMany, SelectTwoModel, Selector, SelectorRaw, SelectorTrait, TryGetableMany,
 };
 use async_stream::stream;
 use futures::Stream;
-use sea_query::{Alias, Expr, SelectStatement};
+use sea_query::{Alias, Condition, Expr, IntoValueTuple, SelectStatement, Value};
 use std::{marker::PhantomData, pin::Pin};
 
 /// Pin a Model so that stream operations can be performed on the model
@@ -205,6 +206,217 @@ where
     }
 }
 
+/// Paginate the result of [SelectTwoMany] by its parent models, so that
+/// the children of a parent are never split across two pages.
+///
+/// Each page first selects the primary keys of the parents within the page,
+/// then fetches the joined rows of those parents only.
+#[derive(Clone, Debug)]
+pub struct SelectTwoManyPaginator<'db, C, E, F>
+where
+    C: ConnectionTrait,
+    E: EntityTrait,
+    F: EntityTrait,
+{
+    pub(crate) keys: Paginator<'db, C, SelectPrimaryKey<E>>,
+    pub(crate) query: SelectStatement,
+    pub(crate) entity: PhantomData<F>,
+}
+
+/// Read the primary key of `E` from the parent keys query of [SelectTwoManyPaginator]
+#[derive(Clone, Debug)]
+pub(crate) struct SelectPrimaryKey<E>
+where
+    E: EntityTrait,
+{
+    entity: PhantomData<E>,
+}
+
+impl<E> SelectorTrait for SelectPrimaryKey<E>
+where
+    E: EntityTrait,
+{
+    type Item = <E::PrimaryKey as PrimaryKeyTrait>::ValueType;
+
+    fn from_raw_query_result(res: QueryResult) -> Result<Self::Item, DbErr> {
+        let cols: Vec<String> = <E::PrimaryKey as Iterable>::iter()
+            .map(|pk| pk.as_str().to_owned())
+            .collect();
+        Self::Item::try_get_many(&res, "", &cols).map_err(Into::into)
+    }
+}
+
+impl<'db, C, E, F> SelectTwoManyPaginator<'db, C, E, F>
+where
+    C: ConnectionTrait,
+    E: EntityTrait,
+    F: EntityTrait,
+{
+    pub(crate) fn new(query: SelectStatement, db: &'db C, page_size: u64) -> Self {
+        let mut keys = query.clone();
+        keys.clear_selects();
+        for pk in <E::PrimaryKey as Iterable>::iter() {
+            let col = (E::default(), pk.into_column());
+            keys.expr_as(Expr::tbl(col.0, col.1), Alias::new(pk.as_str()))
+                .group_by_col(col);
+        }
+        Self {
+            keys: Paginator {
+                query: keys,
+                page: 0,
+                page_size,
+                db,
+                selector: PhantomData,
+            },
+            query,
+            entity: PhantomData,
+        }
+    }
+
+    /// Fetch a specific page; page index starts from zero
+    pub async fn fetch_page(&self, page: u64) -> Result<Vec<(E::Model, Vec<F::Model>)>, DbErr> {
+        let keys = self.keys.fetch_page(page).await?;
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+        let query = self
+            .query
+            .clone()
+            .cond_where(Condition::all().add(Self::key_condition(keys)))
+            .to_owned();
+        SelectTwoMany::<E, F> {
+            query,
+            entity: PhantomData,
+        }
+        .all(self.keys.db)
+        .await
+    }
+
+    /// Select the rows of the parents having one of the `keys`
+    fn key_condition(keys: Vec<<E::PrimaryKey as PrimaryKeyTrait>::ValueType>) -> Condition {
+        let cols: Vec<_> = <E::PrimaryKey as Iterable>::iter()
+            .map(|pk| pk.into_column())
+            .collect();
+        if cols.len() == 1 {
+            let values = keys
+                .into_iter()
+                .flat_map(|key| key.into_value_tuple())
+                .collect::<Vec<Value>>();
+            return Condition::all().add(Expr::tbl(E::default(), cols[0]).is_in(values));
+        }
+        keys.into_iter().fold(Condition::any(), |condition, key| {
+            let cond = cols
+                .iter()
+                .zip(key.into_value_tuple())
+                .fold(Condition::all(), |cond, (col, value)| {
+                    cond.add(Expr::tbl(E::default(), *col).eq(value))
+                });
+            condition.add(cond)
+        })
+    }
+
+    /// Fetch the current page
+    pub async fn fetch(&self) -> Result<Vec<(E::Model, Vec<F::Model>)>, DbErr> {
+        self.fetch_page(self.keys.page).await
+    }
+
+    /// Get the total number of parent models
+    pub async fn num_items(&self) -> Result<u64, DbErr> {
+        self.keys.num_items().await
+    }
+
+    /// Get the total number of pages
+    pub async fn num_pages(&self) -> Result<u64, DbErr> {
+        self.keys.num_pages().await
+    }
+
+    /// Get the total number of parent models and pages
+    pub async fn num_items_and_pages(&self) -> Result<ItemsAndPagesNumber, DbErr> {
+        self.keys.num_items_and_pages().await
+    }
+
+    /// Increment the page counter
+    pub fn next(&mut self) {
+        self.keys.next();
+    }
+
+    /// Get current page number
+    pub fn cur_page(&self) -> u64 {
+        self.keys.cur_page()
+    }
+
+    /// Fetch one page and increment the page counter
+    pub async fn fetch_and_next(
+        &mut self,
+    ) -> Result<Option<Vec<(E::Model, Vec<F::Model>)>>, DbErr> {
+        let vec = self.fetch().await?;
+        self.next();
+        let opt = if !vec.is_empty() { Some(vec) } else { None };
+        Ok(opt)
+    }
+}
+
+impl<E, F> SelectTwoMany<E, F>
+where
+    E: EntityTrait,
+    F: EntityTrait,
+{
+    /// Paginate the result by the parent models, so that a page holds `page_size`
+    /// parents along with all of their children.
+    ///
+    /// The parents within a page are selected by grouping the query on the primary key of `E`,
+    /// so any `ORDER BY` must be on the columns of `E`.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results(vec![
+    /// #         vec![maplit::btreemap! { "id" => Into::<Value>::into(1) }],
+    /// #         vec![maplit::btreemap! {
+    /// #             "A_id" => Into::<Value>::into(1),
+    /// #             "A_name" => Into::<Value>::into("Chocolate Forest"),
+    /// #             "B_id" => Into::<Value>::into(1),
+    /// #             "B_name" => Into::<Value>::into("Apple"),
+    /// #             "B_cake_id" => Into::<Value>::into(1),
+    /// #         }],
+    /// #         vec![],
+    /// #     ])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}};
+    ///
+    /// let mut pages = cake::Entity::find()
+    ///     .find_with_related(fruit::Entity)
+    ///     .paginate(&db, 10);
+    ///
+    /// while let Some(cakes) = pages.fetch_and_next().await? {
+    ///     // Do something on cakes: Vec<(cake::Model, Vec<fruit::Model>)>
+    /// }
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn paginate<C>(self, db: &C, page_size: u64) -> SelectTwoManyPaginator<'_, C, E, F>
+    where
+        C: ConnectionTrait,
+    {
+        SelectTwoManyPaginator::new(self.query, db, page_size)
+    }
+
+    /// Count the number of parent models
+    pub async fn count<C>(self, db: &C) -> Result<u64, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        self.paginate(db, 1).num_items().await
+    }
+}
+
 #[async_trait::async_trait]
 /// A Trait for any type that can paginate results
 pub trait PaginatorTrait<'db, C>
@@ -218,6 +430,10 @@ where
     fn paginate(self, db: &'db C, page_size: u64) -> Paginator<'db, C, Self::Selector>;
 
     /// Perform a count on the paginated results
+    ///
+    /// The query is wrapped as `SELECT COUNT(*) FROM (...) AS sub_query`, so it counts the rows
+    /// it returns: one per group for a grouped query, and one per joined row for a joined query.
+    /// Use [Select::count_distinct] to count the distinct values of a column instead.
     async fn count(self, db: &'db C) -> Result<u64, DbErr>
     where
         Self: Send + Sized,
@@ -307,6 +523,7 @@ mod tests {
     use futures::TryStreamExt;
     use once_cell::sync::Lazy;
     use sea_query::{Alias, Expr, SelectStatement, Value};
+    use std::collections::BTreeMap;
 
     static RAW_STMT: Lazy<Statement> = Lazy::new(|| {
         Statement::from_sql_and_values(
@@ -520,6 +737,41 @@ mod tests {
         Ok(())
     }
 
+    #[smol_potat::test]
+    async fn count_joined() -> Result<(), DbErr> {
+        use crate::QuerySelect;
+
+        let (db, num_items) = setup_num_items();
+
+        assert_eq!(
+            cake::Entity::find()
+                .select_only()
+                .column(cake::Column::Id)
+                .inner_join(fruit::Entity)
+                .group_by(cake::Column::Id)
+                .count(&db)
+                .await?,
+            num_items as u64
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT COUNT(*) AS num_items FROM"#,
+                    r#"(SELECT "cake"."id" FROM "cake""#,
+                    r#"INNER JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+                    r#"GROUP BY "cake"."id") AS "sub_query""#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec![]
+            )]
+        );
+        Ok(())
+    }
+
     #[smol_potat::test]
     async fn num_pages_raw() -> Result<(), DbErr> {
         let (db, num_items) = setup_num_items();
@@ -692,6 +944,45 @@ mod tests {
         Ok(())
     }
 
+    #[smol_potat::test]
+    async fn into_stream_collect() -> Result<(), DbErr> {
+        let (db, pages) = setup();
+
+        let fruits: Vec<fruit::Model> = fruit::Entity::find()
+            .paginate(&db, 2)
+            .into_stream()
+            .try_concat()
+            .await?;
+
+        assert_eq!(fruits, pages.concat());
+        assert_eq!(db.into_transaction_log().len(), 3);
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn into_stream_error() -> Result<(), DbErr> {
+        let (_, pages) = setup();
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![pages[0].clone()])
+            .append_query_errors(vec![DbErr::Query("connection lost".to_owned())])
+            .append_query_results(vec![pages[1].clone()])
+            .into_connection();
+
+        let mut fruit_stream = fruit::Entity::find().paginate(&db, 2).into_stream();
+
+        assert_eq!(fruit_stream.try_next().await?, Some(pages[0].clone()));
+        assert_eq!(
+            fruit_stream.try_next().await,
+            Err(DbErr::Query("connection lost".to_owned()))
+        );
+        assert_eq!(fruit_stream.try_next().await?, None);
+
+        drop(fruit_stream);
+
+        assert_eq!(db.into_transaction_log().len(), 2);
+        Ok(())
+    }
+
     #[smol_potat::test]
     async fn into_stream_raw() -> Result<(), DbErr> {
         let (db, pages) = setup();
@@ -726,4 +1017,154 @@ mod tests {
         assert_eq!(db.into_transaction_log(), Transaction::wrap(stmts));
         Ok(())
     }
+
+    fn cake_with_fruit(
+        cake: &cake::Model,
+        fruit: Option<&fruit::Model>,
+    ) -> BTreeMap<&'static str, Value> {
+        maplit::btreemap! {
+            "A_id" => cake.id.into(),
+            "A_name" => cake.name.clone().into(),
+            "B_id" => fruit.map(|f| f.id).into(),
+            "B_name" => fruit.map(|f| f.name.clone()).into(),
+            "B_cake_id" => fruit.and_then(|f| f.cake_id).into(),
+        }
+    }
+
+    fn cake_keys(ids: &[i32]) -> Vec<BTreeMap<&'static str, Value>> {
+        ids.iter()
+            .map(|id| maplit::btreemap! { "id" => Into::<Value>::into(*id) })
+            .collect()
+    }
+
+    #[smol_potat::test]
+    async fn fetch_page_with_related() -> Result<(), DbErr> {
+        let cakes: Vec<_> = (1..=3)
+            .map(|id| cake::Model {
+                id,
+                name: format!("Cake {}", id),
+            })
+            .collect();
+        let fruits: Vec<_> = [(1, 1), (2, 1), (3, 1), (4, 3)]
+            .into_iter()
+            .map(|(id, cake_id)| fruit::Model {
+                id,
+                name: format!("Fruit {}", id),
+                cake_id: Some(cake_id),
+            })
+            .collect();
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![
+                cake_keys(&[1, 2]),
+                vec![
+                    cake_with_fruit(&cakes[0], Some(&fruits[0])),
+                    cake_with_fruit(&cakes[0], Some(&fruits[1])),
+                    cake_with_fruit(&cakes[0], Some(&fruits[2])),
+                    cake_with_fruit(&cakes[1], None),
+                ],
+                cake_keys(&[3]),
+                vec![cake_with_fruit(&cakes[2], Some(&fruits[3]))],
+                cake_keys(&[]),
+            ])
+            .into_connection();
+
+        let mut paginator = cake::Entity::find()
+            .find_with_related(fruit::Entity)
+            .paginate(&db, 2);
+
+        // The three fruits of the first cake don't push the second cake off the first page
+        assert_eq!(
+            paginator.fetch_and_next().await?,
+            Some(vec![
+                (cakes[0].clone(), fruits[0..3].to_vec()),
+                (cakes[1].clone(), vec![]),
+            ])
+        );
+        assert_eq!(
+            paginator.fetch_and_next().await?,
+            Some(vec![(cakes[2].clone(), vec![fruits[3].clone()])])
+        );
+        assert_eq!(paginator.fetch_and_next().await?, None);
+        assert_eq!(paginator.cur_page(), 3);
+
+        let keys = [
+            r#"SELECT "cake"."id" AS "id" FROM "cake""#,
+            r#"LEFT JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+            r#"GROUP BY "cake"."id" ORDER BY "cake"."id" ASC"#,
+        ]
+        .join(" ");
+        let rows = [
+            r#"SELECT "cake"."id" AS "A_id", "cake"."name" AS "A_name","#,
+            r#""fruit"."id" AS "B_id", "fruit"."name" AS "B_name", "fruit"."cake_id" AS "B_cake_id""#,
+            r#"FROM "cake" LEFT JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+        ]
+        .join(" ");
+        assert_eq!(
+            db.into_transaction_log(),
+            [
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    &format!("{} LIMIT $1 OFFSET $2", keys),
+                    vec![2u64.into(), 0u64.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    &format!(
+                        r#"{} WHERE "cake"."id" IN ($1, $2) ORDER BY "cake"."id" ASC"#,
+                        rows
+                    ),
+                    vec![1i32.into(), 2i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    &format!("{} LIMIT $1 OFFSET $2", keys),
+                    vec![2u64.into(), 2u64.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    &format!(
+                        r#"{} WHERE "cake"."id" IN ($1) ORDER BY "cake"."id" ASC"#,
+                        rows
+                    ),
+                    vec![3i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    &format!("{} LIMIT $1 OFFSET $2", keys),
+                    vec![2u64.into(), 4u64.into()]
+                ),
+            ]
+        );
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn num_items_with_related() -> Result<(), DbErr> {
+        let (db, num_items) = setup_num_items();
+
+        let paginator = cake::Entity::find()
+            .find_with_related(fruit::Entity)
+            .filter(fruit::Column::Name.contains("berry"))
+            .paginate(&db, 2);
+
+        assert_eq!(paginator.num_items().await?, num_items as u64);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                &[
+                    r#"SELECT COUNT(*) AS num_items FROM"#,
+                    r#"(SELECT "cake"."id" AS "id" FROM "cake""#,
+                    r#"LEFT JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+                    r#"WHERE "fruit"."name" LIKE $1"#,
+                    r#"GROUP BY "cake"."id" ORDER BY "cake"."id" ASC) AS "sub_query""#,
+                ]
+                .join(" "),
+                vec!["%berry%".into()]
+            )]
+        );
+        Ok(())
+    }
 }
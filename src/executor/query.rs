@@ -1,6 +1,6 @@
 #[cfg(feature = "mock")]
 use crate::debug_print;
-use crate::{DbErr, SelectGetableValue, SelectorRaw, Statement};
+use crate::{ActiveEnum, DbErr, SelectGetableValue, SelectorRaw, Statement, Value};
 use std::fmt;
 
 /// Defines the result of a query operation on a Model
@@ -58,6 +58,15 @@ impl QueryResult {
         Ok(T::try_get(self, pre, col)?)
     }
 
+    /// Get an [ActiveEnum] from a Column, converting its value with [ActiveEnum::try_from_value]
+    pub fn try_get_enum<T>(&self, pre: &str, col: &str) -> Result<T, DbErr>
+    where
+        T: ActiveEnum,
+    {
+        let value: T::Value = self.try_get(pre, col)?;
+        T::try_from_value(&value)
+    }
+
     /// Perform query operations on multiple Columns
     pub fn try_get_many<T>(&self, pre: &str, cols: &[String]) -> Result<T, DbErr>
     where
@@ -65,6 +74,183 @@ impl QueryResult {
     {
         Ok(T::try_get_many(self, pre, cols)?)
     }
+
+    /// Get the names of all columns in the order they appear in the row.
+    /// Rows of a [MockDatabase](crate::MockDatabase) are keyed by column name,
+    /// so their column names are sorted by name instead.
+    pub fn column_names(&self) -> Vec<String> {
+        #[cfg(feature = "sqlx-dep")]
+        use sqlx::{Column, Row};
+
+        match &self.row {
+            #[cfg(feature = "sqlx-mysql")]
+            QueryResultRow::SqlxMySql(row) => {
+                row.columns().iter().map(|c| c.name().to_owned()).collect()
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            QueryResultRow::SqlxPostgres(row) => {
+                row.columns().iter().map(|c| c.name().to_owned()).collect()
+            }
+            #[cfg(feature = "sqlx-sqlite")]
+            QueryResultRow::SqlxSqlite(row) => {
+                row.columns().iter().map(|c| c.name().to_owned()).collect()
+            }
+            #[cfg(feature = "mock")]
+            QueryResultRow::Mock(row) => row.column_names(),
+            #[allow(unreachable_patterns)]
+            _ => unreachable!(),
+        }
+    }
+
+    /// Get the values of all columns, in the same order as [QueryResult::column_names].
+    /// Each value is decoded by the type of its column, e.g. a `BIGINT` as [Value::BigInt].
+    #[allow(unused_mut, unused_variables, unreachable_code)]
+    pub fn try_get_values(&self) -> Result<Vec<Value>, DbErr> {
+        let mut values = Vec::new();
+        #[allow(unused_macros)]
+        macro_rules! try_get_type {
+            ( $type: ty, $col: expr ) => {
+                if let Ok(v) = self.try_get::<Option<$type>>("", $col) {
+                    values.push(v.into());
+                    continue;
+                }
+            };
+        }
+        #[allow(unused_macros)]
+        macro_rules! unsupported_type {
+            ( $col: expr, $col_type: expr ) => {
+                return Err(DbErr::Type(format!(
+                    "unsupported type `{}` of column `{}`",
+                    $col_type.name(),
+                    $col
+                )))
+            };
+        }
+        match &self.row {
+            #[cfg(feature = "sqlx-mysql")]
+            QueryResultRow::SqlxMySql(row) => {
+                use sqlx::{Column, MySql, Row, Type, TypeInfo};
+                for column in row.columns() {
+                    let (col, col_type) = (column.name(), column.type_info());
+                    macro_rules! match_mysql_type {
+                        ( $type: ty ) => {
+                            if <$type as Type<MySql>>::type_info().eq(col_type) {
+                                try_get_type!($type, col)
+                            }
+                        };
+                    }
+                    match_mysql_type!(bool);
+                    match_mysql_type!(i8);
+                    match_mysql_type!(i16);
+                    match_mysql_type!(i32);
+                    match_mysql_type!(i64);
+                    match_mysql_type!(u8);
+                    match_mysql_type!(u16);
+                    match_mysql_type!(u32);
+                    match_mysql_type!(u64);
+                    match_mysql_type!(f32);
+                    match_mysql_type!(f64);
+                    match_mysql_type!(String);
+                    #[cfg(feature = "with-chrono")]
+                    match_mysql_type!(chrono::NaiveDate);
+                    #[cfg(feature = "with-chrono")]
+                    match_mysql_type!(chrono::NaiveTime);
+                    #[cfg(feature = "with-chrono")]
+                    match_mysql_type!(chrono::NaiveDateTime);
+                    #[cfg(feature = "with-chrono")]
+                    match_mysql_type!(chrono::DateTime<chrono::Utc>);
+                    #[cfg(feature = "with-rust_decimal")]
+                    match_mysql_type!(rust_decimal::Decimal);
+                    #[cfg(feature = "with-json")]
+                    try_get_type!(serde_json::Value, col);
+                    try_get_type!(String, col);
+                    #[cfg(feature = "with-uuid")]
+                    try_get_type!(uuid::Uuid, col);
+                    try_get_type!(Vec<u8>, col);
+                    unsupported_type!(col, col_type);
+                }
+            }
+            #[cfg(feature = "sqlx-postgres")]
+            QueryResultRow::SqlxPostgres(row) => {
+                use sqlx::{postgres::types::Oid, Column, Postgres, Row, Type, TypeInfo};
+                for column in row.columns() {
+                    let (col, col_type) = (column.name(), column.type_info());
+                    macro_rules! match_postgres_type {
+                        ( $type: ty ) => {
+                            if <$type as Type<Postgres>>::type_info().eq(col_type) {
+                                try_get_type!($type, col)
+                            }
+                        };
+                    }
+                    match_postgres_type!(bool);
+                    match_postgres_type!(i8);
+                    match_postgres_type!(i16);
+                    match_postgres_type!(i32);
+                    match_postgres_type!(i64);
+                    if <Oid as Type<Postgres>>::type_info().eq(col_type) {
+                        try_get_type!(u32, col)
+                    }
+                    match_postgres_type!(f32);
+                    match_postgres_type!(f64);
+                    #[cfg(feature = "with-chrono")]
+                    match_postgres_type!(chrono::NaiveDate);
+                    #[cfg(feature = "with-chrono")]
+                    match_postgres_type!(chrono::NaiveTime);
+                    #[cfg(feature = "with-chrono")]
+                    match_postgres_type!(chrono::NaiveDateTime);
+                    #[cfg(feature = "with-chrono")]
+                    match_postgres_type!(chrono::DateTime<chrono::FixedOffset>);
+                    #[cfg(feature = "with-rust_decimal")]
+                    match_postgres_type!(rust_decimal::Decimal);
+                    #[cfg(feature = "with-json")]
+                    try_get_type!(serde_json::Value, col);
+                    try_get_type!(String, col);
+                    #[cfg(feature = "with-uuid")]
+                    try_get_type!(uuid::Uuid, col);
+                    try_get_type!(Vec<u8>, col);
+                    unsupported_type!(col, col_type);
+                }
+            }
+            #[cfg(feature = "sqlx-sqlite")]
+            QueryResultRow::SqlxSqlite(row) => {
+                use sqlx::{Column, Row, Sqlite, Type, TypeInfo};
+                for column in row.columns() {
+                    let (col, col_type) = (column.name(), column.type_info());
+                    macro_rules! match_sqlite_type {
+                        ( $type: ty ) => {
+                            if <$type as Type<Sqlite>>::type_info().eq(col_type) {
+                                try_get_type!($type, col)
+                            }
+                        };
+                    }
+                    match_sqlite_type!(bool);
+                    match_sqlite_type!(i32);
+                    match_sqlite_type!(i64);
+                    // `f32` and `f64` share the same type info, try the wider type
+                    // first to not lose precision
+                    match_sqlite_type!(f64);
+                    #[cfg(feature = "with-chrono")]
+                    match_sqlite_type!(chrono::NaiveDate);
+                    #[cfg(feature = "with-chrono")]
+                    match_sqlite_type!(chrono::NaiveTime);
+                    #[cfg(feature = "with-chrono")]
+                    match_sqlite_type!(chrono::NaiveDateTime);
+                    try_get_type!(String, col);
+                    #[cfg(feature = "with-uuid")]
+                    try_get_type!(uuid::Uuid, col);
+                    try_get_type!(Vec<u8>, col);
+                    unsupported_type!(col, col_type);
+                }
+            }
+            #[cfg(feature = "mock")]
+            QueryResultRow::Mock(row) => {
+                values.extend(row.clone().into_column_value_tuples().map(|(_, v)| v));
+            }
+            #[allow(unreachable_patterns)]
+            _ => unreachable!(),
+        }
+        Ok(values)
+    }
 }
 
 #[allow(unused_variables)]
@@ -263,7 +449,7 @@ macro_rules! try_getable_date_time {
 
 #[allow(unused_macros)]
 macro_rules! try_getable_time {
-    ( $type: ty ) => {
+    ( $type: ty, $format: ident ) => {
         #[allow(unused_variables)]
         impl TryGetable for $type {
             fn try_get(res: &QueryResult, pre: &str, col: &str) -> Result<Self, TryGetError> {
@@ -284,9 +470,63 @@ macro_rules! try_getable_time {
                             .and_then(|opt| opt.ok_or(TryGetError::Null(column)))
                     }
                     #[cfg(feature = "sqlx-sqlite")]
-                    QueryResultRow::SqlxSqlite(_) => {
-                        panic!("{} unsupported by sqlx-sqlite", stringify!($type))
+                    QueryResultRow::SqlxSqlite(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(column.as_str())
+                            .or_else(|e| {
+                                // sqlx cannot decode every text format SeaQuery binds,
+                                // e.g. the offset of a `TimeDateTimeWithTimeZone`
+                                match row.try_get::<Option<String>, _>(column.as_str()) {
+                                    Ok(Some(s)) => {
+                                        <$type>::parse(&s, sea_query::value::time_format::$format)
+                                            .map(Some)
+                                            .map_err(|_| e)
+                                    }
+                                    _ => Err(e),
+                                }
+                            })
+                            .map_err(|e| TryGetError::DbErr(crate::sqlx_error_to_query_err(e)))
+                            .and_then(|opt| opt.ok_or(TryGetError::Null(column)))
+                    }
+                    #[cfg(feature = "mock")]
+                    #[allow(unused_variables)]
+                    QueryResultRow::Mock(row) => row.try_get(column.as_str()).map_err(|e| {
+                        debug_print!("{:#?}", e.to_string());
+                        TryGetError::Null(column)
+                    }),
+                    #[allow(unreachable_patterns)]
+                    _ => unreachable!(),
+                }
+            }
+        }
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! try_getable_postgres {
+    ( $type: ty ) => {
+        impl TryGetable for $type {
+            fn try_get(res: &QueryResult, pre: &str, col: &str) -> Result<Self, TryGetError> {
+                #[allow(unused_variables)]
+                let column = format!("{}{}", pre, col);
+                match &res.row {
+                    #[cfg(feature = "sqlx-mysql")]
+                    QueryResultRow::SqlxMySql(_) => Err(TryGetError::DbErr(DbErr::Type(format!(
+                        "{} unsupported by sqlx-mysql",
+                        stringify!($type)
+                    )))),
+                    #[cfg(feature = "sqlx-postgres")]
+                    QueryResultRow::SqlxPostgres(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(column.as_str())
+                            .map_err(|e| TryGetError::DbErr(crate::sqlx_error_to_query_err(e)))
+                            .and_then(|opt| opt.ok_or(TryGetError::Null(column)))
                     }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    QueryResultRow::SqlxSqlite(_) => Err(TryGetError::DbErr(DbErr::Type(format!(
+                        "{} unsupported by sqlx-sqlite",
+                        stringify!($type)
+                    )))),
                     #[cfg(feature = "mock")]
                     #[allow(unused_variables)]
                     QueryResultRow::Mock(row) => row.try_get(column.as_str()).map_err(|e| {
@@ -301,6 +541,44 @@ macro_rules! try_getable_time {
     };
 }
 
+#[allow(unused_macros)]
+macro_rules! try_getable_postgres_range {
+    ( $type: ty ) => {
+        impl TryGetable for crate::PgRange<$type> {
+            #[allow(unused_variables)]
+            fn try_get(res: &QueryResult, pre: &str, col: &str) -> Result<Self, TryGetError> {
+                let column = format!("{}{}", pre, col);
+                match &res.row {
+                    #[cfg(feature = "sqlx-mysql")]
+                    QueryResultRow::SqlxMySql(_) => Err(TryGetError::DbErr(DbErr::Type(format!(
+                        "PgRange<{}> unsupported by sqlx-mysql",
+                        stringify!($type)
+                    )))),
+                    #[cfg(feature = "sqlx-postgres")]
+                    QueryResultRow::SqlxPostgres(row) => {
+                        use sqlx::{postgres::types::PgRange, Row};
+                        row.try_get::<Option<PgRange<$type>>, _>(column.as_str())
+                            .map_err(|e| TryGetError::DbErr(crate::sqlx_error_to_query_err(e)))
+                            .and_then(|opt| opt.map(Into::into).ok_or(TryGetError::Null(column)))
+                    }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    QueryResultRow::SqlxSqlite(_) => Err(TryGetError::DbErr(DbErr::Type(format!(
+                        "PgRange<{}> unsupported by sqlx-sqlite",
+                        stringify!($type)
+                    )))),
+                    #[cfg(feature = "mock")]
+                    QueryResultRow::Mock(row) => row.try_get(column.as_str()).map_err(|e| {
+                        debug_print!("{:#?}", e.to_string());
+                        TryGetError::Null(column)
+                    }),
+                    #[allow(unreachable_patterns)]
+                    _ => unreachable!(),
+                }
+            }
+        }
+    };
+}
+
 try_getable_all!(bool);
 try_getable_all!(i8);
 try_getable_all!(i16);
@@ -336,16 +614,16 @@ try_getable_all!(chrono::DateTime<chrono::Utc>);
 try_getable_all!(chrono::DateTime<chrono::Local>);
 
 #[cfg(feature = "with-time")]
-try_getable_time!(time::Date);
+try_getable_time!(time::Date, FORMAT_DATE);
 
 #[cfg(feature = "with-time")]
-try_getable_time!(time::Time);
+try_getable_time!(time::Time, FORMAT_TIME);
 
 #[cfg(feature = "with-time")]
-try_getable_time!(time::PrimitiveDateTime);
+try_getable_time!(time::PrimitiveDateTime, FORMAT_DATETIME);
 
 #[cfg(feature = "with-time")]
-try_getable_time!(time::OffsetDateTime);
+try_getable_time!(time::OffsetDateTime, FORMAT_DATETIME_TZ);
 
 #[cfg(feature = "with-rust_decimal")]
 use rust_decimal::Decimal;
@@ -401,6 +679,78 @@ impl TryGetable for Decimal {
 #[cfg(feature = "with-uuid")]
 try_getable_all!(uuid::Uuid);
 
+#[cfg(feature = "postgres-array")]
+try_getable_postgres!(Vec<bool>);
+
+#[cfg(feature = "postgres-array")]
+try_getable_postgres!(Vec<i16>);
+
+#[cfg(feature = "postgres-array")]
+try_getable_postgres!(Vec<i32>);
+
+#[cfg(feature = "postgres-array")]
+try_getable_postgres!(Vec<i64>);
+
+#[cfg(feature = "postgres-array")]
+try_getable_postgres!(Vec<f32>);
+
+#[cfg(feature = "postgres-array")]
+try_getable_postgres!(Vec<f64>);
+
+#[cfg(feature = "postgres-array")]
+try_getable_postgres!(Vec<String>);
+
+#[cfg(all(feature = "postgres-array", feature = "with-json"))]
+try_getable_postgres!(Vec<serde_json::Value>);
+
+#[cfg(all(feature = "postgres-array", feature = "with-chrono"))]
+try_getable_postgres!(Vec<chrono::NaiveDate>);
+
+#[cfg(all(feature = "postgres-array", feature = "with-chrono"))]
+try_getable_postgres!(Vec<chrono::NaiveTime>);
+
+#[cfg(all(feature = "postgres-array", feature = "with-chrono"))]
+try_getable_postgres!(Vec<chrono::NaiveDateTime>);
+
+#[cfg(all(feature = "postgres-array", feature = "with-chrono"))]
+try_getable_postgres!(Vec<chrono::DateTime<chrono::FixedOffset>>);
+
+#[cfg(all(feature = "postgres-array", feature = "with-chrono"))]
+try_getable_postgres!(Vec<chrono::DateTime<chrono::Utc>>);
+
+#[cfg(all(feature = "postgres-array", feature = "with-rust_decimal"))]
+try_getable_postgres!(Vec<rust_decimal::Decimal>);
+
+#[cfg(all(feature = "postgres-array", feature = "with-time"))]
+try_getable_postgres!(Vec<time::Date>);
+
+#[cfg(all(feature = "postgres-array", feature = "with-time"))]
+try_getable_postgres!(Vec<time::Time>);
+
+#[cfg(all(feature = "postgres-array", feature = "with-time"))]
+try_getable_postgres!(Vec<time::PrimitiveDateTime>);
+
+#[cfg(all(feature = "postgres-array", feature = "with-time"))]
+try_getable_postgres!(Vec<time::OffsetDateTime>);
+
+#[cfg(all(feature = "postgres-array", feature = "with-uuid"))]
+try_getable_postgres!(Vec<uuid::Uuid>);
+
+try_getable_postgres_range!(i32);
+try_getable_postgres_range!(i64);
+
+#[cfg(feature = "with-chrono")]
+try_getable_postgres_range!(chrono::NaiveDate);
+
+#[cfg(feature = "with-chrono")]
+try_getable_postgres_range!(chrono::NaiveDateTime);
+
+#[cfg(feature = "with-chrono")]
+try_getable_postgres_range!(chrono::DateTime<chrono::FixedOffset>);
+
+#[cfg(feature = "with-rust_decimal")]
+try_getable_postgres_range!(rust_decimal::Decimal);
+
 impl TryGetable for u32 {
     fn try_get(res: &QueryResult, pre: &str, col: &str) -> Result<Self, TryGetError> {
         #[allow(unused_variables)]
@@ -697,6 +1047,35 @@ where
     }
 }
 
+// TryGetableArray //
+
+/// A type that can be read from a Postgres array column, by converting
+/// the elements of the array read as `Vec<Self::Value>`.
+/// Reading an array on MySQL or SQLite fails with [DbErr::Type].
+#[cfg(feature = "postgres-array")]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgres-array")))]
+pub trait TryGetableArray: Sized {
+    /// The type of the array elements as read from the database
+    type Value;
+
+    /// Convert an element of the array
+    fn try_from_array_value(v: Self::Value) -> Result<Self, TryGetError>;
+}
+
+#[cfg(feature = "postgres-array")]
+impl<T> TryGetable for Vec<T>
+where
+    T: TryGetableArray,
+    Vec<T::Value>: TryGetable,
+{
+    fn try_get(res: &QueryResult, pre: &str, col: &str) -> Result<Self, TryGetError> {
+        <Vec<T::Value> as TryGetable>::try_get(res, pre, col)?
+            .into_iter()
+            .map(T::try_from_array_value)
+            .collect()
+    }
+}
+
 // TryFromU64 //
 /// Try to convert a type to a u64
 pub trait TryFromU64: Sized {
@@ -837,4 +1216,62 @@ mod tests {
         let expected = "error occurred while decoding column: Null".to_owned();
         assert_eq!(DbErr::from(try_get_error), DbErr::Query(expected));
     }
+
+    #[test]
+    #[cfg(all(feature = "mock", feature = "postgres-array"))]
+    fn try_get_array() {
+        use super::{QueryResult, QueryResultRow};
+        use crate::IntoMockRow;
+        use std::collections::BTreeMap;
+
+        let row = QueryResult {
+            row: QueryResultRow::Mock(
+                BTreeMap::from([
+                    ("integers", vec![1, 2, 3].into()),
+                    ("names", vec!["a".to_owned(), "b".to_owned()].into()),
+                ])
+                .into_mock_row(),
+            ),
+        };
+        assert_eq!(
+            row.try_get::<Vec<i32>>("", "integers").unwrap(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(
+            row.try_get::<Vec<String>>("", "names").unwrap(),
+            vec!["a".to_owned(), "b".to_owned()]
+        );
+    }
+
+    #[cfg(feature = "mock")]
+    #[smol_potat::test]
+    async fn column_names_and_values() -> Result<(), DbErr> {
+        use crate::{ConnectionTrait, DbBackend, MockDatabase, Statement};
+        use sea_query::Value;
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![maplit::btreemap! {
+                "name" => Into::<Value>::into("apple"), "id" => Into::<Value>::into(1)
+            }]])
+            .into_connection();
+
+        let row = db
+            .query_one(Statement::from_string(
+                DbBackend::Postgres,
+                r#"SELECT "name", "id" FROM "fruit""#.to_owned(),
+            ))
+            .await?
+            .unwrap();
+
+        assert_eq!(row.column_names(), ["id", "name"]);
+        assert_eq!(
+            row.try_get_values()?,
+            [
+                Value::Int(Some(1)),
+                Value::String(Some(Box::new("apple".to_owned())))
+            ]
+        );
+
+        Ok(())
+    }
 }
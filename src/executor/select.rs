@@ -1,10 +1,10 @@
 use crate::{
-    error::*, ConnectionTrait, EntityTrait, FromQueryResult, IdenStatic, Iterable, ModelTrait,
-    PrimaryKeyToColumn, QueryResult, Select, SelectA, SelectB, SelectTwo, SelectTwoMany, Statement,
-    StreamTrait, TryGetableMany,
+    error::*, ConnectionTrait, DbBackend, EntityTrait, FromQueryResult, IdenStatic, IntoSimpleExpr,
+    Iterable, ModelTrait, PrimaryKeyToColumn, QueryResult, Select, SelectA, SelectB, SelectTwo,
+    SelectTwoMany, Statement, StreamTrait, TryGetableMany,
 };
 use futures::{Stream, TryStreamExt};
-use sea_query::SelectStatement;
+use sea_query::{Alias, Expr, Func, OrderedStatement, SelectStatement};
 use std::marker::PhantomData;
 use std::pin::Pin;
 
@@ -52,6 +52,15 @@ where
     model: PhantomData<T>,
 }
 
+/// Perform an operation on an entity that can yield a tuple of values by column position
+#[derive(Debug)]
+pub struct SelectGetableTuple<T>
+where
+    T: TryGetableMany,
+{
+    model: PhantomData<T>,
+}
+
 /// Defines a type to get a Model
 #[derive(Debug)]
 pub struct SelectModel<M>
@@ -71,6 +80,15 @@ where
     model: PhantomData<(M, N)>,
 }
 
+/// Defines a type to get a Model and the count of its related Entity
+#[derive(Clone, Debug)]
+pub struct SelectModelAndCount<M>
+where
+    M: FromQueryResult,
+{
+    model: PhantomData<M>,
+}
+
 impl<T, C> SelectorTrait for SelectGetableValue<T, C>
 where
     T: TryGetableMany,
@@ -84,6 +102,18 @@ where
     }
 }
 
+impl<T> SelectorTrait for SelectGetableTuple<T>
+where
+    T: TryGetableMany,
+{
+    type Item = T;
+
+    fn from_raw_query_result(res: QueryResult) -> Result<Self::Item, DbErr> {
+        let cols = res.column_names();
+        T::try_get_many(&res, "", &cols).map_err(Into::into)
+    }
+}
+
 impl<M> SelectorTrait for SelectModel<M>
 where
     M: FromQueryResult + Sized,
@@ -110,6 +140,20 @@ where
     }
 }
 
+impl<M> SelectorTrait for SelectModelAndCount<M>
+where
+    M: FromQueryResult + Sized,
+{
+    type Item = (M, i64);
+
+    fn from_raw_query_result(res: QueryResult) -> Result<Self::Item, DbErr> {
+        Ok((
+            M::from_query_result(&res, SelectA.as_str())?,
+            res.try_get(SelectB.as_str(), "count")?,
+        ))
+    }
+}
+
 impl<E> Select<E>
 where
     E: EntityTrait,
@@ -253,6 +297,65 @@ where
         Selector::<SelectGetableValue<T, C>>::with_columns(self.query)
     }
 
+    /// Get the selected columns as a tuple of values, mapped by their position in the result row
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(all(feature = "mock", feature = "macros"))]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results(vec![vec![
+    /// #         maplit::btreemap! {
+    /// #             "id" => Into::<Value>::into(1),
+    /// #             "name" => Into::<Value>::into("Chocolate Forest"),
+    /// #         },
+    /// #     ]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let res: Vec<(i32, String)> = cake::Entity::find()
+    ///     .select_only()
+    ///     .column(cake::Column::Id)
+    ///     .column(cake::Column::Name)
+    ///     .into_tuple()
+    ///     .all(&db)
+    ///     .await?;
+    ///
+    /// assert_eq!(res, vec![(1, "Chocolate Forest".to_owned())]);
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     vec![Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+    ///         vec![]
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_tuple<T>(self) -> Selector<SelectGetableTuple<T>>
+    where
+        T: TryGetableMany,
+    {
+        Selector {
+            query: self.query,
+            selector: SelectGetableTuple { model: PhantomData },
+        }
+    }
+
+    pub(crate) fn into_model_and_count(self) -> Selector<SelectModelAndCount<E::Model>> {
+        Selector {
+            query: self.query,
+            selector: SelectModelAndCount { model: PhantomData },
+        }
+    }
+
     /// Get one Model from the SELECT query
     pub async fn one<'a, C>(self, db: &C) -> Result<Option<E::Model>, DbErr>
     where
@@ -269,7 +372,90 @@ where
         self.into_model().all(db).await
     }
 
+    /// Count the distinct non null values of `col` among the rows of the SELECT query.
+    ///
+    /// Unlike [PaginatorTrait::count](crate::PaginatorTrait::count), which counts the rows,
+    /// a value repeated by a join is only counted once.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results(vec![vec![maplit::btreemap! {
+    /// #         "num_items" => Into::<Value>::into(2i64),
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .inner_join(fruit::Entity)
+    ///         .count_distinct(&db, cake::Column::Id)
+    ///         .await?,
+    ///     2
+    /// );
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     vec![Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         [
+    ///             r#"SELECT COUNT("distinct_value") AS "num_items" FROM"#,
+    ///             r#"(SELECT DISTINCT "cake"."id" AS "distinct_value" FROM "cake""#,
+    ///             r#"INNER JOIN "fruit" ON "cake"."id" = "fruit"."cake_id") AS "sub_query""#,
+    ///         ]
+    ///         .join(" ")
+    ///         .as_str(),
+    ///         vec![]
+    ///     ),]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn count_distinct<C, T>(self, db: &C, col: T) -> Result<u64, DbErr>
+    where
+        C: ConnectionTrait,
+        T: IntoSimpleExpr,
+    {
+        let mut query = self.query;
+        query
+            .clear_selects()
+            .expr_as(col.into_simple_expr(), Alias::new("distinct_value"))
+            .distinct()
+            .clear_order_by()
+            .reset_limit()
+            .reset_offset();
+        let builder = db.get_database_backend();
+        let stmt = builder.build(
+            SelectStatement::new()
+                .expr_as(
+                    Func::count(Expr::col(Alias::new("distinct_value"))),
+                    Alias::new("num_items"),
+                )
+                .from_subquery(query, Alias::new("sub_query")),
+        );
+        let result = match db.query_one(stmt).await? {
+            Some(res) => res,
+            None => return Ok(0),
+        };
+        let num_items = match builder {
+            DbBackend::Postgres => result.try_get::<i64>("", "num_items")? as u64,
+            _ => result.try_get::<i32>("", "num_items")? as u64,
+        };
+        Ok(num_items)
+    }
+
     /// Stream the results of a SELECT operation on a Model
+    ///
+    /// The rows are fetched and converted one by one as the stream is polled, instead of
+    /// being buffered in memory. The stream borrows the connection, or the
+    /// [DatabaseTransaction](crate::DatabaseTransaction), until it is dropped.
     pub async fn stream<'a: 'b, 'b, C>(
         self,
         db: &'a C,
@@ -307,6 +493,17 @@ where
         }
     }
 
+    /// Get the selected columns as a tuple of values, mapped by their position in the result row
+    pub fn into_tuple<T>(self) -> Selector<SelectGetableTuple<T>>
+    where
+        T: TryGetableMany,
+    {
+        Selector {
+            query: self.query,
+            selector: SelectGetableTuple { model: PhantomData },
+        }
+    }
+
     /// Get one Model from the Select query
     pub async fn one<'a, C>(self, db: &C) -> Result<Option<(E::Model, Option<F::Model>)>, DbErr>
     where
@@ -387,15 +584,6 @@ where
         let rows = self.into_model().all(db).await?;
         Ok(consolidate_query_result::<E, F>(rows))
     }
-
-    // pub fn paginate()
-    // we could not implement paginate easily, if the number of children for a
-    // parent is larger than one page, then we will end up splitting it in two pages
-    // so the correct way is actually perform query in two stages
-    // paginate the parent model and then populate the children
-
-    // pub fn count()
-    // we should only count the number of items of the parent model
 }
 
 impl<S> Selector<S>
@@ -785,3 +973,316 @@ where
     }
     acc
 }
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{
+        entity::prelude::*, tests_cfg::*, ConnectionTrait, DbBackend, MockDatabase, QuerySelect,
+        QueryTrait, Transaction,
+    };
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+
+    #[smol_potat::test]
+    async fn into_tuple() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![
+                vec![
+                    maplit::btreemap! {
+                        "id" => Into::<Value>::into(1),
+                        "name" => Into::<Value>::into("Chocolate Forest"),
+                    },
+                    maplit::btreemap! {
+                        "id" => Into::<Value>::into(2),
+                        "name" => Into::<Value>::into("New York Cheese"),
+                    },
+                ],
+                vec![maplit::btreemap! {
+                    "id" => Into::<Value>::into(1),
+                    "name" => Into::<Value>::into("Chocolate Forest"),
+                }],
+            ])
+            .into_connection();
+
+        let res: Vec<(i32, String)> = cake::Entity::find()
+            .select_only()
+            .column(cake::Column::Id)
+            .column(cake::Column::Name)
+            .into_tuple()
+            .all(&db)
+            .await?;
+
+        assert_eq!(
+            res,
+            vec![
+                (1, "Chocolate Forest".to_owned()),
+                (2, "New York Cheese".to_owned()),
+            ]
+        );
+
+        let res: Vec<(i32, String)> = cake::Entity::find()
+            .select_only()
+            .column(cake::Column::Id)
+            .column(cake::Column::Name)
+            .into_tuple()
+            .stream(&db)
+            .await?
+            .try_collect()
+            .await?;
+
+        assert_eq!(res, vec![(1, "Chocolate Forest".to_owned())]);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                    vec![]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                    vec![]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn column_as_aggregates() -> Result<(), DbErr> {
+        use crate as sea_orm;
+        use crate::{sea_query::Expr, FromQueryResult};
+
+        #[derive(Debug, PartialEq, FromQueryResult)]
+        struct Stats {
+            cnt: i64,
+            max_id: i32,
+        }
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![
+                vec![maplit::btreemap! {
+                    "cnt" => Into::<Value>::into(3i64),
+                    "max_id" => Into::<Value>::into(5),
+                }],
+                vec![maplit::btreemap! {
+                    "cnt" => Into::<Value>::into(3i64),
+                    "max_id" => Into::<Value>::into(5),
+                }],
+            ])
+            .into_connection();
+
+        let select = cake::Entity::find()
+            .select_only()
+            .column_as(Expr::asterisk().count(), "cnt")
+            .column_as(cake::Column::Id.max(), "max_id");
+
+        let res = db
+            .query_one(select.build(DbBackend::Postgres))
+            .await?
+            .unwrap();
+        assert_eq!(res.try_get::<i64>("", "cnt")?, 3);
+        assert_eq!(res.try_get::<i32>("", "max_id")?, 5);
+
+        let res = select.into_model::<Stats>().one(&db).await?;
+        assert_eq!(res, Some(Stats { cnt: 3, max_id: 5 }));
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT COUNT(*) AS "cnt", MAX("cake"."id") AS "max_id" FROM "cake""#,
+                    vec![]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT COUNT(*) AS "cnt", MAX("cake"."id") AS "max_id" FROM "cake" LIMIT $1"#,
+                    vec![1u64.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn into_tuple_select_two() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![maplit::btreemap! {
+                "cake_name" => Into::<Value>::into("Chocolate Forest"),
+                "fruit_name" => Into::<Value>::into("Apple"),
+            }]])
+            .into_connection();
+
+        let res: Option<(String, String)> = cake::Entity::find()
+            .find_also_related(fruit::Entity)
+            .select_only()
+            .column_as(cake::Column::Name, "cake_name")
+            .column_as(fruit::Column::Name, "fruit_name")
+            .into_tuple()
+            .one(&db)
+            .await?;
+
+        assert_eq!(
+            res,
+            Some(("Chocolate Forest".to_owned(), "Apple".to_owned()))
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "cake"."name" AS "cake_name", "fruit"."name" AS "fruit_name""#,
+                    r#"FROM "cake" LEFT JOIN "fruit" ON "cake"."id" = "fruit"."cake_id" LIMIT $1"#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec![1u64.into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn into_model_nested() -> Result<(), DbErr> {
+        use crate as sea_orm;
+        use crate::FromQueryResult;
+
+        #[derive(Debug, PartialEq, FromQueryResult)]
+        struct CakeWithFruit {
+            #[sea_orm(nested)]
+            cake: cake::Model,
+            #[sea_orm(nested, prefix = "f_")]
+            fruit: Option<fruit::Model>,
+            #[sea_orm(skip)]
+            note: String,
+        }
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                maplit::btreemap! {
+                    "cake_id" => Into::<Value>::into(1),
+                    "cake_name" => Into::<Value>::into("Chocolate Forest"),
+                    "f_id" => Into::<Value>::into(2),
+                    "f_name" => Into::<Value>::into("Apple"),
+                    "f_cake_id" => Into::<Value>::into(Some(1)),
+                },
+                maplit::btreemap! {
+                    "cake_id" => Into::<Value>::into(3),
+                    "cake_name" => Into::<Value>::into("New York Cheese"),
+                    "f_id" => Into::<Value>::into(Option::<i32>::None),
+                    "f_name" => Into::<Value>::into(Option::<String>::None),
+                    "f_cake_id" => Into::<Value>::into(Option::<i32>::None),
+                },
+            ]])
+            .into_connection();
+
+        // both models have `id` and `name` columns, told apart by the prefix
+        let res = cake::Entity::find()
+            .select_only()
+            .column_as(cake::Column::Id, "cake_id")
+            .column_as(cake::Column::Name, "cake_name")
+            .left_join(fruit::Entity)
+            .column_as(fruit::Column::Id, "f_id")
+            .column_as(fruit::Column::Name, "f_name")
+            .column_as(fruit::Column::CakeId, "f_cake_id")
+            .into_model::<CakeWithFruit>()
+            .all(&db)
+            .await?;
+
+        assert_eq!(
+            res,
+            vec![
+                CakeWithFruit {
+                    cake: cake::Model {
+                        id: 1,
+                        name: "Chocolate Forest".to_owned(),
+                    },
+                    fruit: Some(fruit::Model {
+                        id: 2,
+                        name: "Apple".to_owned(),
+                        cake_id: Some(1),
+                    }),
+                    note: String::new(),
+                },
+                CakeWithFruit {
+                    cake: cake::Model {
+                        id: 3,
+                        name: "New York Cheese".to_owned(),
+                    },
+                    fruit: None,
+                    note: String::new(),
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn find_with_related_count() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                maplit::btreemap! {
+                    "A_id" => Into::<Value>::into(1),
+                    "A_name" => Into::<Value>::into("Chocolate Forest"),
+                    "B_count" => Into::<Value>::into(2i64),
+                },
+                maplit::btreemap! {
+                    "A_id" => Into::<Value>::into(2),
+                    "A_name" => Into::<Value>::into("New York Cheese"),
+                    "B_count" => Into::<Value>::into(0i64),
+                },
+            ]])
+            .into_connection();
+
+        let res = cake::Entity::find()
+            .find_with_related_count(fruit::Entity)
+            .all(&db)
+            .await?;
+
+        assert_eq!(
+            res,
+            vec![
+                (
+                    cake::Model {
+                        id: 1,
+                        name: "Chocolate Forest".to_owned(),
+                    },
+                    2
+                ),
+                (
+                    cake::Model {
+                        id: 2,
+                        name: "New York Cheese".to_owned(),
+                    },
+                    0
+                ),
+            ]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "cake"."id" AS "A_id", "cake"."name" AS "A_name", COUNT("fruit"."id") AS "B_count""#,
+                    r#"FROM "cake" LEFT JOIN "fruit" ON "cake"."id" = "fruit"."cake_id""#,
+                    r#"GROUP BY "cake"."id", "cake"."name""#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec![]
+            )]
+        );
+
+        Ok(())
+    }
+}
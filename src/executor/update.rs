@@ -1,6 +1,9 @@
 use crate::{
-    error::*, ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, IntoActiveModel,
-    Iterable, SelectModel, SelectorRaw, Statement, UpdateMany, UpdateOne,
+    error::*,
+    executor::insert::{query_returning, returning_all_columns},
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, FromQueryResult, IntoActiveModel,
+    Iterable, SelectGetableTuple, SelectModel, SelectorRaw, SelectorTrait, Statement,
+    TryGetableMany, UpdateMany, UpdateOne,
 };
 use sea_query::{Alias, Expr, FromValueTuple, Query, UpdateStatement};
 use std::future::Future;
@@ -46,6 +49,57 @@ where
         // so that self is dropped before entering await
         exec_update_only(self.query, db)
     }
+
+    /// Execute an update operation on multiple ActiveModels and return the updated models.
+    ///
+    /// This requires `RETURNING` syntax, which is only used on Postgres. Other backends
+    /// return a [DbErr::Exec], as the updated rows cannot be re-selected reliably.
+    pub fn exec_with_returning<C>(
+        self,
+        db: &'a C,
+    ) -> impl Future<Output = Result<Vec<E::Model>, DbErr>> + 'a
+    where
+        C: ConnectionTrait,
+    {
+        // so that self is dropped before entering await
+        exec_update_with_returning::<E, _>(self.query, db)
+    }
+
+    /// Execute an update operation and read the columns set by [UpdateMany::returning], or all
+    /// columns if there are none, of every updated row into a partial model.
+    ///
+    /// This requires `RETURNING` syntax, which is only used on Postgres. Other backends
+    /// return a [DbErr::Exec].
+    pub async fn exec_with_returning_as<M, C>(self, db: &C) -> Result<Vec<M>, DbErr>
+    where
+        M: FromQueryResult,
+        C: ConnectionTrait,
+    {
+        self.exec_returning::<SelectModel<M>, _>(db).await
+    }
+
+    /// Execute an update operation and read the columns set by [UpdateMany::returning], or all
+    /// columns if there are none, of every updated row into a tuple, by column position.
+    ///
+    /// This requires `RETURNING` syntax, which is only used on Postgres. Other backends
+    /// return a [DbErr::Exec].
+    pub async fn exec_with_returning_tuple<T, C>(self, db: &C) -> Result<Vec<T>, DbErr>
+    where
+        T: TryGetableMany,
+        C: ConnectionTrait,
+    {
+        self.exec_returning::<SelectGetableTuple<T>, _>(db).await
+    }
+
+    async fn exec_returning<S, C>(self, db: &C) -> Result<Vec<S::Item>, DbErr>
+    where
+        S: SelectorTrait,
+        C: ConnectionTrait,
+    {
+        let mut query = self.query;
+        query.returning(self.returning.unwrap_or_else(returning_all_columns::<E>));
+        query_returning::<S, _>(db.get_database_backend().build(&query), db).await
+    }
 }
 
 impl Updater {
@@ -80,6 +134,26 @@ where
     Updater::new(query).exec(db).await
 }
 
+pub(crate) async fn exec_update_with_returning<E, C>(
+    mut query: UpdateStatement,
+    db: &C,
+) -> Result<Vec<E::Model>, DbErr>
+where
+    E: EntityTrait,
+    C: ConnectionTrait,
+{
+    if !db.support_returning() {
+        return Err(DbErr::Exec(
+            "`RETURNING` is not supported by the database backend".to_owned(),
+        ));
+    }
+    let db_backend = db.get_database_backend();
+    query.returning(returning_all_columns::<E>());
+    SelectorRaw::<SelectModel<E::Model>>::from_statement(db_backend.build(&query))
+        .all(db)
+        .await
+}
+
 async fn exec_update_and_return_updated<A, C>(
     mut query: UpdateStatement,
     model: A,
@@ -112,14 +186,15 @@ where
             // If we got `None` then we are updating a row that does not exist.
             match found {
                 Some(model) => Ok(model),
-                None => Err(DbErr::RecordNotFound(
-                    "None of the database rows are affected".to_owned(),
-                )),
+                None => Err(err_record_not_updated::<A::Entity>()),
             }
         }
         false => {
             // If we updating a row that does not exist then an error will be thrown here.
-            Updater::new(query).check_record_exists().exec(db).await?;
+            let res = Updater::new(query).exec(db).await?;
+            if res.rows_affected == 0 {
+                return Err(err_record_not_updated::<A::Entity>());
+            }
             let primary_key_value = match model.get_primary_key_value() {
                 Some(val) => FromValueTuple::from_value_tuple(val),
                 None => return Err(DbErr::Exec("Fail to get primary key from model".to_owned())),
@@ -136,6 +211,20 @@ where
     }
 }
 
+fn err_record_not_updated<E>() -> DbErr
+where
+    E: EntityTrait,
+{
+    match E::version_column() {
+        // The row may exist, but its version no longer matches
+        Some(_) => DbErr::RecordNotUpdated(
+            "None of the database rows are affected, the record is either missing or stale"
+                .to_owned(),
+        ),
+        None => DbErr::RecordNotFound("None of the database rows are affected".to_owned()),
+    }
+}
+
 async fn exec_update<C>(
     statement: Statement,
     db: &C,
@@ -280,4 +369,224 @@ mod tests {
 
         Ok(())
     }
+
+    #[smol_potat::test]
+    async fn update_many_exec_with_returning() -> Result<(), DbErr> {
+        let update = || {
+            cake::Entity::update_many()
+                .col_expr(cake::Column::Name, Expr::value("Cheese Cake".to_owned()))
+                .filter(cake::Column::Id.gt(1))
+        };
+        let models = vec![
+            cake::Model {
+                id: 2,
+                name: "Cheese Cake".to_owned(),
+            },
+            cake::Model {
+                id: 3,
+                name: "Cheese Cake".to_owned(),
+            },
+        ];
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![models.clone()])
+            .into_connection();
+
+        assert_eq!(update().exec_with_returning(&db).await?, models);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"UPDATE "cake" SET "name" = $1 WHERE "cake"."id" > $2 RETURNING "id", "name""#,
+                vec!["Cheese Cake".into(), 1i32.into()]
+            )]
+        );
+
+        let db = MockDatabase::new(DbBackend::MySql).into_connection();
+
+        assert_eq!(
+            update().exec_with_returning(&db).await.err(),
+            Some(DbErr::Exec(
+                "`RETURNING` is not supported by the database backend".to_owned()
+            ))
+        );
+        assert_eq!(db.into_transaction_log(), vec![]);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn update_many_exec_with_returning_tuple() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                maplit::btreemap! { "id" => Into::<Value>::into(2) },
+                maplit::btreemap! { "id" => Into::<Value>::into(3) },
+            ]])
+            .into_connection();
+
+        assert_eq!(
+            cake::Entity::update_many()
+                .col_expr(cake::Column::Name, Expr::value("Cheese Cake".to_owned()))
+                .filter(cake::Column::Id.gt(1))
+                .returning([cake::Column::Id])
+                .exec_with_returning_tuple::<i32, _>(&db)
+                .await?,
+            [2, 3]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"UPDATE "cake" SET "name" = $1 WHERE "cake"."id" > $2 RETURNING "id""#,
+                vec!["Cheese Cake".into(), 1i32.into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    mod versioned_cake {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "cake")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub name: String,
+            #[sea_orm(version)]
+            pub version: i32,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[smol_potat::test]
+    async fn update_with_version() -> Result<(), DbErr> {
+        let updated = versioned_cake::Model {
+            id: 1,
+            name: "Cheese Cake".to_owned(),
+            version: 3,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![updated.clone()], vec![]])
+            .into_connection();
+
+        let update = || versioned_cake::ActiveModel {
+            id: Set(1),
+            name: Set("Cheese Cake".to_owned()),
+            version: Set(2),
+        };
+
+        assert_eq!(update().update(&db).await?, updated);
+        assert_eq!(
+            update().update(&db).await,
+            Err(DbErr::RecordNotUpdated(
+                "None of the database rows are affected, the record is either missing or stale"
+                    .to_owned()
+            ))
+        );
+
+        let sql = r#"UPDATE "cake" SET "name" = $1, "version" = "version" + $2 WHERE "cake"."id" = $3 AND "cake"."version" = $4 RETURNING "id", "name", "version""#;
+        let values = vec!["Cheese Cake".into(), 1i32.into(), 1i32.into(), 2i32.into()];
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::from_sql_and_values(DbBackend::Postgres, sql, values.clone()),
+                Transaction::from_sql_and_values(DbBackend::Postgres, sql, values),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn update_with_stale_version() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 0,
+            }])
+            .into_connection();
+
+        assert_eq!(
+            versioned_cake::ActiveModel {
+                id: Set(1),
+                name: Set("Cheese Cake".to_owned()),
+                version: Unchanged(2),
+            }
+            .update(&db)
+            .await,
+            Err(DbErr::RecordNotUpdated(
+                "None of the database rows are affected, the record is either missing or stale"
+                    .to_owned()
+            ))
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::MySql,
+                r#"UPDATE `cake` SET `name` = ?, `version` = `version` + ? WHERE `cake`.`id` = ? AND `cake`.`version` = ?"#,
+                vec!["Cheese Cake".into(), 1i32.into(), 1i32.into(), 2i32.into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn update_many_set_many() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 2,
+            }])
+            .into_connection();
+
+        let res = cake::Entity::update_many()
+            .set_many([
+                cake::ActiveModel {
+                    id: Set(1),
+                    name: Set("Cheese Cake".to_owned()),
+                },
+                cake::ActiveModel {
+                    id: Set(2),
+                    name: Set("Chocolate Cake".to_owned()),
+                },
+            ])?
+            .exec(&db)
+            .await?;
+        assert_eq!(res.rows_affected, 2);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"UPDATE "cake" SET "name" = (CASE WHEN ("cake"."id" = $1) THEN $2 WHEN ("cake"."id" = $3) THEN $4 ELSE "name" END)"#,
+                    r#"WHERE "cake"."id" IN ($5, $6)"#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec![
+                    1i32.into(),
+                    "Cheese Cake".into(),
+                    2i32.into(),
+                    "Chocolate Cake".into(),
+                    1i32.into(),
+                    2i32.into(),
+                ]
+            )]
+        );
+
+        Ok(())
+    }
 }
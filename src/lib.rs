@@ -318,6 +318,8 @@ pub mod error;
 mod executor;
 /// Holds types and methods to perform metric collection
 pub mod metric;
+/// Lightweight migrations, recorded in the `seaql_migrations` table
+pub mod migration;
 /// Holds types and methods to perform queries
 pub mod query;
 /// Holds types that defines the schemas of an Entity
@@ -338,9 +340,9 @@ pub use schema::*;
 #[cfg(feature = "macros")]
 pub use sea_orm_macros::{
     DeriveActiveEnum, DeriveActiveModel, DeriveActiveModelBehavior, DeriveColumn,
-    DeriveCustomColumn, DeriveEntity, DeriveEntityModel, DeriveIntoActiveModel,
-    DeriveMigrationName, DeriveModel, DerivePrimaryKey, DeriveRelation, FromJsonQueryResult,
-    FromQueryResult,
+    DeriveCoreMigrationName, DeriveCustomColumn, DeriveEntity, DeriveEntityModel,
+    DeriveIntoActiveModel, DeriveMigrationName, DeriveModel, DerivePrimaryKey, DeriveRelation,
+    DeriveValueType, FromJsonQueryResult, FromQueryResult,
 };
 
 pub use sea_query;
@@ -350,3 +352,7 @@ pub use sea_query::Iden as DeriveIden;
 
 pub use strum;
 pub use strum::EnumIter;
+
+#[cfg(feature = "with-json")]
+#[doc(hidden)]
+pub use serde;
@@ -0,0 +1,162 @@
+use crate::{
+    ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbBackend, DbErr, ExecResult,
+    QueryResult, Statement, StatementBuilder,
+};
+use sea_query::{
+    ForeignKeyCreateStatement, ForeignKeyDropStatement, IndexCreateStatement, IndexDropStatement,
+    TableAlterStatement, TableCreateStatement, TableDropStatement, TableRenameStatement,
+    TableTruncateStatement,
+};
+
+/// The handle passed to a [MigrationTrait](super::MigrationTrait) to change the schema
+#[derive(Debug)]
+pub struct SchemaManager<'c> {
+    conn: SchemaManagerConnection<'c>,
+}
+
+/// The connection a [SchemaManager] executes statements on, the transaction of the migration
+/// on databases supporting transactional DDL
+#[derive(Debug)]
+pub enum SchemaManagerConnection<'c> {
+    /// A database connection
+    Connection(&'c DatabaseConnection),
+    /// A transaction
+    Transaction(&'c DatabaseTransaction),
+}
+
+impl<'c> From<&'c DatabaseConnection> for SchemaManagerConnection<'c> {
+    fn from(conn: &'c DatabaseConnection) -> Self {
+        Self::Connection(conn)
+    }
+}
+
+impl<'c> From<&'c DatabaseTransaction> for SchemaManagerConnection<'c> {
+    fn from(txn: &'c DatabaseTransaction) -> Self {
+        Self::Transaction(txn)
+    }
+}
+
+#[async_trait::async_trait]
+impl<'c> ConnectionTrait for SchemaManagerConnection<'c> {
+    fn get_database_backend(&self) -> DbBackend {
+        match self {
+            Self::Connection(conn) => conn.get_database_backend(),
+            Self::Transaction(txn) => txn.get_database_backend(),
+        }
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        match self {
+            Self::Connection(conn) => conn.execute(stmt).await,
+            Self::Transaction(txn) => txn.execute(stmt).await,
+        }
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        match self {
+            Self::Connection(conn) => conn.execute_unprepared(sql).await,
+            Self::Transaction(txn) => txn.execute_unprepared(sql).await,
+        }
+    }
+
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        match self {
+            Self::Connection(conn) => conn.query_one(stmt).await,
+            Self::Transaction(txn) => txn.query_one(stmt).await,
+        }
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        match self {
+            Self::Connection(conn) => conn.query_all(stmt).await,
+            Self::Transaction(txn) => txn.query_all(stmt).await,
+        }
+    }
+
+    fn is_mock_connection(&self) -> bool {
+        match self {
+            Self::Connection(conn) => conn.is_mock_connection(),
+            Self::Transaction(txn) => txn.is_mock_connection(),
+        }
+    }
+}
+
+impl<'c> SchemaManager<'c> {
+    /// Create a schema manager executing statements on the given connection or transaction
+    pub fn new<C>(conn: C) -> Self
+    where
+        C: Into<SchemaManagerConnection<'c>>,
+    {
+        Self { conn: conn.into() }
+    }
+
+    /// Build and execute a statement
+    pub async fn exec_stmt<S>(&self, stmt: S) -> Result<(), DbErr>
+    where
+        S: StatementBuilder,
+    {
+        let builder = self.conn.get_database_backend();
+        self.conn.execute(builder.build(&stmt)).await.map(|_| ())
+    }
+
+    /// Get the database backend of the connection
+    pub fn get_database_backend(&self) -> DbBackend {
+        self.conn.get_database_backend()
+    }
+
+    /// Get the connection, to execute statements other than schema changes
+    pub fn get_connection(&self) -> &SchemaManagerConnection<'c> {
+        &self.conn
+    }
+}
+
+/// Schema Creation
+impl<'c> SchemaManager<'c> {
+    /// Create a table
+    pub async fn create_table(&self, stmt: TableCreateStatement) -> Result<(), DbErr> {
+        self.exec_stmt(stmt).await
+    }
+
+    /// Create an index
+    pub async fn create_index(&self, stmt: IndexCreateStatement) -> Result<(), DbErr> {
+        self.exec_stmt(stmt).await
+    }
+
+    /// Create a foreign key
+    pub async fn create_foreign_key(&self, stmt: ForeignKeyCreateStatement) -> Result<(), DbErr> {
+        self.exec_stmt(stmt).await
+    }
+}
+
+/// Schema Mutation
+impl<'c> SchemaManager<'c> {
+    /// Alter a table
+    pub async fn alter_table(&self, stmt: TableAlterStatement) -> Result<(), DbErr> {
+        self.exec_stmt(stmt).await
+    }
+
+    /// Drop a table
+    pub async fn drop_table(&self, stmt: TableDropStatement) -> Result<(), DbErr> {
+        self.exec_stmt(stmt).await
+    }
+
+    /// Rename a table
+    pub async fn rename_table(&self, stmt: TableRenameStatement) -> Result<(), DbErr> {
+        self.exec_stmt(stmt).await
+    }
+
+    /// Truncate a table
+    pub async fn truncate_table(&self, stmt: TableTruncateStatement) -> Result<(), DbErr> {
+        self.exec_stmt(stmt).await
+    }
+
+    /// Drop an index
+    pub async fn drop_index(&self, stmt: IndexDropStatement) -> Result<(), DbErr> {
+        self.exec_stmt(stmt).await
+    }
+
+    /// Drop a foreign key
+    pub async fn drop_foreign_key(&self, stmt: ForeignKeyDropStatement) -> Result<(), DbErr> {
+        self.exec_stmt(stmt).await
+    }
+}
@@ -0,0 +1,190 @@
+use super::{MigrationTrait, SchemaManager};
+use crate::{ConnectionTrait, DatabaseConnection, DbBackend, DbErr, TransactionTrait};
+use sea_query::{Alias, ColumnDef, Expr, Order, Query, Table};
+use std::{
+    collections::HashSet,
+    fmt,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// The table recording the applied migrations, the same as the one of `sea-orm-migration`
+pub const MIGRATION_TABLE: &str = "seaql_migrations";
+
+/// Applies and reverts a list of migrations, recording the applied ones in [MIGRATION_TABLE]
+///
+/// Migrations are applied in the order of the list and reverted in the reverse order. A
+/// migration already recorded as applied is skipped, so [Migrator::up] can run on every start.
+///
+/// On Postgres and SQLite, each migration runs in a transaction together with its change to
+/// [MIGRATION_TABLE], so a failing migration leaves neither the schema nor the record behind.
+/// MySQL commits DDL statements implicitly, a migration failing there can be left half applied.
+pub struct Migrator {
+    migrations: Vec<Box<dyn MigrationTrait>>,
+}
+
+impl fmt::Debug for Migrator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Migrator")
+            .field(
+                "migrations",
+                &self
+                    .migrations
+                    .iter()
+                    .map(|migration| migration.name())
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl Migrator {
+    /// Create a migrator for the given migrations, in time sequence
+    pub fn new(migrations: Vec<Box<dyn MigrationTrait>>) -> Self {
+        Self { migrations }
+    }
+
+    /// Create the [MIGRATION_TABLE] if it does not exist
+    pub async fn install(&self, db: &DatabaseConnection) -> Result<(), DbErr> {
+        let stmt = Table::create()
+            .table(Alias::new(MIGRATION_TABLE))
+            .if_not_exists()
+            .col(
+                ColumnDef::new(Alias::new("version"))
+                    .string()
+                    .not_null()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(Alias::new("applied_at"))
+                    .big_integer()
+                    .not_null(),
+            )
+            .to_owned();
+        SchemaManager::new(db).create_table(stmt).await
+    }
+
+    /// Get the names of the applied migrations, ordered by name
+    pub async fn get_applied_migrations(
+        &self,
+        db: &DatabaseConnection,
+    ) -> Result<Vec<String>, DbErr> {
+        self.install(db).await?;
+        let stmt = Query::select()
+            .column(Alias::new("version"))
+            .from(Alias::new(MIGRATION_TABLE))
+            .order_by(Alias::new("version"), Order::Asc)
+            .to_owned();
+        let builder = db.get_database_backend();
+        db.query_all(builder.build(&stmt))
+            .await?
+            .iter()
+            .map(|row| row.try_get("", "version"))
+            .collect()
+    }
+
+    /// Get the names of the migrations not applied yet, in the order they would be applied
+    pub async fn get_pending_migrations(
+        &self,
+        db: &DatabaseConnection,
+    ) -> Result<Vec<String>, DbErr> {
+        let applied = self.applied_set(db).await?;
+        Ok(self
+            .migrations
+            .iter()
+            .map(|migration| migration.name())
+            .filter(|name| !applied.contains(*name))
+            .map(ToOwned::to_owned)
+            .collect())
+    }
+
+    /// Apply the pending migrations, or only the first `steps` of them
+    pub async fn up(&self, db: &DatabaseConnection, steps: Option<u32>) -> Result<(), DbErr> {
+        let applied = self.applied_set(db).await?;
+        let pending = self
+            .migrations
+            .iter()
+            .filter(|migration| !applied.contains(migration.name()));
+        for migration in pending.take(steps.map_or(usize::MAX, |steps| steps as usize)) {
+            if is_transactional(db) {
+                let txn = db.begin().await?;
+                apply(&SchemaManager::new(&txn), migration.as_ref()).await?;
+                txn.commit().await?;
+            } else {
+                apply(&SchemaManager::new(db), migration.as_ref()).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Revert the applied migrations, or only the last `steps` of them
+    pub async fn down(&self, db: &DatabaseConnection, steps: Option<u32>) -> Result<(), DbErr> {
+        let applied = self.applied_set(db).await?;
+        let applied = self
+            .migrations
+            .iter()
+            .rev()
+            .filter(|migration| applied.contains(migration.name()));
+        for migration in applied.take(steps.map_or(usize::MAX, |steps| steps as usize)) {
+            if is_transactional(db) {
+                let txn = db.begin().await?;
+                revert(&SchemaManager::new(&txn), migration.as_ref()).await?;
+                txn.commit().await?;
+            } else {
+                revert(&SchemaManager::new(db), migration.as_ref()).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The names of the applied migrations, which must all be known to this migrator
+    async fn applied_set(&self, db: &DatabaseConnection) -> Result<HashSet<String>, DbErr> {
+        let applied: HashSet<String> = self.get_applied_migrations(db).await?.into_iter().collect();
+        let known: HashSet<&str> = self
+            .migrations
+            .iter()
+            .map(|migration| migration.name())
+            .collect();
+        let mut missing: Vec<&String> = applied
+            .iter()
+            .filter(|name| !known.contains(name.as_str()))
+            .collect();
+        if missing.is_empty() {
+            return Ok(applied);
+        }
+        missing.sort();
+        Err(DbErr::Migration(format!(
+            "Migrations {:?} have been applied but are missing",
+            missing
+        )))
+    }
+}
+
+/// Whether the database runs DDL statements in transactions, MySQL commits them implicitly
+fn is_transactional(db: &DatabaseConnection) -> bool {
+    db.get_database_backend() != DbBackend::MySql
+}
+
+/// Apply a migration and record it in the [MIGRATION_TABLE]
+async fn apply(manager: &SchemaManager<'_>, migration: &dyn MigrationTrait) -> Result<(), DbErr> {
+    migration.up(manager).await?;
+    let applied_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("SystemTime before UNIX EPOCH!")
+        .as_secs() as i64;
+    let stmt = Query::insert()
+        .into_table(Alias::new(MIGRATION_TABLE))
+        .columns([Alias::new("version"), Alias::new("applied_at")])
+        .values_panic([migration.name().into(), applied_at.into()])
+        .to_owned();
+    manager.exec_stmt(stmt).await
+}
+
+/// Revert a migration and remove it from the [MIGRATION_TABLE]
+async fn revert(manager: &SchemaManager<'_>, migration: &dyn MigrationTrait) -> Result<(), DbErr> {
+    migration.down(manager).await?;
+    let stmt = Query::delete()
+        .from_table(Alias::new(MIGRATION_TABLE))
+        .and_where(Expr::col(Alias::new("version")).eq(migration.name()))
+        .to_owned();
+    manager.exec_stmt(stmt).await
+}
@@ -0,0 +1,68 @@
+use crate::DbErr;
+
+mod manager;
+mod migrator;
+
+pub use manager::*;
+pub use migrator::*;
+
+/// The name of a migration, recorded in the `seaql_migrations` table once it is applied.
+///
+/// It can be derived with [DeriveCoreMigrationName](crate::DeriveCoreMigrationName), which names
+/// a migration after the module defining it.
+pub trait MigrationName {
+    /// The unique name of the migration, e.g. `m20220101_000001_create_post_table`
+    fn name(&self) -> &str;
+}
+
+/// A migration, applied and reverted by a [Migrator]
+///
+/// This is a lightweight alternative to the `sea-orm-migration` crate, without its command line
+/// interface and schema inspection.
+///
+/// ```
+/// use sea_orm::migration::{MigrationName, MigrationTrait, SchemaManager};
+/// use sea_orm::sea_query::{Alias, ColumnDef, Table};
+/// use sea_orm::DbErr;
+///
+/// struct Migration;
+///
+/// impl MigrationName for Migration {
+///     fn name(&self) -> &str {
+///         "m20220101_000001_create_post_table"
+///     }
+/// }
+///
+/// #[async_trait::async_trait]
+/// impl MigrationTrait for Migration {
+///     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+///         manager
+///             .create_table(
+///                 Table::create()
+///                     .table(Alias::new("post"))
+///                     .col(ColumnDef::new(Alias::new("id")).integer().primary_key())
+///                     .to_owned(),
+///             )
+///             .await
+///     }
+///
+///     async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+///         manager
+///             .drop_table(Table::drop().table(Alias::new("post")).to_owned())
+///             .await
+///     }
+/// }
+/// ```
+#[async_trait::async_trait]
+pub trait MigrationTrait: MigrationName + Send + Sync {
+    /// Define actions to perform when applying the migration
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr>;
+
+    /// Define actions to perform when reverting the migration
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Err(DbErr::Migration(format!(
+            "Migration '{}' cannot be reverted",
+            self.name()
+        )))
+    }
+}
@@ -5,7 +5,8 @@ use crate::{
 use core::marker::PhantomData;
 pub use sea_query::JoinType;
 use sea_query::{
-    Alias, ColumnRef, DynIden, Expr, Iden, Order, SeaRc, SelectExpr, SelectStatement, SimpleExpr,
+    Alias, ColumnRef, DynIden, Expr, Iden, IntoIden, Order, SeaRc, SelectExpr, SelectStatement,
+    SimpleExpr,
 };
 
 macro_rules! select_def {
@@ -84,6 +85,19 @@ where
         SelectTwo::new(self.into_query())
     }
 
+    /// Selects an Entity joined under a table alias, e.g. by [QuerySelect::join_as](crate::QuerySelect::join_as),
+    /// and returns it together with the Entity from `Self`
+    pub fn select_also_as<F, I>(mut self, _: F, alias: I) -> SelectTwo<E, F>
+    where
+        F: EntityTrait,
+        I: IntoIden,
+    {
+        self = self.apply_alias(SelectA.as_str());
+        let mut select_two = SelectTwo::new_without_prepare(self.into_query());
+        prepare_select_two::<F, _>(&mut select_two, Some(alias.into_iden()));
+        select_two
+    }
+
     /// Makes a SELECT operation in conjunction to another relation
     pub fn select_with<F>(mut self, _: F) -> SelectTwoMany<E, F>
     where
@@ -111,7 +125,7 @@ where
     }
 
     fn prepare_select(mut self) -> Self {
-        prepare_select_two::<F, Self>(&mut self);
+        prepare_select_two::<F, Self>(&mut self, None);
         self
     }
 }
@@ -131,7 +145,7 @@ where
     }
 
     fn prepare_select(mut self) -> Self {
-        prepare_select_two::<F, Self>(&mut self);
+        prepare_select_two::<F, Self>(&mut self, None);
         self
     }
 
@@ -143,7 +157,8 @@ where
     }
 }
 
-fn prepare_select_two<F, S>(selector: &mut S)
+/// Select the columns of `F` as `B_` prefixed, from the table aliased as `tbl` if any
+pub(crate) fn prepare_select_two<F, S>(selector: &mut S, tbl: Option<DynIden>)
 where
     F: EntityTrait,
     S: QueryTrait<QueryStatement = SelectStatement>,
@@ -153,7 +168,10 @@ where
         let col_def = col.def();
         let col_type = col_def.get_column_type();
         let alias = format!("{}{}", SelectB.as_str(), col.as_str());
-        let expr = Expr::expr(col.into_simple_expr());
+        let expr = match &tbl {
+            Some(tbl) => Expr::tbl(SeaRc::clone(tbl), col.into_iden()),
+            None => Expr::expr(col.into_simple_expr()),
+        };
         let expr = match col_type.get_enum_name() {
             Some(_) => expr.as_enum(text_type.clone()),
             None => expr.into(),
@@ -169,7 +187,10 @@ where
 #[cfg(test)]
 mod tests {
     use crate::tests_cfg::{cake, fruit};
-    use crate::{ColumnTrait, DbBackend, EntityTrait, QueryFilter, QuerySelect, QueryTrait};
+    use crate::{
+        ColumnTrait, DbBackend, EntityTrait, QueryFilter, QuerySelect, QueryTrait, RelationTrait,
+    };
+    use sea_query::{Alias, JoinType};
 
     #[test]
     fn alias_1() {
@@ -254,4 +275,27 @@ mod tests {
             ].join(" ")
         );
     }
+
+    #[test]
+    fn select_also_as_1() {
+        assert_eq!(
+            cake::Entity::find()
+                .join_as(
+                    JoinType::LeftJoin,
+                    cake::Relation::Fruit.def(),
+                    Alias::new("f")
+                )
+                .select_also_as(fruit::Entity, Alias::new("f"))
+                .filter(fruit::Column::Id.with_table_alias(Alias::new("f")).eq(2))
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id` AS `A_id`, `cake`.`name` AS `A_name`,",
+                "`f`.`id` AS `B_id`, `f`.`name` AS `B_name`, `f`.`cake_id` AS `B_cake_id`",
+                "FROM `cake` LEFT JOIN `fruit` AS `f` ON `cake`.`id` = `f`.`cake_id`",
+                "WHERE `f`.`id` = 2",
+            ]
+            .join(" ")
+        );
+    }
 }
@@ -1,9 +1,11 @@
 use crate::{
-    ActiveModelTrait, ColumnTrait, EntityTrait, IntoActiveModel, Iterable, PrimaryKeyToColumn,
-    QueryFilter, QueryTrait,
+    executor::returning_columns, ActiveModelTrait, ColumnTrait, DbBackend, EntityTrait,
+    IntoActiveModel, Iterable, PrimaryKeyToColumn, QueryFilter, QueryTrait, Statement,
 };
 use core::marker::PhantomData;
-use sea_query::DeleteStatement;
+use sea_query::{
+    Condition, DeleteStatement, Func, IntoCondition, ReturningClause, UpdateStatement,
+};
 
 /// Defines the structure for a delete operation
 #[derive(Clone, Debug)]
@@ -16,6 +18,8 @@ where
     A: ActiveModelTrait,
 {
     pub(crate) query: DeleteStatement,
+    pub(crate) conditions: Vec<Condition>,
+    pub(crate) hard_delete: bool,
     pub(crate) model: A,
 }
 
@@ -26,6 +30,9 @@ where
     E: EntityTrait,
 {
     pub(crate) query: DeleteStatement,
+    pub(crate) conditions: Vec<Condition>,
+    pub(crate) hard_delete: bool,
+    pub(crate) returning: Option<ReturningClause>,
     pub(crate) entity: PhantomData<E>,
 }
 
@@ -70,6 +77,8 @@ impl Delete {
             query: DeleteStatement::new()
                 .from_table(A::Entity::default().table_ref())
                 .to_owned(),
+            conditions: Vec::new(),
+            hard_delete: false,
             model: model.into_active_model(),
         };
         myself.prepare()
@@ -96,6 +105,9 @@ impl Delete {
             query: DeleteStatement::new()
                 .from_table(entity.table_ref())
                 .to_owned(),
+            conditions: Vec::new(),
+            hard_delete: false,
+            returning: None,
             entity: PhantomData,
         }
     }
@@ -117,6 +129,93 @@ where
         }
         self
     }
+
+    /// Delete the row even if the entity is soft deletable, see [EntityTrait::soft_delete_column]
+    pub fn hard_delete(mut self) -> Self {
+        self.hard_delete = true;
+        self
+    }
+}
+
+impl<E> DeleteMany<E>
+where
+    E: EntityTrait,
+{
+    /// Delete the rows even if the entity is soft deletable, see [EntityTrait::soft_delete_column]
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::delete_many()
+    ///         .filter(fruit::Column::Name.contains("Apple"))
+    ///         .hard_delete()
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"DELETE FROM "fruit" WHERE "fruit"."name" LIKE '%Apple%'"#,
+    /// );
+    /// ```
+    pub fn hard_delete(mut self) -> Self {
+        self.hard_delete = true;
+        self
+    }
+
+    /// Only return the given columns of the deleted rows, when executing with
+    /// [DeleteMany::exec_with_returning_as](crate::DeleteMany::exec_with_returning_as) or
+    /// [DeleteMany::exec_with_returning_tuple](crate::DeleteMany::exec_with_returning_tuple)
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::delete_many()
+    ///         .filter(fruit::Column::Name.contains("Apple"))
+    ///         .returning([fruit::Column::Id])
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"DELETE FROM "fruit" WHERE "fruit"."name" LIKE '%Apple%' RETURNING "id""#,
+    /// );
+    /// ```
+    pub fn returning<I>(mut self, cols: I) -> Self
+    where
+        I: IntoIterator<Item = E::Column>,
+    {
+        let returning = returning_columns(cols);
+        self.query.returning(returning.clone());
+        self.returning = Some(returning);
+        self
+    }
+}
+
+/// Build the statement executed by a delete: the `DELETE` itself, or on a soft deletable entity,
+/// an `UPDATE` setting the soft delete column of the rows matched by the filter `conditions`
+pub(crate) fn build_delete<E>(
+    db_backend: DbBackend,
+    query: &DeleteStatement,
+    conditions: &[Condition],
+    returning: Option<&ReturningClause>,
+    hard_delete: bool,
+) -> Statement
+where
+    E: EntityTrait,
+{
+    let col = match E::soft_delete_column() {
+        Some(col) if !hard_delete => col,
+        _ => return db_backend.build(query),
+    };
+    let condition = conditions
+        .iter()
+        .cloned()
+        .fold(Condition::all(), |acc, condition| acc.add(condition));
+    let mut update = UpdateStatement::new();
+    update
+        .table(E::default().table_ref())
+        .col_expr(col, Func::current_timestamp())
+        .cond_where(Condition::all().add(col.is_null()).add(condition));
+    if let Some(returning) = returning {
+        update.returning(returning.clone());
+    }
+    db_backend.build(&update)
 }
 
 impl<A> QueryFilter for DeleteOne<A>
@@ -128,6 +227,16 @@ where
     fn query(&mut self) -> &mut DeleteStatement {
         &mut self.query
     }
+
+    fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: IntoCondition,
+    {
+        let condition = Condition::all().add(filter.into_condition());
+        self.query.cond_where(condition.clone());
+        self.conditions.push(condition);
+        self
+    }
 }
 
 impl<E> QueryFilter for DeleteMany<E>
@@ -139,6 +248,16 @@ where
     fn query(&mut self) -> &mut DeleteStatement {
         &mut self.query
     }
+
+    fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: IntoCondition,
+    {
+        let condition = Condition::all().add(filter.into_condition());
+        self.query.cond_where(condition.clone());
+        self.conditions.push(condition);
+        self
+    }
 }
 
 impl<A> QueryTrait for DeleteOne<A>
@@ -158,6 +277,18 @@ where
     fn into_query(self) -> DeleteStatement {
         self.query
     }
+
+    /// Build the `DELETE` statement, or the `UPDATE` statement marking the row as deleted if the
+    /// entity is soft deletable, see [EntityTrait::soft_delete_column]
+    fn build(&self, db_backend: DbBackend) -> Statement {
+        build_delete::<A::Entity>(
+            db_backend,
+            &self.query,
+            &self.conditions,
+            None,
+            self.hard_delete,
+        )
+    }
 }
 
 impl<E> QueryTrait for DeleteMany<E>
@@ -177,12 +308,25 @@ where
     fn into_query(self) -> DeleteStatement {
         self.query
     }
+
+    /// Build the `DELETE` statement, or the `UPDATE` statement marking the rows as deleted if the
+    /// entity is soft deletable, see [EntityTrait::soft_delete_column]
+    fn build(&self, db_backend: DbBackend) -> Statement {
+        build_delete::<E>(
+            db_backend,
+            &self.query,
+            &self.conditions,
+            self.returning.as_ref(),
+            self.hard_delete,
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::tests_cfg::{cake, fruit};
     use crate::{entity::*, query::*, DbBackend};
+    use sea_query::Condition;
 
     #[test]
     fn delete_1() {
@@ -216,4 +360,42 @@ mod tests {
             r#"DELETE FROM "fruit" WHERE "fruit"."name" LIKE '%Cheese%'"#,
         );
     }
+
+    #[test]
+    fn delete_filter_any() {
+        let any = || {
+            Condition::any()
+                .add(fruit::Column::Name.contains("Cheese"))
+                .add(fruit::Column::CakeId.is_null())
+        };
+
+        assert_eq!(
+            Delete::many(fruit::Entity)
+                .filter(any())
+                .filter(fruit::Column::Id.gt(2))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"DELETE FROM "fruit""#,
+                r#"WHERE ("fruit"."name" LIKE '%Cheese%' OR "fruit"."cake_id" IS NULL)"#,
+                r#"AND "fruit"."id" > 2"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            Delete::one(fruit::Model {
+                id: 1,
+                name: "Cheese".to_owned(),
+                cake_id: None,
+            })
+            .filter(any())
+            .build(DbBackend::Postgres)
+            .to_string(),
+            [
+                r#"DELETE FROM "fruit" WHERE "fruit"."id" = 1"#,
+                r#"AND ("fruit"."name" LIKE '%Cheese%' OR "fruit"."cake_id" IS NULL)"#,
+            ]
+            .join(" ")
+        );
+    }
 }
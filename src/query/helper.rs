@@ -1,12 +1,15 @@
 use crate::{
-    ColumnTrait, EntityTrait, Identity, IntoIdentity, IntoSimpleExpr, Iterable, ModelTrait,
-    PrimaryKeyToColumn, RelationDef,
+    ColumnTrait, DbErr, EntityName, EntityTrait, Identity, IntoIdentity, IntoSimpleExpr, Iterable,
+    ModelTrait, PrimaryKeyToColumn, RelationDef,
 };
 use sea_query::{
-    Alias, Expr, Iden, IntoCondition, IntoIden, LockType, SeaRc, SelectExpr, SelectStatement,
-    SimpleExpr, TableRef,
+    Alias, Expr, Iden, IntoCondition, IntoIden, PostgresQueryBuilder, QueryBuilder, SeaRc,
+    SelectExpr, SelectStatement, SimpleExpr, TableRef, Value,
+};
+pub use sea_query::{
+    Condition, ConditionalStatement, DynIden, JoinType, LockBehavior, LockType, NullOrdering,
+    Order, OrderedStatement,
 };
-pub use sea_query::{Condition, ConditionalStatement, DynIden, JoinType, Order, OrderedStatement};
 
 // LINT: when the column does not appear in tables selected from
 // LINT: when there is a group by clause, but some columns don't have aggregate functions
@@ -59,6 +62,21 @@ pub trait QuerySelect: Sized {
     ///     r#"SELECT COUNT("cake"."id") AS "count" FROM "cake""#
     /// );
     /// ```
+    ///
+    /// Aliased expressions can be chained, and read back by alias from the query result
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::Expr, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .select_only()
+    ///         .column_as(Expr::asterisk().count(), "cnt")
+    ///         .column_as(cake::Column::Id.max(), "max_id")
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT COUNT(*) AS "cnt", MAX("cake"."id") AS "max_id" FROM "cake""#
+    /// );
+    /// ```
     fn column_as<C, I>(mut self, col: C, alias: I) -> Self
     where
         C: IntoSimpleExpr,
@@ -72,6 +90,33 @@ pub trait QuerySelect: Sized {
         self
     }
 
+    /// Add a select expression with alias, whose value is read as an [ActiveEnum](crate::ActiveEnum).
+    /// On Postgres, the expression is cast to `text`, so native enum types can be decoded
+    /// into the string value of the [ActiveEnum](crate::ActiveEnum).
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::Expr, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .select_only()
+    ///         .column(cake::Column::Id)
+    ///         .expr_as_enum(Expr::cust("CASE WHEN id > 10 THEN 'B' ELSE 'S' END"), "size")
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", CAST(CASE WHEN id > 10 THEN 'B' ELSE 'S' END AS text) AS "size" FROM "cake""#
+    /// );
+    /// ```
+    fn expr_as_enum<C, I>(self, expr: C, alias: I) -> Self
+    where
+        C: IntoSimpleExpr,
+        I: IntoIdentity,
+    {
+        self.column_as(
+            Expr::expr(expr.into_simple_expr()).as_enum(Alias::new("text")),
+            alias,
+        )
+    }
+
     /// Add an offset expression
     /// ```
     /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
@@ -141,11 +186,66 @@ pub trait QuerySelect: Sized {
     ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` HAVING `cake`.`id` = 4 AND `cake`.`id` = 5"
     /// );
     /// ```
+    ///
+    /// Filter groups by an aggregate expression
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::Expr, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .select_only()
+    ///         .column(fruit::Column::CakeId)
+    ///         .column_as(fruit::Column::Id.count(), "num_of_fruits")
+    ///         .group_by(fruit::Column::CakeId)
+    ///         .having(Expr::expr(Expr::asterisk().count()).gt(5))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "fruit"."cake_id", COUNT("fruit"."id") AS "num_of_fruits" FROM "fruit""#,
+    ///         r#"GROUP BY "fruit"."cake_id" HAVING COUNT(*) > 5"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
     fn having<F>(mut self, filter: F) -> Self
     where
         F: IntoCondition,
     {
-        self.query().cond_having(filter.into_condition());
+        self.query()
+            .cond_having(Condition::all().add(filter.into_condition()));
+        self
+    }
+
+    /// Keep only the first row of each group of rows with the same values in `cols`,
+    /// i.e. `SELECT DISTINCT ON (cols)`. Which row is first is decided by `ORDER BY`,
+    /// whose leftmost expressions have to match `cols`.
+    ///
+    /// `DISTINCT ON` is supported by Postgres only. Like in SeaQuery, the clause is omitted
+    /// from the SQL of MySQL and SQLite, where all rows are returned.
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .distinct_on([fruit::Column::CakeId])
+    ///         .order_by_asc(fruit::Column::CakeId)
+    ///         .order_by_desc(fruit::Column::Id)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT DISTINCT ON ("cake_id") "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+    ///         r#"ORDER BY "fruit"."cake_id" ASC, "fruit"."id" DESC"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn distinct_on<T, I>(mut self, cols: I) -> Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.query()
+            .distinct_on(cols.into_iter().map(|col| col.into_iden()));
         self
     }
 
@@ -208,23 +308,62 @@ pub trait QuerySelect: Sized {
         self
     }
 
-    /// Select lock
+    /// Select lock. SQLite has no row-level locking, the lock is omitted from its SQL.
     fn lock(mut self, lock_type: LockType) -> Self {
         self.query().lock(lock_type);
         self
     }
 
-    /// Select lock shared
+    /// Select lock shared, i.e. `FOR SHARE`
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .lock_shared()
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" FOR SHARE"#
+    /// );
+    /// ```
     fn lock_shared(mut self) -> Self {
         self.query().lock_shared();
         self
     }
 
-    /// Select lock exclusive
+    /// Select lock exclusive, i.e. `FOR UPDATE`
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .lock_exclusive()
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` FOR UPDATE"
+    /// );
+    /// ```
     fn lock_exclusive(mut self) -> Self {
         self.query().lock_exclusive();
         self
     }
+
+    /// Select lock with a behavior on rows already locked, i.e. `NOWAIT` or `SKIP LOCKED`
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" FOR UPDATE SKIP LOCKED"#
+    /// );
+    /// ```
+    fn lock_with_behavior(mut self, lock_type: LockType, behavior: LockBehavior) -> Self {
+        self.query().lock_with_behavior(lock_type, behavior);
+        self
+    }
 }
 
 // LINT: when the column does not appear in tables selected from
@@ -298,6 +437,36 @@ pub trait QueryOrder: Sized {
             .order_by_expr(col.into_simple_expr(), Order::Desc);
         self
     }
+
+    /// Add an order_by expression with the placement of `NULL` values.
+    /// MySQL has no `NULLS FIRST` / `NULLS LAST`, so it is ordered by `col IS NULL` first instead.
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .order_by_with_nulls(fruit::Column::CakeId, Order::Asc, NullOrdering::Last)
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit" ORDER BY "fruit"."cake_id" ASC NULLS LAST"#
+    /// );
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .order_by_with_nulls(fruit::Column::CakeId, Order::Asc, NullOrdering::Last)
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id` FROM `fruit` ORDER BY `fruit`.`cake_id` IS NULL ASC, `fruit`.`cake_id` ASC"
+    /// );
+    /// ```
+    fn order_by_with_nulls<C>(mut self, col: C, ord: Order, nulls: NullOrdering) -> Self
+    where
+        C: IntoSimpleExpr,
+    {
+        self.query()
+            .order_by_expr_with_nulls(col.into_simple_expr(), ord, nulls);
+        self
+    }
 }
 
 // LINT: when the column does not appear in tables selected from
@@ -410,6 +579,25 @@ pub trait QueryFilter: Sized {
     ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE (NOT (1 = 1 AND 2 = 2)) AND (3 = 3 OR 4 = 4)"#
     /// );
     /// ```
+    ///
+    /// Negate a condition tree, later filters are added next to it rather than inside it.
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(
+    ///             Condition::any()
+    ///                 .add(cake::Column::Id.eq(4))
+    ///                 .add(cake::Column::Id.eq(5))
+    ///                 .not()
+    ///         )
+    ///         .filter(cake::Column::Name.contains("cheese"))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE (NOT ("cake"."id" = 4 OR "cake"."id" = 5)) AND "cake"."name" LIKE '%cheese%'"#
+    /// );
+    /// ```
     /// Use a sea_query expression
     /// ```
     /// use sea_orm::{entity::*, query::*, sea_query::Expr, tests_cfg::fruit, DbBackend};
@@ -426,10 +614,111 @@ pub trait QueryFilter: Sized {
     where
         F: IntoCondition,
     {
-        self.query().cond_where(filter.into_condition());
+        self.query()
+            .cond_where(Condition::all().add(filter.into_condition()));
         self
     }
 
+    /// Add an AND WHERE expression only if `cond` is true
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// let only_chocolate = true;
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter_if(only_chocolate, cake::Column::Name.contains("chocolate"))
+    ///         .filter_if(false, cake::Column::Id.eq(5))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` LIKE '%chocolate%'"
+    /// );
+    /// ```
+    fn filter_if<F>(self, cond: bool, filter: F) -> Self
+    where
+        F: IntoCondition,
+    {
+        if cond {
+            self.filter(filter)
+        } else {
+            self
+        }
+    }
+
+    /// Apply `f` to the query only if `value` is `Some`, the query is unchanged otherwise
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    /// struct Input {
+    ///     id: Option<i32>,
+    ///     name: Option<String>,
+    /// }
+    /// let input = Input {
+    ///     id: None,
+    ///     name: Some("cheese".to_owned()),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .apply_if(input.id, |q, id| q.filter(cake::Column::Id.eq(id)))
+    ///         .apply_if(input.name, |q, n| q.filter(cake::Column::Name.contains(&n)))
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` LIKE '%cheese%'"
+    /// );
+    /// ```
+    fn apply_if<T, F>(self, value: Option<T>, f: F) -> Self
+    where
+        F: FnOnce(Self, T) -> Self,
+    {
+        match value {
+            Some(value) => f(self, value),
+            None => self,
+        }
+    }
+
+    /// Add an AND WHERE `column = value` for every pair of column name and value, e.g. the filters
+    /// of a request. The names are parsed as columns of `C` the way its [FromStr](std::str::FromStr)
+    /// does, so that an unknown name returns a [DbErr::Query] instead of reaching the SQL.
+    /// A null value adds `column IS NULL`, as `column = NULL` would never match.
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend, DbErr};
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut filters = BTreeMap::new();
+    /// filters.insert("id".to_owned(), Value::from(1));
+    /// filters.insert("name".to_owned(), Value::from("Cheese Cake"));
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter_values::<cake::Column, _>(filters)?
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = 1 AND `cake`.`name` = 'Cheese Cake'"
+    /// );
+    /// # Ok::<(), DbErr>(())
+    /// ```
+    fn filter_values<C, I>(mut self, values: I) -> Result<Self, DbErr>
+    where
+        C: ColumnTrait,
+        I: IntoIterator<Item = (String, Value)>,
+    {
+        for (name, value) in values {
+            let col = C::from_str(&name).map_err(|_| {
+                DbErr::Query(format!(
+                    "Unknown column '{}' of `{}`",
+                    name,
+                    C::EntityName::default().table_name()
+                ))
+            })?;
+            if is_null(&value) {
+                self = self.filter(col.is_null());
+            } else {
+                self = self.filter(col.eq(value));
+            }
+        }
+        Ok(self)
+    }
+
     /// Apply a where condition using the model's primary key
     fn belongs_to<M>(mut self, model: &M) -> Self
     where
@@ -532,3 +821,425 @@ pub(crate) fn unpack_table_alias(table_ref: &TableRef) -> Option<DynIden> {
         | TableRef::DatabaseSchemaTableAlias(_, _, _, alias) => Some(SeaRc::clone(alias)),
     }
 }
+
+/// Whether the value is a null of any type, every backend renders those as `NULL`
+fn is_null(value: &Value) -> bool {
+    PostgresQueryBuilder.value_to_string(value) == "NULL"
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::{cake, fruit};
+    use crate::{
+        ColumnTrait, Condition, DbBackend, DbErr, EntityTrait, LockBehavior, LockType,
+        NullOrdering, Order, QueryFilter, QueryOrder, QuerySelect, QueryTrait,
+    };
+    use pretty_assertions::assert_eq;
+    use sea_query::Value;
+    use std::collections::HashMap;
+
+    #[test]
+    fn lock() {
+        let cases = [
+            (cake::Entity::find().lock_exclusive(), "FOR UPDATE"),
+            (cake::Entity::find().lock_shared(), "FOR SHARE"),
+            (
+                cake::Entity::find().lock_with_behavior(LockType::Update, LockBehavior::Nowait),
+                "FOR UPDATE NOWAIT",
+            ),
+            (
+                cake::Entity::find().lock_with_behavior(LockType::Update, LockBehavior::SkipLocked),
+                "FOR UPDATE SKIP LOCKED",
+            ),
+            (
+                cake::Entity::find().lock_with_behavior(LockType::Share, LockBehavior::SkipLocked),
+                "FOR SHARE SKIP LOCKED",
+            ),
+        ];
+        for (select, lock) in cases {
+            assert_eq!(
+                select.build(DbBackend::Postgres).to_string(),
+                format!(r#"SELECT "cake"."id", "cake"."name" FROM "cake" {}"#, lock)
+            );
+            assert_eq!(
+                select.build(DbBackend::MySql).to_string(),
+                format!("SELECT `cake`.`id`, `cake`.`name` FROM `cake` {}", lock)
+            );
+            // SQLite has no row-level locking
+            assert_eq!(
+                select.build(DbBackend::Sqlite).to_string(),
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake" "#
+            );
+        }
+    }
+
+    #[test]
+    fn condition_add_option() {
+        let id: Option<i32> = None;
+        let name: Option<&str> = Some("cheese");
+
+        let condition = Condition::all()
+            .add_option(id.map(|id| cake::Column::Id.eq(id)))
+            .add_option(name.map(|name| cake::Column::Name.contains(name)));
+        assert_eq!(condition.len(), 1);
+        assert_eq!(
+            cake::Entity::find()
+                .filter(condition)
+                .build(DbBackend::MySql)
+                .to_string(),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` LIKE '%cheese%'"
+        );
+
+        let condition = Condition::all().add_option(id.map(|id| cake::Column::Id.eq(id)));
+        assert!(condition.is_empty());
+        assert_eq!(
+            cake::Entity::find()
+                .filter(condition)
+                .build(DbBackend::MySql)
+                .to_string(),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake`"
+        );
+    }
+
+    #[test]
+    fn condition_not() {
+        let a = || cake::Column::Id.eq(1);
+        let b = || cake::Column::Name.contains("cheese");
+        let find = |condition: Condition| {
+            cake::Entity::find()
+                .filter(condition)
+                .build(DbBackend::MySql)
+                .to_string()
+        };
+
+        assert_eq!(
+            find(Condition::all().add(a()).add(b()).not()),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE NOT (`cake`.`id` = 1 AND `cake`.`name` LIKE '%cheese%')"
+        );
+        assert_eq!(
+            find(Condition::any().add(a()).add(b()).not()),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE NOT (`cake`.`id` = 1 OR `cake`.`name` LIKE '%cheese%')"
+        );
+        assert_eq!(
+            find(Condition::all().add(a()).add(b()).not().not()),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = 1 AND `cake`.`name` LIKE '%cheese%'"
+        );
+        assert_eq!(
+            find(Condition::all().add(Condition::all().add(a()).add(b()).not()).not()),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE NOT (NOT (`cake`.`id` = 1 AND `cake`.`name` LIKE '%cheese%'))"
+        );
+        assert_eq!(
+            find(Condition::all().add(Condition::all().add(a()).not()).add(b()).not()),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE NOT ((NOT (`cake`.`id` = 1)) AND `cake`.`name` LIKE '%cheese%')"
+        );
+    }
+
+    #[test]
+    fn filter_after_condition() {
+        let a = || cake::Column::Id.eq(1);
+        let b = || cake::Column::Name.contains("cheese");
+        let c = || cake::Column::Id.ne(2);
+
+        assert_eq!(
+            cake::Entity::find()
+                .filter(Condition::all().add(a()).add(b()).not())
+                .filter(c())
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "WHERE (NOT (`cake`.`id` = 1 AND `cake`.`name` LIKE '%cheese%')) AND `cake`.`id` <> 2",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            cake::Entity::find()
+                .filter(Condition::any().add(a()).add(b()))
+                .filter(c())
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "WHERE (`cake`.`id` = 1 OR `cake`.`name` LIKE '%cheese%') AND `cake`.`id` <> 2",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            cake::Entity::find()
+                .select_only()
+                .column(cake::Column::Name)
+                .group_by(cake::Column::Name)
+                .having(Condition::any().add(a()).add(b()))
+                .having(c())
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`name` FROM `cake` GROUP BY `cake`.`name`",
+                "HAVING (`cake`.`id` = 1 OR `cake`.`name` LIKE '%cheese%') AND `cake`.`id` <> 2",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            cake::Entity::delete_many()
+                .filter(Condition::all().add(a()).add(b()).not())
+                .filter(c())
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "DELETE FROM `cake`",
+                "WHERE (NOT (`cake`.`id` = 1 AND `cake`.`name` LIKE '%cheese%')) AND `cake`.`id` <> 2",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            cake::Entity::delete_by_id(3)
+                .filter(Condition::any().add(a()).add(b()))
+                .filter(c())
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "DELETE FROM `cake` WHERE `cake`.`id` = 3",
+                "AND (`cake`.`id` = 1 OR `cake`.`name` LIKE '%cheese%') AND `cake`.`id` <> 2",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn order_by_with_nulls() {
+        let select = || {
+            fruit::Entity::find()
+                .order_by_with_nulls(fruit::Column::CakeId, Order::Desc, NullOrdering::First)
+                .order_by_with_nulls(fruit::Column::Name, Order::Asc, NullOrdering::Last)
+        };
+        assert_eq!(
+            select().build(DbBackend::Postgres).to_string(),
+            [
+                r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                r#"ORDER BY "fruit"."cake_id" DESC NULLS FIRST, "fruit"."name" ASC NULLS LAST"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            select().build(DbBackend::Sqlite).to_string(),
+            [
+                r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                r#"ORDER BY "fruit"."cake_id" DESC NULLS FIRST, "fruit"."name" ASC NULLS LAST"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            select().build(DbBackend::MySql).to_string(),
+            [
+                "SELECT `fruit`.`id`, `fruit`.`name`, `fruit`.`cake_id` FROM `fruit`",
+                "ORDER BY `fruit`.`cake_id` IS NULL DESC, `fruit`.`cake_id` DESC,",
+                "`fruit`.`name` IS NULL ASC, `fruit`.`name` ASC",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn group_by_having() {
+        use sea_query::{Alias, Expr};
+
+        let select = || {
+            fruit::Entity::find()
+                .select_only()
+                .column(fruit::Column::CakeId)
+                .column_as(fruit::Column::Id.count(), "num_of_fruits")
+                .group_by(fruit::Column::CakeId)
+        };
+        assert_eq!(
+            select()
+                .having(Expr::expr(Expr::asterisk().count()).gt(5))
+                .having(fruit::Column::CakeId.is_not_null())
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `fruit`.`cake_id`, COUNT(`fruit`.`id`) AS `num_of_fruits` FROM `fruit`",
+                "GROUP BY `fruit`.`cake_id` HAVING COUNT(*) > 5 AND `fruit`.`cake_id` IS NOT NULL",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            select()
+                .having(
+                    Condition::any()
+                        .add(Expr::col(Alias::new("num_of_fruits")).gt(5))
+                        .add(Expr::expr(Expr::col(fruit::Column::Id).max()).lt(10))
+                )
+                .build(DbBackend::Sqlite)
+                .to_string(),
+            [
+                r#"SELECT "fruit"."cake_id", COUNT("fruit"."id") AS "num_of_fruits" FROM "fruit""#,
+                r#"GROUP BY "fruit"."cake_id" HAVING "num_of_fruits" > 5 OR MAX("id") < 10"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn distinct_on() {
+        let select = || {
+            fruit::Entity::find()
+                .select_only()
+                .column(fruit::Column::CakeId)
+                .column(fruit::Column::Name)
+                .distinct_on([fruit::Column::CakeId, fruit::Column::Name])
+                .order_by_asc(fruit::Column::CakeId)
+                .order_by_asc(fruit::Column::Name)
+        };
+        assert_eq!(
+            select().build(DbBackend::Postgres).to_string(),
+            [
+                r#"SELECT DISTINCT ON ("cake_id", "name") "fruit"."cake_id", "fruit"."name" FROM "fruit""#,
+                r#"ORDER BY "fruit"."cake_id" ASC, "fruit"."name" ASC"#,
+            ]
+            .join(" ")
+        );
+        // Not supported by MySQL and SQLite
+        assert_eq!(
+            select().build(DbBackend::Sqlite).to_string(),
+            [
+                r#"SELECT "fruit"."cake_id", "fruit"."name" FROM "fruit""#,
+                r#"ORDER BY "fruit"."cake_id" ASC, "fruit"."name" ASC"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            select().build(DbBackend::MySql).to_string(),
+            [
+                "SELECT `fruit`.`cake_id`, `fruit`.`name` FROM `fruit`",
+                "ORDER BY `fruit`.`cake_id` ASC, `fruit`.`name` ASC",
+            ]
+            .join(" ")
+        );
+        // An empty list of columns removes the clause
+        assert_eq!(
+            select()
+                .distinct_on(Vec::<fruit::Column>::new())
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "fruit"."cake_id", "fruit"."name" FROM "fruit""#,
+                r#"ORDER BY "fruit"."cake_id" ASC, "fruit"."name" ASC"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn filter_if() {
+        assert_eq!(
+            cake::Entity::find()
+                .filter_if(false, cake::Column::Id.eq(1))
+                .build(DbBackend::MySql)
+                .to_string(),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake`"
+        );
+        assert_eq!(
+            cake::Entity::find()
+                .filter_if(true, cake::Column::Id.eq(1))
+                .build(DbBackend::MySql)
+                .to_string(),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = 1"
+        );
+    }
+
+    #[test]
+    fn apply_if() {
+        assert_eq!(
+            cake::Entity::find()
+                .apply_if(None::<i32>, |q, id| q.filter(cake::Column::Id.eq(id)))
+                .build(DbBackend::MySql)
+                .to_string(),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake`"
+        );
+        assert_eq!(
+            cake::Entity::find()
+                .apply_if(Some(1), |q, id| q.filter(cake::Column::Id.eq(id)))
+                .build(DbBackend::MySql)
+                .to_string(),
+            "SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = 1"
+        );
+        assert_eq!(
+            cake::Entity::delete_many()
+                .apply_if(Some("cheese"), |q, n| q
+                    .filter(cake::Column::Name.contains(n)))
+                .build(DbBackend::MySql)
+                .to_string(),
+            "DELETE FROM `cake` WHERE `cake`.`name` LIKE '%cheese%'"
+        );
+    }
+
+    #[test]
+    fn filter_values() -> Result<(), DbErr> {
+        let filters = HashMap::from([
+            ("cake_id".to_owned(), Value::from(2)),
+            ("name".to_owned(), Value::from("Apple")),
+        ]);
+        let sql = fruit::Entity::find()
+            .filter_values::<fruit::Column, _>(filters)?
+            .build(DbBackend::Postgres)
+            .to_string();
+        let select = r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#;
+        // The conditions follow the iteration order of the map
+        assert!([
+            format!(
+                r#"{} WHERE "fruit"."cake_id" = 2 AND "fruit"."name" = 'Apple'"#,
+                select
+            ),
+            format!(
+                r#"{} WHERE "fruit"."name" = 'Apple' AND "fruit"."cake_id" = 2"#,
+                select
+            ),
+        ]
+        .contains(&sql));
+
+        assert_eq!(
+            fruit::Entity::find()
+                .filter_values::<fruit::Column, _>([
+                    ("cake_id".to_owned(), Value::Int(None)),
+                    ("name".to_owned(), Value::from("NULL")),
+                ])?
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                r#"WHERE "fruit"."cake_id" IS NULL AND "fruit"."name" = 'NULL'"#,
+            ]
+            .join(" ")
+        );
+
+        assert_eq!(
+            fruit::Entity::find()
+                .filter(fruit::Column::Id.gt(1))
+                .filter_values::<fruit::Column, _>([("cakeId".to_owned(), Value::from(2))])?
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                r#"WHERE "fruit"."id" > 1 AND "fruit"."cake_id" = 2"#,
+            ]
+            .join(" ")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn filter_values_unknown_column() {
+        let filters = [
+            ("name".to_owned(), Value::from("Apple")),
+            ("name = name OR 1".to_owned(), Value::from(1)),
+        ];
+        assert_eq!(
+            fruit::Entity::find()
+                .filter_values::<fruit::Column, _>(filters)
+                .map(|select| select.build(DbBackend::Postgres).to_string()),
+            Err(DbErr::Query(
+                "Unknown column 'name = name OR 1' of `fruit`".to_owned()
+            ))
+        );
+    }
+}
@@ -1,9 +1,12 @@
 use crate::{
-    ActiveModelTrait, ColumnTrait, EntityName, EntityTrait, IntoActiveModel, Iterable,
-    PrimaryKeyTrait, QueryTrait,
+    executor::returning_columns, ActiveModelTrait, ColumnTrait, DbBackend, DbErr, EntityName,
+    EntityTrait, IntoActiveModel, Iterable, PrimaryKeyTrait, QueryTrait,
 };
 use core::marker::PhantomData;
-use sea_query::{Alias, Expr, InsertStatement, OnConflict, ValueTuple};
+use sea_query::{
+    Alias, Expr, InsertStatement, Keyword, OnConflict, Query, ReturningClause, SelectStatement,
+    SimpleExpr, ValueTuple,
+};
 
 /// Performs INSERT operations on a ActiveModel
 #[derive(Debug)]
@@ -13,7 +16,12 @@ where
 {
     pub(crate) query: InsertStatement,
     pub(crate) columns: Vec<bool>,
+    pub(crate) rows: Vec<Vec<SimpleExpr>>,
+    pub(crate) on_conflict: Option<OnConflict>,
+    pub(crate) chunk_size: Option<usize>,
     pub(crate) primary_key: Option<ValueTuple>,
+    pub(crate) returning: Option<ReturningClause>,
+    pub(crate) from_select: bool,
     pub(crate) model: PhantomData<A>,
 }
 
@@ -37,7 +45,12 @@ where
                 .or_default_values()
                 .to_owned(),
             columns: Vec::new(),
+            rows: Vec::new(),
+            on_conflict: None,
+            chunk_size: None,
             primary_key: None,
+            returning: None,
+            from_select: false,
             model: PhantomData,
         }
     }
@@ -108,6 +121,50 @@ where
         Self::new().add_many(models)
     }
 
+    /// Insert the rows selected by a [Select](crate::Select) or [SelectStatement] into the given columns,
+    /// in the order they are selected
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, error::*, query::*, tests_cfg::{cake, fruit}, DbBackend};
+    ///
+    /// # fn main() -> Result<(), DbErr> {
+    /// assert_eq!(
+    ///     Insert::<cake::ActiveModel>::from_select(
+    ///         [cake::Column::Name],
+    ///         fruit::Entity::find()
+    ///             .select_only()
+    ///             .column(fruit::Column::Name)
+    ///             .filter(fruit::Column::CakeId.is_null()),
+    ///     )?
+    ///     .build(DbBackend::Postgres)
+    ///     .to_string(),
+    ///     [
+    ///         r#"INSERT INTO "cake" ("name")"#,
+    ///         r#"SELECT "fruit"."name" FROM "fruit" WHERE "fruit"."cake_id" IS NULL"#,
+    ///     ]
+    ///     .join(" "),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Use [Insert::exec_without_returning](crate::Insert::exec_without_returning) to get the number
+    /// of inserted rows. Returns a [DbErr::Query] if the number of columns and selected expressions differ.
+    pub fn from_select<I, S>(columns: I, select: S) -> Result<Self, DbErr>
+    where
+        I: IntoIterator<Item = <A::Entity as EntityTrait>::Column>,
+        S: Into<SelectStatement>,
+    {
+        let mut insert = Self::new();
+        insert.query.columns(columns);
+        insert
+            .query
+            .select_from(select)
+            .map_err(|e| DbErr::Query(e.to_string()))?;
+        insert.from_select = true;
+        Ok(insert)
+    }
+
     /// Add a Model to Self
     #[allow(clippy::should_implement_trait)]
     pub fn add<M>(mut self, m: M) -> Self
@@ -126,7 +183,7 @@ where
         let columns_empty = self.columns.is_empty();
         for (idx, col) in <A::Entity as EntityTrait>::Column::iter().enumerate() {
             let av = am.take(col);
-            let av_has_val = av.is_set() || av.is_unchanged();
+            let av_has_val = (av.is_set() || av.is_unchanged()) && !col.def().is_generated();
             if columns_empty {
                 self.columns.push(av_has_val);
             } else if self.columns[idx] != av_has_val {
@@ -137,14 +194,15 @@ where
                 let val = Expr::val(av.into_value().unwrap());
                 let col_def = col.def();
                 let col_type = col_def.get_column_type();
-                let expr = match col_type.get_enum_name() {
-                    Some(enum_name) => val.as_enum(Alias::new(enum_name)),
+                let expr = match col_type.get_cast_name() {
+                    Some(type_name) => val.as_enum(Alias::new(type_name)),
                     None => val.into(),
                 };
                 values.push(expr);
             }
         }
         self.query.columns(columns);
+        self.rows.push(values.clone());
         self.query.exprs_panic(values);
         self
     }
@@ -204,10 +262,112 @@ where
     ///     r#"INSERT INTO "cake" ("id", "name") VALUES (2, 'Orange') ON CONFLICT ("name") DO UPDATE SET "name" = "excluded"."name""#,
     /// );
     /// ```
+    ///
+    /// MySQL has no `DO NOTHING` clause, updating the primary key with itself has the same effect.
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::OnConflict, tests_cfg::cake, DbBackend};
+    ///
+    /// let orange = cake::ActiveModel {
+    ///     id: ActiveValue::set(2),
+    ///     name: ActiveValue::set("Orange".to_owned()),
+    /// };
+    /// assert_eq!(
+    ///     cake::Entity::insert(orange)
+    ///         .on_conflict(
+    ///             OnConflict::column(cake::Column::Id)
+    ///                 .update_column(cake::Column::Id)
+    ///                 .to_owned()
+    ///         )
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     r#"INSERT INTO `cake` (`id`, `name`) VALUES (2, 'Orange') ON DUPLICATE KEY UPDATE `id` = VALUES(`id`)"#,
+    /// );
+    /// ```
+    ///
+    /// On Postgres, executing an insert that was skipped by `DO NOTHING` returns [DbErr::RecordNotInserted](crate::DbErr::RecordNotInserted).
     pub fn on_conflict(mut self, on_conflict: OnConflict) -> Self {
-        self.query.on_conflict(on_conflict);
+        self.query.on_conflict(on_conflict.clone());
+        self.on_conflict = Some(on_conflict);
+        self
+    }
+
+    /// Only return the given columns of the inserted rows, when executing with
+    /// [Insert::exec_with_returning_as](crate::Insert::exec_with_returning_as) or
+    /// [Insert::exec_with_returning_tuple](crate::Insert::exec_with_returning_tuple)
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::insert(cake::ActiveModel {
+    ///         id: NotSet,
+    ///         name: Set("Apple Pie".to_owned()),
+    ///     })
+    ///     .returning([cake::Column::Id])
+    ///     .build(DbBackend::Postgres)
+    ///     .to_string(),
+    ///     r#"INSERT INTO "cake" ("name") VALUES ('Apple Pie') RETURNING "id""#,
+    /// );
+    /// ```
+    pub fn returning<I>(mut self, cols: I) -> Self
+    where
+        I: IntoIterator<Item = <A::Entity as EntityTrait>::Column>,
+    {
+        let returning = returning_columns(cols);
+        self.query.returning(returning.clone());
+        self.returning = Some(returning);
+        self
+    }
+
+    /// Set the maximum number of rows per `INSERT` statement when executing with
+    /// [Insert::exec_without_returning](crate::Insert::exec_without_returning).
+    /// By default, the rows are split to respect the bind parameter limit of the backend.
+    /// The other ways to execute an insert always run a single statement.
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = Some(chunk_size);
         self
     }
+
+    /// Get the maximum number of rows per `INSERT` statement on the given backend
+    pub(crate) fn rows_per_chunk(&self, db_backend: DbBackend) -> usize {
+        if let Some(chunk_size) = self.chunk_size {
+            return chunk_size.max(1);
+        }
+        let max_params = match db_backend {
+            DbBackend::MySql | DbBackend::Postgres => 65535,
+            DbBackend::Sqlite => 999,
+        };
+        let num_columns = self.columns.iter().filter(|has_val| **has_val).count();
+        (max_params / num_columns.max(1)).max(1)
+    }
+
+    /// Split the statement into `INSERT` statements of at most `rows_per_chunk` rows each.
+    /// Every chunk keeps the other clauses of the statement, e.g. `ON CONFLICT` and `RETURNING`.
+    pub(crate) fn into_chunks(self, rows_per_chunk: usize) -> Vec<InsertStatement> {
+        if self.rows.len() <= rows_per_chunk {
+            return vec![self.query];
+        }
+        let num_columns = self.columns.iter().filter(|has_val| **has_val).count();
+        let mut query = self.query;
+        // sea-query has no way to remove the values of a statement, but replacing them
+        // by a `SELECT` does, so that `exprs` starts a new list of values afterwards
+        let select = Query::select()
+            .exprs(std::iter::repeat(SimpleExpr::Keyword(Keyword::Null)).take(num_columns))
+            .to_owned();
+        if query.select_from(select).is_err() {
+            return vec![query];
+        }
+        let mut chunks = Vec::new();
+        let mut rows = self.rows.into_iter().peekable();
+        while rows.peek().is_some() {
+            let mut chunk = query.clone();
+            for values in rows.by_ref().take(rows_per_chunk) {
+                chunk.exprs_panic(values);
+            }
+            chunks.push(chunk);
+        }
+        chunks
+    }
 }
 
 impl<A> QueryTrait for Insert<A>
@@ -231,10 +391,10 @@ where
 
 #[cfg(test)]
 mod tests {
-    use sea_query::OnConflict;
+    use sea_query::{Expr, OnConflict, Query};
 
-    use crate::tests_cfg::cake;
-    use crate::{ActiveValue, DbBackend, EntityTrait, Insert, QueryTrait};
+    use crate::tests_cfg::{cake, fruit, line_item};
+    use crate::{ActiveValue, DbBackend, DbErr, EntityTrait, Insert, QuerySelect, QueryTrait};
 
     #[test]
     fn insert_1() {
@@ -357,4 +517,126 @@ mod tests {
             r#"INSERT INTO "cake" ("id", "name") VALUES (2, 'Orange') ON CONFLICT ("name") DO UPDATE SET "name" = "excluded"."name""#,
         );
     }
+
+    #[test]
+    fn insert_on_conflict_do_nothing() {
+        let orange = cake::ActiveModel {
+            id: ActiveValue::set(2),
+            name: ActiveValue::set("Orange".to_owned()),
+        };
+        let insert = cake::Entity::insert(orange)
+            .on_conflict(OnConflict::column(cake::Column::Id).do_nothing().to_owned());
+
+        assert_eq!(
+            insert.build(DbBackend::Postgres).to_string(),
+            r#"INSERT INTO "cake" ("id", "name") VALUES (2, 'Orange') ON CONFLICT ("id") DO NOTHING"#,
+        );
+        assert_eq!(
+            insert.build(DbBackend::Sqlite).to_string(),
+            r#"INSERT INTO "cake" ("id", "name") VALUES (2, 'Orange') ON CONFLICT ("id") DO NOTHING"#,
+        );
+
+        let orange = cake::ActiveModel {
+            id: ActiveValue::set(2),
+            name: ActiveValue::set("Orange".to_owned()),
+        };
+        assert_eq!(
+            cake::Entity::insert(orange)
+                .on_conflict(
+                    OnConflict::column(cake::Column::Id)
+                        .update_column(cake::Column::Id)
+                        .to_owned()
+                )
+                .build(DbBackend::MySql)
+                .to_string(),
+            r#"INSERT INTO `cake` (`id`, `name`) VALUES (2, 'Orange') ON DUPLICATE KEY UPDATE `id` = VALUES(`id`)"#,
+        );
+    }
+
+    #[test]
+    fn insert_on_conflict_update_columns() {
+        let apple = fruit::ActiveModel {
+            id: ActiveValue::set(1),
+            name: ActiveValue::set("Apple".to_owned()),
+            cake_id: ActiveValue::set(Some(3)),
+        };
+        let insert = fruit::Entity::insert(apple).on_conflict(
+            OnConflict::column(fruit::Column::Id)
+                .update_columns([fruit::Column::Name])
+                .to_owned(),
+        );
+
+        assert_eq!(
+            insert.build(DbBackend::Postgres).to_string(),
+            r#"INSERT INTO "fruit" ("id", "name", "cake_id") VALUES (1, 'Apple', 3) ON CONFLICT ("id") DO UPDATE SET "name" = "excluded"."name""#,
+        );
+        assert_eq!(
+            insert.build(DbBackend::Sqlite).to_string(),
+            r#"INSERT INTO "fruit" ("id", "name", "cake_id") VALUES (1, 'Apple', 3) ON CONFLICT ("id") DO UPDATE SET "name" = "excluded"."name""#,
+        );
+        assert_eq!(
+            insert.build(DbBackend::MySql).to_string(),
+            r#"INSERT INTO `fruit` (`id`, `name`, `cake_id`) VALUES (1, 'Apple', 3) ON DUPLICATE KEY UPDATE `name` = VALUES(`name`)"#,
+        );
+    }
+
+    #[test]
+    fn insert_generated_column() {
+        let line_item = line_item::Model {
+            id: 1,
+            price: 250,
+            quantity: 2,
+            total: 500,
+        };
+        assert_eq!(
+            Insert::<line_item::ActiveModel>::new()
+                .add(line_item)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"INSERT INTO "line_item" ("id", "price", "quantity") VALUES (1, 250, 2)"#,
+        );
+    }
+
+    #[test]
+    fn insert_from_select() -> Result<(), DbErr> {
+        let select = Query::select()
+            .column(fruit::Column::Id)
+            .expr(Expr::col(fruit::Column::Name).like("%Pie"))
+            .from(fruit::Entity)
+            .to_owned();
+        assert_eq!(
+            Insert::<cake::ActiveModel>::from_select(
+                [cake::Column::Id, cake::Column::Name],
+                select
+            )?
+            .build(DbBackend::MySql)
+            .to_string(),
+            r#"INSERT INTO `cake` (`id`, `name`) SELECT `id`, `name` LIKE '%Pie' FROM `fruit`"#,
+        );
+        assert_eq!(
+            Insert::<cake::ActiveModel>::from_select(
+                [cake::Column::Id, cake::Column::Name],
+                fruit::Entity::find()
+                    .select_only()
+                    .column(fruit::Column::Id)
+                    .column(fruit::Column::Name)
+            )?
+            .build(DbBackend::Postgres)
+            .to_string(),
+            r#"INSERT INTO "cake" ("id", "name") SELECT "fruit"."id", "fruit"."name" FROM "fruit""#,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn insert_from_select_columns_mismatch() {
+        assert_eq!(
+            Insert::<cake::ActiveModel>::from_select([cake::Column::Name], fruit::Entity::find())
+                .err(),
+            Some(DbErr::Query(
+                "Columns and values length mismatch: 1 != 3".to_owned()
+            ))
+        );
+    }
 }
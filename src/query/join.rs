@@ -1,9 +1,11 @@
+use super::combine::prepare_select_two;
 use crate::{
-    join_tbl_on_condition, unpack_table_ref, ColumnTrait, EntityTrait, IdenStatic, Iterable,
-    Linked, QuerySelect, Related, Select, SelectA, SelectB, SelectTwo, SelectTwoMany,
+    join_tbl_on_condition, unpack_table_ref, EntityTrait, IdenStatic, IntoSimpleExpr, Iterable,
+    Linked, PrimaryKeyToColumn, QuerySelect, Related, Select, SelectA, SelectB,
+    SelectModelAndCount, SelectTwo, SelectTwoMany, Selector,
 };
 pub use sea_query::JoinType;
-use sea_query::{Alias, Condition, DynIden, Expr, IntoIden, SeaRc, SelectExpr};
+use sea_query::{Alias, Condition, Expr, IntoIden, SeaRc, SimpleExpr};
 
 impl<E> Select<E>
 where
@@ -62,8 +64,55 @@ where
         self.left_join(r).select_with(r)
     }
 
-    /// Left Join with a Linked Entity and select both Entity.
+    /// Left Join with a Related Entity and count the related Entity of each Model.
+    /// The result is grouped by all columns of `Self` and yields `(Model, i64)`.
+    pub fn find_with_related_count<R>(self, r: R) -> Selector<SelectModelAndCount<E::Model>>
+    where
+        R: EntityTrait,
+        E: Related<R>,
+    {
+        let expr = match <R::PrimaryKey as Iterable>::iter().next() {
+            Some(pk) => pk.into_column().into_simple_expr(),
+            None => panic!("the related Entity must have a primary key"),
+        };
+        self.left_join(r).select_count(expr)
+    }
+
+    /// Left Join with a Related Entity and select both Entity.
     pub fn find_also_linked<L, T>(self, l: L) -> SelectTwo<E, T>
+    where
+        L: Linked<FromEntity = E, ToEntity = T>,
+        T: EntityTrait,
+    {
+        let mut slf = self.join_linked(&l);
+        slf = slf.apply_alias(SelectA.as_str());
+        let mut select_two = SelectTwo::new_without_prepare(slf.query);
+        prepare_select_two::<T, _>(
+            &mut select_two,
+            Some(Alias::new(&format!("r{}", l.link().len() - 1)).into_iden()),
+        );
+        select_two
+    }
+
+    /// Left Join with a Linked Entity and count the linked Entity of each Model.
+    /// The result is grouped by all columns of `Self` and yields `(Model, i64)`.
+    pub fn find_with_linked_count<L, T>(self, l: L) -> Selector<SelectModelAndCount<E::Model>>
+    where
+        L: Linked<FromEntity = E, ToEntity = T>,
+        T: EntityTrait,
+    {
+        let expr = match <T::PrimaryKey as Iterable>::iter().next() {
+            Some(pk) => Expr::tbl(
+                Alias::new(&format!("r{}", l.link().len() - 1)).into_iden(),
+                pk.into_column().into_iden(),
+            )
+            .into(),
+            None => panic!("the linked Entity must have a primary key"),
+        };
+        self.join_linked(&l).select_count(expr)
+    }
+
+    fn join_linked<L, T>(self, l: &L) -> Self
     where
         L: Linked<FromEntity = E, ToEntity = T>,
         T: EntityTrait,
@@ -91,28 +140,19 @@ where
             slf.query()
                 .join_as(JoinType::LeftJoin, table_ref, to_tbl, condition);
         }
-        slf = slf.apply_alias(SelectA.as_str());
-        let text_type = SeaRc::new(Alias::new("text")) as DynIden;
-        let mut select_two = SelectTwo::new_without_prepare(slf.query);
-        for col in <T::Column as Iterable>::iter() {
-            let col_def = col.def();
-            let col_type = col_def.get_column_type();
-            let alias = format!("{}{}", SelectB.as_str(), col.as_str());
-            let expr = Expr::tbl(
-                Alias::new(&format!("r{}", l.link().len() - 1)).into_iden(),
-                col.into_iden(),
-            );
-            let expr = match col_type.get_enum_name() {
-                Some(_) => expr.as_enum(text_type.clone()),
-                None => expr.into(),
-            };
-            select_two.query().expr(SelectExpr {
-                expr,
-                alias: Some(SeaRc::new(Alias::new(&alias))),
-                window: None,
-            });
+        slf
+    }
+
+    fn select_count(mut self, expr: SimpleExpr) -> Selector<SelectModelAndCount<E::Model>> {
+        for col in <E::Column as Iterable>::iter() {
+            self.query().group_by_col((E::default(), col));
         }
-        select_two
+        self = self.apply_alias(SelectA.as_str());
+        self.query().expr_as(
+            Expr::expr(expr).count(),
+            Alias::new(&format!("{}count", SelectB.as_str())),
+        );
+        self.into_model_and_count()
     }
 }
 
@@ -124,7 +164,7 @@ mod tests {
         RelationTrait,
     };
     use pretty_assertions::assert_eq;
-    use sea_query::{Alias, Expr, IntoCondition, JoinType};
+    use sea_query::{Alias, Expr, IntoCondition, JoinType, MysqlQueryBuilder};
 
     #[test]
     fn join_1() {
@@ -566,4 +606,39 @@ mod tests {
             .join(" ")
         );
     }
+
+    #[test]
+    fn join_22() {
+        assert_eq!(
+            cake::Entity::find()
+                .find_with_related_count(fruit::Entity)
+                .query
+                .to_string(MysqlQueryBuilder),
+            [
+                r#"SELECT `cake`.`id` AS `A_id`, `cake`.`name` AS `A_name`, COUNT(`fruit`.`id`) AS `B_count`"#,
+                r#"FROM `cake`"#,
+                r#"LEFT JOIN `fruit` ON `cake`.`id` = `fruit`.`cake_id`"#,
+                r#"GROUP BY `cake`.`id`, `cake`.`name`"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn join_23() {
+        assert_eq!(
+            cake::Entity::find()
+                .find_with_linked_count(entity_linked::CakeToFilling)
+                .query
+                .to_string(MysqlQueryBuilder),
+            [
+                r#"SELECT `cake`.`id` AS `A_id`, `cake`.`name` AS `A_name`, COUNT(`r1`.`id`) AS `B_count`"#,
+                r#"FROM `cake`"#,
+                r#"LEFT JOIN `cake_filling` AS `r0` ON `cake`.`id` = `r0`.`cake_id`"#,
+                r#"LEFT JOIN `filling` AS `r1` ON `r0`.`filling_id` = `r1`.`id`"#,
+                r#"GROUP BY `cake`.`id`, `cake`.`name`"#,
+            ]
+            .join(" ")
+        );
+    }
 }
@@ -144,7 +144,8 @@ impl FromQueryResult for JsonValue {
                     match_sqlite_type!(u16);
                     match_sqlite_type!(u32);
                     // match_sqlite_type!(u64); // unsupported by SQLx Sqlite
-                    match_sqlite_type!(f32);
+                    // `f32` and `f64` share the same type info, try the wider type
+                    // first to not lose precision
                     match_sqlite_type!(f64);
                     #[cfg(feature = "with-chrono")]
                     match_sqlite_type!(chrono::NaiveDate);
@@ -181,7 +182,7 @@ impl FromQueryResult for JsonValue {
 #[cfg(feature = "mock")]
 mod tests {
     use crate::tests_cfg::cake;
-    use crate::{entity::*, DbBackend, DbErr, MockDatabase};
+    use crate::{entity::*, DbBackend, DbErr, MockDatabase, Statement};
     use sea_query::Value;
 
     #[smol_potat::test]
@@ -202,4 +203,32 @@ mod tests {
 
         Ok(())
     }
+
+    #[smol_potat::test]
+    async fn query_all_json() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                maplit::btreemap! {
+                    "id" => Into::<Value>::into(1), "name" => Into::<Value>::into("apple"), "price" => Into::<Value>::into(Some(2.5f64))
+                },
+                maplit::btreemap! {
+                    "id" => Into::<Value>::into(2), "name" => Into::<Value>::into("orange"), "price" => Into::<Value>::into(Option::<f64>::None)
+                },
+            ]])
+            .into_connection();
+
+        assert_eq!(
+            db.query_all_json(Statement::from_string(
+                DbBackend::Postgres,
+                r#"SELECT "id", "name", "price" FROM "fruit""#.to_owned(),
+            ))
+            .await?,
+            [
+                serde_json::json!({ "id": 1, "name": "apple", "price": 2.5 }),
+                serde_json::json!({ "id": 2, "name": "orange", "price": null }),
+            ]
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,487 @@
+use crate::{
+    unpack_table_ref, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait, Identity,
+    ModelTrait, QueryFilter, Related, RelationType, Value,
+};
+use async_trait::async_trait;
+use std::{collections::HashMap, str::FromStr};
+
+/// A Trait for loading the related Models of a batch of Models in a single query,
+/// avoiding the N+1 problem of calling [ModelTrait::find_related] on each Model
+#[async_trait]
+pub trait LoaderTrait {
+    /// The Model to load the relations of
+    type Model: ModelTrait;
+
+    /// Load the related Model of each Model through a has-one or belongs-to relation.
+    /// The result is in the same order as `self`.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results(vec![vec![cake::Model {
+    /// #         id: 1,
+    /// #         name: "Chocolate Forest".to_owned(),
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}};
+    ///
+    /// let fruits = vec![
+    ///     fruit::Model { id: 1, name: "Apple".to_owned(), cake_id: Some(1) },
+    ///     fruit::Model { id: 2, name: "Orange".to_owned(), cake_id: None },
+    /// ];
+    ///
+    /// let cakes: Vec<Option<cake::Model>> = fruits.load_one(cake::Entity, &db).await?;
+    ///
+    /// assert_eq!(
+    ///     cakes,
+    ///     vec![
+    ///         Some(cake::Model { id: 1, name: "Chocolate Forest".to_owned() }),
+    ///         None,
+    ///     ]
+    /// );
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     vec![Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" IN ($1, $2)"#,
+    ///         vec![Some(1i32).into(), Option::<i32>::None.into()]
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn load_one<R, C>(&self, r: R, db: &C) -> Result<Vec<Option<R::Model>>, DbErr>
+    where
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        C: ConnectionTrait,
+        <Self::Model as ModelTrait>::Entity: Related<R>;
+
+    /// Load the related Models of each Model through a has-many relation.
+    /// The result is in the same order as `self`.
+    async fn load_many<R, C>(&self, r: R, db: &C) -> Result<Vec<Vec<R::Model>>, DbErr>
+    where
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        C: ConnectionTrait,
+        <Self::Model as ModelTrait>::Entity: Related<R>;
+}
+
+#[async_trait]
+impl<M> LoaderTrait for Vec<M>
+where
+    M: ModelTrait + Sync,
+{
+    type Model = M;
+
+    async fn load_one<R, C>(&self, r: R, db: &C) -> Result<Vec<Option<R::Model>>, DbErr>
+    where
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        C: ConnectionTrait,
+        <Self::Model as ModelTrait>::Entity: Related<R>,
+    {
+        self.as_slice().load_one(r, db).await
+    }
+
+    async fn load_many<R, C>(&self, r: R, db: &C) -> Result<Vec<Vec<R::Model>>, DbErr>
+    where
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        C: ConnectionTrait,
+        <Self::Model as ModelTrait>::Entity: Related<R>,
+    {
+        self.as_slice().load_many(r, db).await
+    }
+}
+
+#[async_trait]
+impl<M> LoaderTrait for &[M]
+where
+    M: ModelTrait + Sync,
+{
+    type Model = M;
+
+    async fn load_one<R, C>(&self, _: R, db: &C) -> Result<Vec<Option<R::Model>>, DbErr>
+    where
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        C: ConnectionTrait,
+        <Self::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let (keys, grouped) = load::<M, R, C>(self, RelationType::HasOne, db).await?;
+        Ok(keys
+            .iter()
+            .map(|key| grouped.get(key).and_then(|models| models.first().cloned()))
+            .collect())
+    }
+
+    async fn load_many<R, C>(&self, _: R, db: &C) -> Result<Vec<Vec<R::Model>>, DbErr>
+    where
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        C: ConnectionTrait,
+        <Self::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let (keys, grouped) = load::<M, R, C>(self, RelationType::HasMany, db).await?;
+        Ok(keys
+            .iter()
+            .map(|key| grouped.get(key).cloned().unwrap_or_default())
+            .collect())
+    }
+}
+
+/// Query the related Models of all `models` at once, returning the key of each Model
+/// along with the related Models grouped by key
+async fn load<M, R, C>(
+    models: &[M],
+    rel_type: RelationType,
+    db: &C,
+) -> Result<(Vec<String>, HashMap<String, Vec<R::Model>>), DbErr>
+where
+    M: ModelTrait,
+    R: EntityTrait,
+    C: ConnectionTrait,
+    M::Entity: Related<R>,
+{
+    if <M::Entity as Related<R>>::via().is_some() {
+        return Err(DbErr::Query(
+            "Loading a relation via an intermediate Entity is not supported".to_owned(),
+        ));
+    }
+    let mut rel = <M::Entity as Related<R>>::to();
+    match (&rel_type, &rel.rel_type) {
+        (RelationType::HasOne, RelationType::HasOne)
+        | (RelationType::HasMany, RelationType::HasMany) => {}
+        (RelationType::HasOne, RelationType::HasMany) => {
+            return Err(DbErr::Query(
+                "Relation is HasMany instead of HasOne, use `load_many`".to_owned(),
+            ))
+        }
+        (RelationType::HasMany, RelationType::HasOne) => {
+            return Err(DbErr::Query(
+                "Relation is HasOne instead of HasMany, use `load_one`".to_owned(),
+            ))
+        }
+    }
+
+    if models.is_empty() {
+        return Ok((Vec::new(), HashMap::new()));
+    }
+
+    let from_cols = identity_to_columns::<M::Entity>(&rel.from_col)?;
+    let to_cols = identity_to_columns::<R>(&rel.to_col)?;
+
+    let mut keys = Vec::with_capacity(models.len());
+    let mut values: Vec<Vec<Value>> = Vec::new();
+    for model in models {
+        let value: Vec<Value> = from_cols.iter().map(|col| model.get(*col)).collect();
+        keys.push(format!("{:?}", value));
+        if !values.contains(&value) {
+            values.push(value);
+        }
+    }
+
+    let condition = match to_cols.as_slice() {
+        [col] => Condition::all().add(col.is_in(values.into_iter().flatten())),
+        _ => values.into_iter().fold(Condition::any(), |cond, value| {
+            cond.add(
+                to_cols
+                    .iter()
+                    .zip(value)
+                    .fold(Condition::all(), |cond, (col, v)| cond.add(col.eq(v))),
+            )
+        }),
+    };
+    // The related Entity is queried on its own, so the extra join condition can only refer to it
+    let condition = match rel.on_condition.take() {
+        Some(f) => condition.add(f(
+            unpack_table_ref(&rel.from_tbl),
+            unpack_table_ref(&rel.to_tbl),
+        )),
+        None => condition,
+    };
+
+    let mut grouped: HashMap<String, Vec<R::Model>> = HashMap::new();
+    for model in R::find().filter(condition).all(db).await? {
+        let value: Vec<Value> = to_cols.iter().map(|col| model.get(*col)).collect();
+        grouped
+            .entry(format!("{:?}", value))
+            .or_default()
+            .push(model);
+    }
+
+    Ok((keys, grouped))
+}
+
+fn identity_to_columns<E>(identity: &Identity) -> Result<Vec<E::Column>, DbErr>
+where
+    E: EntityTrait,
+{
+    let idens = match identity {
+        Identity::Unary(iden1) => vec![iden1],
+        Identity::Binary(iden1, iden2) => vec![iden1, iden2],
+        Identity::Ternary(iden1, iden2, iden3) => vec![iden1, iden2, iden3],
+    };
+    idens
+        .into_iter()
+        .map(|iden| {
+            let name = iden.to_string();
+            E::Column::from_str(&name)
+                .map_err(|_| DbErr::Type(format!("Failed at mapping string to column: {}", name)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{entity::prelude::*, tests_cfg::*, DbBackend, MockDatabase, Transaction};
+    use pretty_assertions::assert_eq;
+
+    fn cake_model(id: i32, name: &str) -> cake::Model {
+        cake::Model {
+            id,
+            name: name.to_owned(),
+        }
+    }
+
+    fn fruit_model(id: i32, name: &str, cake_id: Option<i32>) -> fruit::Model {
+        fruit::Model {
+            id,
+            name: name.to_owned(),
+            cake_id,
+        }
+    }
+
+    #[smol_potat::test]
+    async fn load_many() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                fruit_model(1, "Apple", Some(1)),
+                fruit_model(2, "Orange", Some(2)),
+                fruit_model(3, "Banana", Some(1)),
+            ]])
+            .into_connection();
+
+        let cakes = vec![
+            cake_model(1, "Chocolate Forest"),
+            cake_model(2, "New York Cheese"),
+            cake_model(3, "Lemon"),
+        ];
+
+        assert_eq!(
+            cakes.load_many(fruit::Entity, &db).await?,
+            vec![
+                vec![
+                    fruit_model(1, "Apple", Some(1)),
+                    fruit_model(3, "Banana", Some(1)),
+                ],
+                vec![fruit_model(2, "Orange", Some(2))],
+                vec![],
+            ]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                    r#"WHERE "fruit"."cake_id" IN ($1, $2, $3)"#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec![1i32.into(), 2i32.into(), 3i32.into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    mod tropical_cake {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+        use crate::tests_cfg::{cake, fruit};
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "cake")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub name: String,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl Related<fruit::Entity> for Entity {
+            fn to() -> RelationDef {
+                cake::Relation::TropicalFruit.def()
+            }
+        }
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[smol_potat::test]
+    async fn load_many_on_condition() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![fruit_model(1, "Tropical Mango", Some(1))]])
+            .into_connection();
+
+        let cakes = vec![tropical_cake::Model {
+            id: 1,
+            name: "Chocolate Forest".to_owned(),
+        }];
+
+        assert_eq!(
+            cakes.load_many(fruit::Entity, &db).await?,
+            vec![vec![fruit_model(1, "Tropical Mango", Some(1))]]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+                    r#"WHERE "fruit"."cake_id" IN ($1) AND "fruit"."name" LIKE $2"#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec![1i32.into(), "%tropical%".into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn load_one() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                cake_model(1, "Chocolate Forest"),
+                cake_model(2, "New York Cheese"),
+            ]])
+            .into_connection();
+
+        let fruits = vec![
+            fruit_model(1, "Apple", Some(1)),
+            fruit_model(2, "Orange", Some(2)),
+            fruit_model(3, "Banana", Some(1)),
+        ];
+
+        assert_eq!(
+            fruits.as_slice().load_one(cake::Entity, &db).await?,
+            vec![
+                Some(cake_model(1, "Chocolate Forest")),
+                Some(cake_model(2, "New York Cheese")),
+                Some(cake_model(1, "Chocolate Forest")),
+            ]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" IN ($1, $2)"#,
+                vec![Some(1i32).into(), Some(2i32).into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn load_one_composite_key() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                cake_filling::Model {
+                    cake_id: 1,
+                    filling_id: 2,
+                },
+                cake_filling::Model {
+                    cake_id: 3,
+                    filling_id: 4,
+                },
+            ]])
+            .into_connection();
+
+        let prices = vec![
+            cake_filling_price::Model {
+                cake_id: 3,
+                filling_id: 4,
+                ignored_attr: 0,
+            },
+            cake_filling_price::Model {
+                cake_id: 1,
+                filling_id: 2,
+                ignored_attr: 0,
+            },
+        ];
+
+        assert_eq!(
+            prices.load_one(cake_filling::Entity, &db).await?,
+            vec![
+                Some(cake_filling::Model {
+                    cake_id: 3,
+                    filling_id: 4,
+                }),
+                Some(cake_filling::Model {
+                    cake_id: 1,
+                    filling_id: 2,
+                }),
+            ]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "cake_filling"."cake_id", "cake_filling"."filling_id" FROM "cake_filling""#,
+                    r#"WHERE ("cake_filling"."cake_id" = $1 AND "cake_filling"."filling_id" = $2)"#,
+                    r#"OR ("cake_filling"."cake_id" = $3 AND "cake_filling"."filling_id" = $4)"#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec![3i32.into(), 4i32.into(), 1i32.into(), 2i32.into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn load_empty() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+
+        let cakes: Vec<cake::Model> = vec![];
+
+        assert!(cakes.load_many(fruit::Entity, &db).await?.is_empty());
+        assert!(db.into_transaction_log().is_empty());
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn load_wrong_relation_type() {
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+
+        let cakes = vec![cake_model(1, "Chocolate Forest")];
+
+        assert!(matches!(
+            cakes.load_one(fruit::Entity, &db).await,
+            Err(DbErr::Query(_))
+        ));
+    }
+}
@@ -2,7 +2,9 @@ use crate::{ColumnTrait, EntityTrait, Iterable, QueryFilter, QueryOrder, QuerySe
 use core::fmt::Debug;
 use core::marker::PhantomData;
 pub use sea_query::JoinType;
-use sea_query::{Alias, DynIden, Expr, IntoColumnRef, SeaRc, SelectStatement, SimpleExpr};
+use sea_query::{
+    Alias, DynIden, Expr, IntoColumnRef, IntoIden, SeaRc, SelectStatement, SimpleExpr,
+};
 
 /// Defines a structure to perform select operations
 #[derive(Clone, Debug)]
@@ -100,6 +102,12 @@ impl IntoSimpleExpr for SimpleExpr {
     }
 }
 
+impl IntoSimpleExpr for Expr {
+    fn into_simple_expr(self) -> SimpleExpr {
+        self.into()
+    }
+}
+
 impl<E> Select<E>
 where
     E: EntityTrait,
@@ -113,13 +121,61 @@ where
         .prepare_from()
     }
 
+    /// Select from a derived table, i.e. `SELECT ... FROM (subquery) AS alias`,
+    /// instead of the table of the Entity.
+    ///
+    /// The columns of the Entity are selected from the derived table, qualified by `alias`.
+    /// Use [QuerySelect::select_only] to select other output columns of the subquery,
+    /// which can be referred to by name with `Expr::tbl(alias, Alias::new("column"))`.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    /// use sea_query::{Alias, Expr, Func, Query};
+    ///
+    /// let subquery = Query::select()
+    ///     .column(cake::Column::Name)
+    ///     .expr_as(Func::count(Expr::col(cake::Column::Id)), Alias::new("num_of_cakes"))
+    ///     .from(cake::Entity)
+    ///     .group_by_col(cake::Column::Name)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     Select::<cake::Entity>::from_subquery(subquery, Alias::new("t"))
+    ///         .select_only()
+    ///         .column_as(Expr::tbl(Alias::new("t"), Alias::new("name")), "name")
+    ///         .filter(Expr::tbl(Alias::new("t"), Alias::new("num_of_cakes")).gt(1))
+    ///         .order_by_desc(Expr::tbl(Alias::new("t"), Alias::new("num_of_cakes")))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "t"."name" AS "name" FROM (SELECT "name", COUNT("id") AS "num_of_cakes" FROM "cake" GROUP BY "name") AS "t""#,
+    ///         r#"WHERE "t"."num_of_cakes" > 1 ORDER BY "t"."num_of_cakes" DESC"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn from_subquery<T>(subquery: SelectStatement, alias: T) -> Self
+    where
+        T: IntoIden,
+    {
+        let alias = alias.into_iden();
+        let mut query = SelectStatement::new();
+        query
+            .exprs(Self::column_list(alias.clone()))
+            .from_subquery(subquery, alias);
+        Self {
+            query,
+            entity: PhantomData,
+        }
+    }
+
     fn prepare_select(mut self) -> Self {
-        self.query.exprs(self.column_list());
+        self.query
+            .exprs(Self::column_list(SeaRc::new(E::default()) as DynIden));
         self
     }
 
-    fn column_list(&self) -> Vec<SimpleExpr> {
-        let table = SeaRc::new(E::default()) as DynIden;
+    fn column_list(table: DynIden) -> Vec<SimpleExpr> {
         let text_type = SeaRc::new(Alias::new("text")) as DynIden;
         E::Column::iter()
             .map(|col| {
@@ -156,6 +212,15 @@ where
     }
 }
 
+impl<E> From<Select<E>> for SelectStatement
+where
+    E: EntityTrait,
+{
+    fn from(select: Select<E>) -> Self {
+        select.into_query()
+    }
+}
+
 macro_rules! select_two {
     ( $selector: ident ) => {
         impl<E, F> QueryTrait for $selector<E, F>
@@ -179,3 +244,221 @@ macro_rules! select_two {
 
 select_two!(SelectTwo);
 select_two!(SelectTwoMany);
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_cfg::{cake, fruit};
+    use crate::{
+        ColumnTrait, DbBackend, EntityTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait,
+        Select,
+    };
+    use sea_query::{Alias, Expr, Func};
+
+    #[test]
+    fn from_subquery_1() {
+        assert_eq!(
+            Select::<cake::Entity>::from_subquery(
+                cake::Entity::find()
+                    .filter(cake::Column::Name.contains("cheese"))
+                    .into_query(),
+                Alias::new("t"),
+            )
+            .filter(Expr::tbl(Alias::new("t"), cake::Column::Id).gt(2))
+            .build(DbBackend::MySql)
+            .to_string(),
+            [
+                "SELECT `t`.`id`, `t`.`name` FROM",
+                "(SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` LIKE '%cheese%') AS `t`",
+                "WHERE `t`.`id` > 2",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn from_subquery_2() {
+        let subquery = fruit::Entity::find()
+            .select_only()
+            .column(fruit::Column::CakeId)
+            .column_as(fruit::Column::Id.count(), "num_of_fruits")
+            .group_by(fruit::Column::CakeId)
+            .into_query();
+
+        assert_eq!(
+            Select::<fruit::Entity>::from_subquery(subquery, Alias::new("t"))
+                .select_only()
+                .column_as(
+                    Func::max(Expr::tbl(Alias::new("t"), Alias::new("num_of_fruits"))),
+                    "max_num_of_fruits"
+                )
+                .having(Expr::tbl(Alias::new("t"), Alias::new("num_of_fruits")).gt(1))
+                .order_by_asc(Expr::tbl(Alias::new("t"), Alias::new("cake_id")))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT MAX("t"."num_of_fruits") AS "max_num_of_fruits" FROM"#,
+                r#"(SELECT "fruit"."cake_id", COUNT("fruit"."id") AS "num_of_fruits" FROM "fruit" GROUP BY "fruit"."cake_id") AS "t""#,
+                r#"HAVING "t"."num_of_fruits" > 1 ORDER BY "t"."cake_id" ASC"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[cfg(feature = "mock")]
+    #[smol_potat::test]
+    async fn from_subquery_into_model() -> Result<(), crate::DbErr> {
+        use crate as sea_orm;
+        use crate::{FromQueryResult, MockDatabase, Transaction};
+
+        #[derive(Debug, PartialEq, FromQueryResult)]
+        struct CakeFruitCount {
+            cake_id: i32,
+            num_of_fruits: i64,
+        }
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![maplit::btreemap! {
+                "cake_id" => 1i32.into(),
+                "num_of_fruits" => 3i64.into(),
+            }]])
+            .into_connection();
+
+        let subquery = fruit::Entity::find()
+            .select_only()
+            .column(fruit::Column::CakeId)
+            .column_as(fruit::Column::Id.count(), "num_of_fruits")
+            .group_by(fruit::Column::CakeId)
+            .into_query();
+
+        assert_eq!(
+            Select::<fruit::Entity>::from_subquery(subquery, Alias::new("t"))
+                .select_only()
+                .column_as(Expr::tbl(Alias::new("t"), Alias::new("cake_id")), "cake_id")
+                .column_as(
+                    Expr::tbl(Alias::new("t"), Alias::new("num_of_fruits")),
+                    "num_of_fruits"
+                )
+                .filter(Expr::tbl(Alias::new("t"), Alias::new("num_of_fruits")).gt(2))
+                .into_model::<CakeFruitCount>()
+                .all(&db)
+                .await?,
+            vec![CakeFruitCount {
+                cake_id: 1,
+                num_of_fruits: 3,
+            }]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "t"."cake_id" AS "cake_id", "t"."num_of_fruits" AS "num_of_fruits" FROM"#,
+                    r#"(SELECT "fruit"."cake_id", COUNT("fruit"."id") AS "num_of_fruits" FROM "fruit" GROUP BY "fruit"."cake_id") AS "t""#,
+                    r#"WHERE "t"."num_of_fruits" > $1"#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec![2i32.into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    fn category_ancestors() -> sea_query::WithQuery {
+        use sea_query::{CommonTableExpression, Query, UnionType, WithClause};
+
+        let base = Query::select()
+            .columns([Alias::new("id"), Alias::new("parent_id")])
+            .from(Alias::new("category"))
+            .and_where(Expr::col(Alias::new("id")).eq(5))
+            .to_owned();
+        let recursive = Query::select()
+            .column((Alias::new("c"), Alias::new("id")))
+            .column((Alias::new("c"), Alias::new("parent_id")))
+            .from_as(Alias::new("category"), Alias::new("c"))
+            .inner_join(
+                Alias::new("ancestor"),
+                Expr::tbl(Alias::new("c"), Alias::new("id"))
+                    .equals(Alias::new("ancestor"), Alias::new("parent_id")),
+            )
+            .to_owned();
+        let cte = CommonTableExpression::new()
+            .query(base.clone().union(UnionType::All, recursive).to_owned())
+            .columns([Alias::new("id"), Alias::new("parent_id")])
+            .table_name(Alias::new("ancestor"))
+            .to_owned();
+
+        Query::select()
+            .columns([Alias::new("id"), Alias::new("parent_id")])
+            .from(Alias::new("ancestor"))
+            .to_owned()
+            .with(WithClause::new().recursive(true).cte(cte).to_owned())
+    }
+
+    #[test]
+    fn with_recursive_cte() {
+        let sql = [
+            r#"WITH RECURSIVE "ancestor" ("id", "parent_id") AS"#,
+            r#"(SELECT "id", "parent_id" FROM "category" WHERE "id" = 5"#,
+            r#"UNION ALL SELECT "c"."id", "c"."parent_id" FROM "category" AS "c""#,
+            r#"INNER JOIN "ancestor" ON "c"."id" = "ancestor"."parent_id")"#,
+            r#"SELECT "id", "parent_id" FROM "ancestor""#,
+        ]
+        .join(" ");
+
+        assert_eq!(
+            DbBackend::Postgres.build(&category_ancestors()).to_string(),
+            sql
+        );
+        assert_eq!(
+            DbBackend::Sqlite.build(&category_ancestors()).to_string(),
+            sql
+        );
+    }
+
+    #[cfg(feature = "mock")]
+    #[smol_potat::test]
+    async fn with_recursive_cte_into_model() -> Result<(), crate::DbErr> {
+        use crate as sea_orm;
+        use crate::{FromQueryResult, MockDatabase, StatementBuilder, Transaction};
+
+        #[derive(Debug, PartialEq, FromQueryResult)]
+        struct Category {
+            id: i32,
+            parent_id: Option<i32>,
+        }
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                maplit::btreemap! {
+                    "id" => 5i32.into(),
+                    "parent_id" => Some(2i32).into(),
+                },
+                maplit::btreemap! {
+                    "id" => 2i32.into(),
+                    "parent_id" => Option::<i32>::None.into(),
+                },
+            ]])
+            .into_connection();
+
+        let stmt = category_ancestors().build(&DbBackend::Postgres);
+        assert_eq!(
+            Category::find_by_statement(stmt.clone()).all(&db).await?,
+            vec![
+                Category {
+                    id: 5,
+                    parent_id: Some(2),
+                },
+                Category {
+                    id: 2,
+                    parent_id: None,
+                },
+            ]
+        );
+        assert_eq!(db.into_transaction_log(), vec![Transaction::one(stmt)]);
+
+        Ok(())
+    }
+}
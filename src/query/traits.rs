@@ -23,4 +23,168 @@ pub trait QueryTrait {
             self.as_query().build_any(query_builder.as_ref()),
         )
     }
+
+    /// Build the query as a multi-line SQL string with its parameters inlined, for debugging.
+    /// The values are escaped for display only, execute the [`Statement`] returned by
+    /// [QueryTrait::build] to bind them.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(cake::Column::Name.contains("cheese"))
+    ///         .order_by_asc(cake::Column::Id)
+    ///         .build_pretty(DbBackend::Postgres),
+    ///     [
+    ///         r#"SELECT "cake"."id", "cake"."name""#,
+    ///         r#"FROM "cake""#,
+    ///         r#"WHERE "cake"."name" LIKE '%cheese%'"#,
+    ///         r#"ORDER BY "cake"."id" ASC"#,
+    ///     ]
+    ///     .join("\n")
+    /// );
+    /// ```
+    fn build_pretty(&self, db_backend: DbBackend) -> String {
+        pretty_print(&self.build(db_backend).to_string())
+    }
+}
+
+/// The clauses starting on a new line, longer ones first so that `INNER JOIN` is not split
+const CLAUSES: [&str; 17] = [
+    "ON DUPLICATE KEY UPDATE",
+    "FULL OUTER JOIN",
+    "ON CONFLICT",
+    "INNER JOIN",
+    "CROSS JOIN",
+    "RIGHT JOIN",
+    "LEFT JOIN",
+    "RETURNING",
+    "GROUP BY",
+    "ORDER BY",
+    "HAVING",
+    "OFFSET",
+    "VALUES",
+    "WHERE",
+    "LIMIT",
+    "UNION",
+    "FROM",
+];
+
+/// Break a SQL string before each top level clause, skipping quoted strings,
+/// identifiers and parenthesized subqueries
+fn pretty_print(sql: &str) -> String {
+    let mut pretty = String::with_capacity(sql.len());
+    let mut quote = None;
+    let mut depth = 0usize;
+    let mut chars = sql.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match quote {
+            Some(q) => {
+                pretty.push(c);
+                if c == '\\' {
+                    if let Some((_, escaped)) = chars.next() {
+                        pretty.push(escaped);
+                    }
+                } else if c == q {
+                    quote = None;
+                }
+                continue;
+            }
+            None if matches!(c, '\'' | '"' | '`') => quote = Some(c),
+            None if c == '(' => depth += 1,
+            None if c == ')' => depth = depth.saturating_sub(1),
+            None => {}
+        }
+        let clause = match (depth, pretty.ends_with(' ')) {
+            (0, true) => CLAUSES.iter().find(|clause| {
+                let rest = &sql[i..];
+                rest.starts_with(*clause)
+                    && rest[clause.len()..]
+                        .chars()
+                        .next()
+                        .map_or(true, |c| c == ' ')
+            }),
+            _ => None,
+        };
+        match clause {
+            Some(clause) => {
+                pretty.pop();
+                pretty.push('\n');
+                pretty.push_str(clause);
+                for _ in 1..clause.chars().count() {
+                    chars.next();
+                }
+            }
+            None => pretty.push(c),
+        }
+    }
+    pretty
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{entity::*, query::*, tests_cfg::*, DbBackend};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn build_placeholders_and_inlined() {
+        let query = cake::Entity::find()
+            .filter(cake::Column::Name.eq("O'Brien's \\ Cake"))
+            .filter(cake::Column::Id.gt(1));
+
+        let stmt = query.build(DbBackend::Postgres);
+        assert_eq!(
+            stmt.sql,
+            [
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                r#"WHERE "cake"."name" = $1 AND "cake"."id" > $2"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            stmt.values,
+            Some(sea_query::Values(vec![
+                "O'Brien's \\ Cake".into(),
+                1i32.into()
+            ]))
+        );
+
+        assert_eq!(
+            query.build_pretty(DbBackend::Postgres),
+            [
+                r#"SELECT "cake"."id", "cake"."name""#,
+                r#"FROM "cake""#,
+                r#"WHERE "cake"."name" = E'O\'Brien\'s \\ Cake' AND "cake"."id" > 1"#,
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn build_pretty_nested() {
+        assert_eq!(
+            cake::Entity::find()
+                .filter(cake::Column::Name.eq("FROM WHERE"))
+                .filter(
+                    cake::Column::Id.in_subquery(
+                        sea_query::Query::select()
+                            .column(fruit::Column::CakeId)
+                            .from(fruit::Entity)
+                            .to_owned()
+                    )
+                )
+                .left_join(fruit::Entity)
+                .limit(10)
+                .build_pretty(DbBackend::MySql),
+            [
+                "SELECT `cake`.`id`, `cake`.`name`",
+                "FROM `cake`",
+                "LEFT JOIN `fruit` ON `cake`.`id` = `fruit`.`cake_id`",
+                "WHERE `cake`.`name` = 'FROM WHERE' AND `cake`.`id` IN (SELECT `cake_id` FROM `fruit`)",
+                "LIMIT 10",
+            ]
+            .join("\n")
+        );
+    }
 }
@@ -1,9 +1,12 @@
 use crate::{
-    ActiveModelTrait, ColumnTrait, EntityTrait, Iterable, PrimaryKeyToColumn, QueryFilter,
-    QueryTrait,
+    executor::returning_columns, ActiveModelTrait, ColumnTrait, ColumnType, DbErr, EntityTrait,
+    IdenStatic, Iterable, PrimaryKeyToColumn, QueryFilter, QueryTrait,
 };
 use core::marker::PhantomData;
-use sea_query::{Alias, Expr, IntoIden, SimpleExpr, UpdateStatement};
+use sea_query::{
+    Alias, CaseStatement, Condition, Expr, Func, IntoIden, ReturningClause, SimpleExpr,
+    UpdateStatement,
+};
 
 /// Defines a structure to perform UPDATE query operations on a ActiveModel
 #[derive(Clone, Debug)]
@@ -26,6 +29,7 @@ where
     E: EntityTrait,
 {
     pub(crate) query: UpdateStatement,
+    pub(crate) returning: Option<ReturningClause>,
     pub(crate) entity: PhantomData<E>,
 }
 
@@ -80,6 +84,7 @@ impl Update {
     {
         UpdateMany {
             query: UpdateStatement::new().table(entity.table_ref()).to_owned(),
+            returning: None,
             entity: PhantomData,
         }
     }
@@ -99,12 +104,22 @@ where
                 panic!("PrimaryKey is not set");
             }
         }
+        if let Some(col) = <A::Entity as EntityTrait>::version_column() {
+            let av = self.model.get(col);
+            if av.is_set() || av.is_unchanged() {
+                self = self.filter(col.eq(av.unwrap()));
+            }
+        }
         self
     }
 
     fn prepare_values(mut self) -> Self {
+        let version_column = <A::Entity as EntityTrait>::version_column();
         for col in <A::Entity as EntityTrait>::Column::iter() {
-            if <A::Entity as EntityTrait>::PrimaryKey::from_column(col).is_some() {
+            if <A::Entity as EntityTrait>::PrimaryKey::from_column(col).is_some()
+                || matches!(version_column, Some(v) if v.as_str() == col.as_str())
+                || col.def().is_generated()
+            {
                 continue;
             }
             let av = self.model.get(col);
@@ -112,13 +127,27 @@ where
                 let val = Expr::val(av.into_value().unwrap());
                 let col_def = col.def();
                 let col_type = col_def.get_column_type();
-                let expr = match col_type.get_enum_name() {
-                    Some(enum_name) => val.as_enum(Alias::new(enum_name)),
+                let expr = match col_type.get_cast_name() {
+                    Some(type_name) => val.as_enum(Alias::new(type_name)),
                     None => val.into(),
                 };
                 self.query.value_expr(col, expr);
             }
         }
+        if let Some(col) = version_column {
+            let expr = match col.def().get_column_type() {
+                ColumnType::TinyInteger
+                | ColumnType::SmallInteger
+                | ColumnType::Integer
+                | ColumnType::BigInteger
+                | ColumnType::TinyUnsigned
+                | ColumnType::SmallUnsigned
+                | ColumnType::Unsigned
+                | ColumnType::BigUnsigned => Expr::col(col).add(1),
+                _ => Func::current_timestamp(),
+            };
+            self.query.value_expr(col, expr);
+        }
         self
     }
 }
@@ -194,13 +223,126 @@ where
     {
         for col in E::Column::iter() {
             let av = model.get(col);
-            if av.is_set() {
+            if av.is_set() && !col.def().is_generated() {
                 self.query.value(col, av.unwrap());
             }
         }
         self
     }
 
+    /// Update each of the models to its own values in a single statement, matching the rows by
+    /// primary key. Every column set in any of the models is assigned a `CASE` expression,
+    /// rows whose model leaves the column unset keep their current value.
+    ///
+    /// Returns a [DbErr::Query] if a model has no primary key, or if none of the models sets a
+    /// column other than the primary key, which leaves nothing to update.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, error::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// # fn main() -> Result<(), DbErr> {
+    /// assert_eq!(
+    ///     Update::many(fruit::Entity)
+    ///         .set_many([
+    ///             fruit::ActiveModel {
+    ///                 id: ActiveValue::set(1),
+    ///                 name: ActiveValue::set("Apple".to_owned()),
+    ///                 cake_id: ActiveValue::not_set(),
+    ///             },
+    ///             fruit::ActiveModel {
+    ///                 id: ActiveValue::set(2),
+    ///                 name: ActiveValue::set("Orange".to_owned()),
+    ///                 cake_id: ActiveValue::set(Some(3)),
+    ///             },
+    ///         ])?
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"UPDATE "fruit" SET"#,
+    ///         r#""name" = (CASE WHEN ("fruit"."id" = 1) THEN 'Apple' WHEN ("fruit"."id" = 2) THEN 'Orange' ELSE "name" END)"#,
+    ///         r#", "cake_id" = (CASE WHEN ("fruit"."id" = 2) THEN 3 ELSE "cake_id" END)"#,
+    ///         r#"WHERE "fruit"."id" IN (1, 2)"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_many<A, I>(mut self, models: I) -> Result<Self, DbErr>
+    where
+        A: ActiveModelTrait<Entity = E>,
+        I: IntoIterator<Item = A>,
+    {
+        let models: Vec<A> = models.into_iter().collect();
+        let keys: Vec<Vec<_>> = models
+            .iter()
+            .map(|model| {
+                E::PrimaryKey::iter()
+                    .map(|key| {
+                        let av = model.get(key.into_column());
+                        if av.is_set() || av.is_unchanged() {
+                            Ok(av.into_value().unwrap())
+                        } else {
+                            Err(DbErr::Query("PrimaryKey is not set".to_owned()))
+                        }
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        let conds: Vec<Condition> = keys
+            .iter()
+            .map(|key| {
+                E::PrimaryKey::iter()
+                    .zip(key)
+                    .fold(Condition::all(), |cond, (pk, v)| {
+                        cond.add(pk.into_column().eq(v.clone()))
+                    })
+            })
+            .collect();
+
+        let mut updated = false;
+        for col in E::Column::iter() {
+            if E::PrimaryKey::from_column(col).is_some() || col.def().is_generated() {
+                continue;
+            }
+            let mut case: Option<CaseStatement> = None;
+            for (model, cond) in models.iter().zip(conds.iter()) {
+                let av = model.get(col);
+                if !av.is_set() {
+                    continue;
+                }
+                let val = Expr::val(av.into_value().unwrap());
+                let val = match col.def().get_column_type().get_cast_name() {
+                    Some(type_name) => Expr::expr(val.as_enum(Alias::new(type_name))),
+                    None => val,
+                };
+                case = Some(case.unwrap_or_default().case(cond.clone(), val));
+            }
+            if let Some(case) = case {
+                let case = case.finally(Expr::col(col));
+                self.query.col_expr(col, SimpleExpr::Case(Box::new(case)));
+                updated = true;
+            }
+        }
+        if !updated {
+            return Err(DbErr::Query(
+                "None of the models sets a column to update".to_owned(),
+            ));
+        }
+
+        let mut pks = E::PrimaryKey::iter();
+        match (pks.next(), pks.next()) {
+            (Some(pk), None) => {
+                self = self.filter(
+                    pk.into_column()
+                        .is_in(keys.into_iter().flat_map(|key| key.into_iter())),
+                )
+            }
+            _ => self = self.filter(conds.into_iter().fold(Condition::any(), Condition::add)),
+        }
+        Ok(self)
+    }
+
     /// Creates a [SimpleExpr] from a column
     pub fn col_expr<T>(mut self, col: T, expr: SimpleExpr) -> Self
     where
@@ -209,12 +351,39 @@ where
         self.query.col_expr(col, expr);
         self
     }
+
+    /// Only return the given columns of the updated rows, when executing with
+    /// [UpdateMany::exec_with_returning_as](crate::UpdateMany::exec_with_returning_as) or
+    /// [UpdateMany::exec_with_returning_tuple](crate::UpdateMany::exec_with_returning_tuple)
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::Expr, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     Update::many(fruit::Entity)
+    ///         .col_expr(fruit::Column::CakeId, Expr::value(Value::Int(None)))
+    ///         .filter(fruit::Column::Name.contains("Apple"))
+    ///         .returning([fruit::Column::Id, fruit::Column::Name])
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"UPDATE "fruit" SET "cake_id" = NULL WHERE "fruit"."name" LIKE '%Apple%' RETURNING "id", "name""#,
+    /// );
+    /// ```
+    pub fn returning<I>(mut self, cols: I) -> Self
+    where
+        I: IntoIterator<Item = E::Column>,
+    {
+        let returning = returning_columns(cols);
+        self.query.returning(returning.clone());
+        self.returning = Some(returning);
+        self
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::tests_cfg::{cake, fruit};
-    use crate::{entity::*, query::*, DbBackend};
+    use crate::tests_cfg::{cake, fruit, line_item};
+    use crate::{entity::*, query::*, DbBackend, DbErr};
     use sea_query::{Expr, Value};
 
     #[test]
@@ -300,4 +469,96 @@ mod tests {
             r#"UPDATE "fruit" SET "id" = 3 WHERE "fruit"."id" = 2"#,
         );
     }
+
+    #[test]
+    fn update_many_set_many() -> Result<(), DbErr> {
+        assert_eq!(
+            Update::many(fruit::Entity)
+                .set_many([
+                    fruit::ActiveModel {
+                        id: ActiveValue::set(1),
+                        name: ActiveValue::set("Apple".to_owned()),
+                        cake_id: ActiveValue::set(None),
+                    },
+                    fruit::ActiveModel {
+                        id: ActiveValue::unchanged(2),
+                        name: ActiveValue::not_set(),
+                        cake_id: ActiveValue::set(Some(3)),
+                    },
+                    fruit::ActiveModel {
+                        id: ActiveValue::set(3),
+                        name: ActiveValue::not_set(),
+                        cake_id: ActiveValue::not_set(),
+                    },
+                ])?
+                .filter(fruit::Column::Name.contains("Apple"))
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "UPDATE `fruit` SET",
+                "`name` = (CASE WHEN (`fruit`.`id` = 1) THEN 'Apple' ELSE `name` END) ,",
+                "`cake_id` = (CASE WHEN (`fruit`.`id` = 1) THEN NULL WHEN (`fruit`.`id` = 2) THEN 3 ELSE `cake_id` END)",
+                "WHERE `fruit`.`id` IN (1, 2, 3) AND `fruit`.`name` LIKE '%Apple%'",
+            ]
+            .join(" ")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn update_many_set_many_nothing_to_update() {
+        assert_eq!(
+            Update::many(cake::Entity)
+                .set_many(Vec::<cake::ActiveModel>::new())
+                .err(),
+            Some(DbErr::Query(
+                "None of the models sets a column to update".to_owned()
+            ))
+        );
+        assert_eq!(
+            Update::many(cake::Entity)
+                .set_many([cake::ActiveModel {
+                    id: ActiveValue::set(1),
+                    name: ActiveValue::not_set(),
+                }])
+                .err(),
+            Some(DbErr::Query(
+                "None of the models sets a column to update".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn update_generated_column() {
+        let line_item = line_item::Model {
+            id: 1,
+            price: 250,
+            quantity: 2,
+            total: 500,
+        };
+        assert_eq!(
+            Update::one(line_item::ActiveModel {
+                quantity: ActiveValue::set(3),
+                total: ActiveValue::set(750),
+                ..line_item.into_active_model()
+            })
+            .build(DbBackend::Postgres)
+            .to_string(),
+            r#"UPDATE "line_item" SET "quantity" = 3 WHERE "line_item"."id" = 1"#,
+        );
+    }
+
+    #[test]
+    fn update_many_set_many_without_primary_key() {
+        assert_eq!(
+            Update::many(cake::Entity)
+                .set_many([cake::ActiveModel {
+                    id: ActiveValue::not_set(),
+                    name: ActiveValue::set("Apple Pie".to_owned()),
+                }])
+                .err(),
+            Some(DbErr::Query("PrimaryKey is not set".to_owned()))
+        );
+    }
 }
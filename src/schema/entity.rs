@@ -1,6 +1,6 @@
 use crate::{
     unpack_table_ref, ActiveEnum, ColumnTrait, ColumnType, DbBackend, EntityTrait, Identity,
-    Iterable, PrimaryKeyToColumn, PrimaryKeyTrait, RelationTrait, Schema,
+    IndexDef, Iterable, PrimaryKeyToColumn, PrimaryKeyTrait, RelationTrait, Schema, Statement,
 };
 use sea_query::{
     extension::postgres::{Type, TypeCreateStatement},
@@ -17,6 +17,16 @@ impl Schema {
         create_enum_from_active_enum::<A>(self.backend)
     }
 
+    /// Creates Postgres enums from an ActiveEnum, returning `None` for MySQL & SQLite,
+    /// where enums are simulated by string columns and check constraints,
+    /// or if the ActiveEnum is not mapped onto a native enum. See [TypeCreateStatement] for more details
+    pub fn try_create_enum_from_active_enum<A>(&self) -> Option<TypeCreateStatement>
+    where
+        A: ActiveEnum,
+    {
+        try_create_enum_from_active_enum::<A>(self.backend)
+    }
+
     /// Creates Postgres enums from an Entity. See [TypeCreateStatement] for more details
     pub fn create_enum_from_entity<E>(&self, entity: E) -> Vec<TypeCreateStatement>
     where
@@ -34,13 +44,80 @@ impl Schema {
     }
 
     /// Creates the indexes from an Entity, returning an empty Vec if there are none
-    /// to create. See [IndexCreateStatement] for more details
+    /// to create. This covers the indexed columns and the multi-column indexes of
+    /// [EntityTrait::indexes], except partial indexes which are created by
+    /// [Schema::create_partial_index_from_entity]. See [IndexCreateStatement] for more details
     pub fn create_index_from_entity<E>(&self, entity: E) -> Vec<IndexCreateStatement>
     where
         E: EntityTrait,
     {
         create_index_from_entity(entity, self.backend)
     }
+
+    /// Creates the partial indexes of [EntityTrait::indexes], returning an empty Vec if there
+    /// are none to create. [IndexCreateStatement] has no `WHERE` clause, so the statements
+    /// are built for the backend of the schema. Panics on MySQL, which has no partial indexes
+    pub fn create_partial_index_from_entity<E>(&self, entity: E) -> Vec<Statement>
+    where
+        E: EntityTrait,
+    {
+        create_partial_index_from_entity(entity, self.backend)
+    }
+
+    /// Creates the tables of many Entities, ordered so that a table is created after the tables
+    /// its foreign keys refer to. A foreign key closing a cycle between the tables is created by
+    /// a separate `ALTER TABLE` statement after all the tables, except on SQLite, which cannot
+    /// add constraints to an existing table but doesn't check them on creation either.
+    ///
+    /// The statements are built for the backend of the schema and meant to be executed in order.
+    /// Postgres enums and indexes are created by [Schema::create_enum_from_entity] and
+    /// [Schema::create_index_from_entity].
+    ///
+    /// ```
+    /// use sea_orm::{tests_cfg::*, DbBackend, Schema};
+    ///
+    /// let stmts = Schema::new(DbBackend::Postgres).create_tables_from_entities(&[
+    ///     &cake_filling::Entity,
+    ///     &cake::Entity,
+    ///     &filling::Entity,
+    /// ]);
+    ///
+    /// assert!(stmts[0].to_string().starts_with(r#"CREATE TABLE "cake" "#));
+    /// assert!(stmts[1].to_string().starts_with(r#"CREATE TABLE "filling" "#));
+    /// assert!(stmts[2].to_string().starts_with(r#"CREATE TABLE "cake_filling" "#));
+    /// ```
+    pub fn create_tables_from_entities(&self, entities: &[&dyn EntityTable]) -> Vec<Statement> {
+        create_tables_from_entities(entities, self.backend)
+    }
+}
+
+/// An Entity of [Schema::create_tables_from_entities], implemented for every [EntityTrait]
+pub trait EntityTable {
+    /// The name of the table of the Entity
+    fn entity_table_name(&self) -> String;
+
+    /// Creates the table of the Entity, leaving out its foreign keys
+    fn create_table_without_foreign_keys(&self, backend: DbBackend) -> TableCreateStatement;
+
+    /// Creates the foreign keys of the Entity
+    fn create_foreign_keys(&self) -> Vec<ForeignKeyCreateStatement>;
+}
+
+impl<E> EntityTable for E
+where
+    E: EntityTrait,
+{
+    fn entity_table_name(&self) -> String {
+        self.table_name().to_owned()
+    }
+
+    fn create_table_without_foreign_keys(&self, backend: DbBackend) -> TableCreateStatement {
+        create_table_without_foreign_keys(*self, backend)
+    }
+
+    fn create_foreign_keys(&self) -> Vec<ForeignKeyCreateStatement> {
+        create_foreign_keys_from_entity(*self)
+    }
 }
 
 pub(crate) fn create_enum_from_active_enum<A>(backend: DbBackend) -> TypeCreateStatement
@@ -55,6 +132,21 @@ where
     create_enum_from_column_type(col_type)
 }
 
+pub(crate) fn try_create_enum_from_active_enum<A>(backend: DbBackend) -> Option<TypeCreateStatement>
+where
+    A: ActiveEnum,
+{
+    if matches!(backend, DbBackend::MySql | DbBackend::Sqlite) {
+        return None;
+    }
+    let col_def = A::db_type();
+    let col_type = col_def.get_column_type();
+    if !matches!(col_type, ColumnType::Enum(_, _)) {
+        return None;
+    }
+    Some(create_enum_from_column_type(col_type))
+}
+
 pub(crate) fn create_enum_from_column_type(col_type: &ColumnType) -> TypeCreateStatement {
     let (name, values) = match col_type {
         ColumnType::Enum(s, v) => (s.as_str(), v),
@@ -111,10 +203,164 @@ where
             .to_owned();
         vec.push(stmt)
     }
+    for index in E::indexes() {
+        if index.condition.is_some() {
+            continue;
+        }
+        vec.push(create_index_from_index_def(entity, &index));
+    }
     vec
 }
 
+pub(crate) fn create_partial_index_from_entity<E>(entity: E, backend: DbBackend) -> Vec<Statement>
+where
+    E: EntityTrait,
+{
+    let mut vec = Vec::new();
+    for index in E::indexes() {
+        let condition = match &index.condition {
+            Some(condition) => condition,
+            None => continue,
+        };
+        if matches!(backend, DbBackend::MySql) {
+            panic!("Partial index is not supported in MySQL");
+        }
+        let mut stmt = backend.build(&create_index_from_index_def(entity, &index));
+        stmt.sql = format!("{} WHERE {}", stmt.sql, condition);
+        vec.push(stmt);
+    }
+    vec
+}
+
+fn create_index_from_index_def<E>(entity: E, index: &IndexDef<E::Column>) -> IndexCreateStatement
+where
+    E: EntityTrait,
+{
+    let mut stmt = Index::create();
+    let name = match &index.name {
+        Some(name) => name.to_owned(),
+        None => format!(
+            "idx-{}-{}",
+            entity.to_string(),
+            index
+                .columns
+                .iter()
+                .map(|column| column.to_string())
+                .collect::<Vec<_>>()
+                .join("-")
+        ),
+    };
+    stmt.name(&name).table(entity);
+    for column in index.columns.iter() {
+        stmt.col(*column);
+    }
+    if index.unique {
+        stmt.unique();
+    }
+    stmt
+}
+
+/// Render a list of string literals, escaped according to the backend's quoting rules
+fn quote_values(backend: DbBackend, values: &[String]) -> String {
+    let query_builder = backend.get_query_builder();
+    values
+        .iter()
+        .map(|value| query_builder.value_to_string(&value.as_str().into()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    New,
+    Visiting,
+    Done,
+}
+
+struct TableToCreate {
+    name: String,
+    stmt: TableCreateStatement,
+    foreign_keys: Vec<ForeignKeyCreateStatement>,
+}
+
+pub(crate) fn create_tables_from_entities(
+    entities: &[&dyn EntityTable],
+    backend: DbBackend,
+) -> Vec<Statement> {
+    let mut tables: Vec<TableToCreate> = entities
+        .iter()
+        .map(|entity| TableToCreate {
+            name: entity.entity_table_name(),
+            stmt: entity.create_table_without_foreign_keys(backend),
+            foreign_keys: entity.create_foreign_keys(),
+        })
+        .collect();
+    let mut visits = vec![Visit::New; tables.len()];
+    let mut order = Vec::with_capacity(tables.len());
+    let mut deferred = Vec::new();
+    for i in 0..tables.len() {
+        if visits[i] == Visit::New {
+            visit_table(
+                i,
+                backend,
+                &mut tables,
+                &mut visits,
+                &mut order,
+                &mut deferred,
+            );
+        }
+    }
+    order
+        .into_iter()
+        .map(|i| backend.build(&tables[i].stmt))
+        .chain(
+            deferred
+                .iter()
+                .map(|foreign_key| backend.build(foreign_key)),
+        )
+        .collect()
+}
+
+/// Visit the tables referred to by the foreign keys of table `i` depth first, then append `i` to `order`
+fn visit_table(
+    i: usize,
+    backend: DbBackend,
+    tables: &mut [TableToCreate],
+    visits: &mut [Visit],
+    order: &mut Vec<usize>,
+    deferred: &mut Vec<ForeignKeyCreateStatement>,
+) {
+    visits[i] = Visit::Visiting;
+    for mut foreign_key in std::mem::take(&mut tables[i].foreign_keys) {
+        let ref_table = foreign_key.get_foreign_key().get_ref_table();
+        match ref_table.and_then(|name| tables.iter().position(|table| table.name == name)) {
+            Some(j) if j != i && visits[j] == Visit::Visiting && backend != DbBackend::Sqlite => {
+                deferred.push(foreign_key);
+                continue;
+            }
+            Some(j) if visits[j] == Visit::New => {
+                visit_table(j, backend, tables, visits, order, deferred);
+            }
+            _ => {}
+        }
+        tables[i].stmt.foreign_key(&mut foreign_key);
+    }
+    visits[i] = Visit::Done;
+    order.push(i);
+}
+
 pub(crate) fn create_table_from_entity<E>(entity: E, backend: DbBackend) -> TableCreateStatement
+where
+    E: EntityTrait,
+{
+    let mut stmt = create_table_without_foreign_keys(entity, backend);
+    for mut foreign_key in create_foreign_keys_from_entity(entity) {
+        stmt.foreign_key(&mut foreign_key);
+    }
+    stmt
+}
+
+fn create_table_without_foreign_keys<E>(entity: E, backend: DbBackend) -> TableCreateStatement
 where
     E: EntityTrait,
 {
@@ -125,7 +371,7 @@ where
         let types = match orm_column_def.col_type {
             ColumnType::Enum(s, variants) => match backend {
                 DbBackend::MySql => {
-                    ColumnType::Custom(format!("ENUM('{}')", variants.join("', '")))
+                    ColumnType::Custom(format!("ENUM({})", quote_values(backend, &variants)))
                 }
                 DbBackend::Postgres => ColumnType::Custom(s),
                 DbBackend::Sqlite => ColumnType::Text,
@@ -143,6 +389,27 @@ where
         if let Some(value) = orm_column_def.default_value {
             column_def.default(value);
         }
+        if let Some(expr) = orm_column_def.generated {
+            column_def.extra(format!(
+                "GENERATED ALWAYS AS ({}) {}",
+                expr,
+                if orm_column_def.generated_stored {
+                    "STORED"
+                } else {
+                    "VIRTUAL"
+                }
+            ));
+        }
+        if let Some(values) = orm_column_def.check_values {
+            // MySQL parses but ignores `CHECK` constraints before 8.0.16, so it is skipped there
+            if matches!(backend, DbBackend::Postgres | DbBackend::Sqlite) {
+                column_def.extra(format!(
+                    "CHECK (\"{}\" IN ({}))",
+                    column.to_string().replace('"', "\"\""),
+                    quote_values(backend, &values)
+                ));
+            }
+        }
         for primary_key in E::PrimaryKey::iter() {
             if column.to_string() == primary_key.into_column().to_string() {
                 if E::PrimaryKey::auto_increment() {
@@ -164,6 +431,14 @@ where
         stmt.primary_key(idx_pk.name(&format!("pk-{}", entity.to_string())).primary());
     }
 
+    stmt.table(entity.table_ref()).take()
+}
+
+fn create_foreign_keys_from_entity<E>(_: E) -> Vec<ForeignKeyCreateStatement>
+where
+    E: EntityTrait,
+{
+    let mut foreign_keys = Vec::new();
     for relation in E::Relation::iter() {
         let relation = relation.def();
         if relation.is_owner {
@@ -209,22 +484,295 @@ where
         } else {
             format!("fk-{}-{}", from_tbl.to_string(), from_cols.join("-"))
         };
-        stmt.foreign_key(
+        foreign_keys.push(
             foreign_key_stmt
                 .name(&name)
                 .from_tbl(from_tbl)
-                .to_tbl(to_tbl),
+                .to_tbl(to_tbl)
+                .take(),
         );
     }
 
-    stmt.table(entity.table_ref()).take()
+    foreign_keys
 }
 
 #[cfg(test)]
 mod tests {
+    use crate as sea_orm;
     use crate::{sea_query::*, tests_cfg::*, DbBackend, EntityName, Schema};
     use pretty_assertions::assert_eq;
 
+    #[derive(Debug, PartialEq, Eq, sea_orm::EnumIter, sea_orm::DeriveActiveEnum)]
+    #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "category")]
+    pub enum Category {
+        #[sea_orm(string_value = "B")]
+        Big,
+        #[sea_orm(string_value = "S")]
+        Small,
+    }
+
+    #[derive(Debug, PartialEq, Eq, sea_orm::EnumIter, sea_orm::DeriveActiveEnum)]
+    #[sea_orm(rs_type = "String", db_type = "String(Some(1))")]
+    pub enum Size {
+        #[sea_orm(string_value = "B")]
+        Big,
+        #[sea_orm(string_value = "S")]
+        Small,
+    }
+
+    mod task {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Copy, Clone, Default, Debug, DeriveEntity)]
+        pub struct Entity;
+
+        impl EntityName for Entity {
+            fn table_name(&self) -> &str {
+                "task"
+            }
+        }
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveModel, DeriveActiveModel)]
+        pub struct Model {
+            pub id: i32,
+            pub status: Status,
+            pub checked_status: CheckedStatus,
+        }
+
+        #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "task_status")]
+        pub enum Status {
+            #[sea_orm(string_value = "En cours")]
+            InProgress,
+            #[sea_orm(string_value = "Terminé")]
+            Done,
+            #[sea_orm(string_value = "It's \\ late")]
+            Late,
+        }
+
+        #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+        #[sea_orm(rs_type = "String", db_type = "StringChecked")]
+        pub enum CheckedStatus {
+            #[sea_orm(string_value = "En cours")]
+            InProgress,
+            #[sea_orm(string_value = "Terminé")]
+            Done,
+            #[sea_orm(string_value = "It's \\ late")]
+            Late,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
+        pub enum Column {
+            Id,
+            Status,
+            CheckedStatus,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
+        pub enum PrimaryKey {
+            Id,
+        }
+
+        impl PrimaryKeyTrait for PrimaryKey {
+            type ValueType = i32;
+
+            fn auto_increment() -> bool {
+                false
+            }
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter)]
+        pub enum Relation {}
+
+        impl ColumnTrait for Column {
+            type EntityName = Entity;
+
+            fn def(&self) -> ColumnDef {
+                match self {
+                    Self::Id => ColumnType::Integer.def(),
+                    Self::Status => Status::db_type(),
+                    Self::CheckedStatus => CheckedStatus::db_type(),
+                }
+            }
+        }
+
+        impl RelationTrait for Relation {
+            fn def(&self) -> RelationDef {
+                panic!("No RelationDef")
+            }
+        }
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[test]
+    fn test_create_enum_and_table_escape_values() {
+        let db_postgres = DbBackend::Postgres;
+        let stmt = Schema::new(db_postgres)
+            .try_create_enum_from_active_enum::<task::Status>()
+            .unwrap();
+        assert_eq!(
+            db_postgres.build(&stmt).to_string(),
+            r#"CREATE TYPE "task_status" AS ENUM ('En cours', 'Terminé', E'It\'s \\ late')"#
+        );
+
+        assert_eq!(
+            db_postgres
+                .build(&Schema::new(db_postgres).create_table_from_entity(task::Entity))
+                .to_string(),
+            [
+                r#"CREATE TABLE "task" ("#,
+                r#""id" integer NOT NULL PRIMARY KEY,"#,
+                r#""status" task_status NOT NULL,"#,
+                r#""checked_status" varchar NOT NULL CHECK ("checked_status" IN ('En cours', 'Terminé', E'It\'s \\ late'))"#,
+                r#")"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            DbBackend::Sqlite
+                .build(&Schema::new(DbBackend::Sqlite).create_table_from_entity(task::Entity))
+                .to_string(),
+            [
+                r#"CREATE TABLE "task" ("#,
+                r#""id" integer NOT NULL PRIMARY KEY,"#,
+                r#""status" text NOT NULL,"#,
+                r#""checked_status" text NOT NULL CHECK ("checked_status" IN ('En cours', 'Terminé', 'It''s \ late'))"#,
+                r#")"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            DbBackend::MySql
+                .build(&Schema::new(DbBackend::MySql).create_table_from_entity(task::Entity))
+                .to_string(),
+            [
+                "CREATE TABLE `task` (",
+                "`id` int NOT NULL PRIMARY KEY,",
+                r#"`status` ENUM('En cours', 'Terminé', 'It\'s \\ late') NOT NULL,"#,
+                "`checked_status` varchar(255) NOT NULL",
+                ")",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn test_try_create_enum_from_active_enum() {
+        let db_postgres = DbBackend::Postgres;
+        let stmt = Schema::new(db_postgres)
+            .try_create_enum_from_active_enum::<Category>()
+            .unwrap();
+        assert_eq!(
+            db_postgres.build(&stmt).to_string(),
+            r#"CREATE TYPE "category" AS ENUM ('B', 'S')"#
+        );
+
+        assert!(Schema::new(db_postgres)
+            .try_create_enum_from_active_enum::<Size>()
+            .is_none());
+        for builder in [DbBackend::MySql, DbBackend::Sqlite] {
+            assert!(Schema::new(builder)
+                .try_create_enum_from_active_enum::<Category>()
+                .is_none());
+        }
+    }
+
+    mod account {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "account")]
+        #[sea_orm(index(columns = ["first_name", "last_name"]))]
+        #[sea_orm(index(name = "idx-account-email", columns = ["tenant_id", "email"], unique))]
+        #[sea_orm(index(columns = ["email"], unique, where = "deleted_at IS NULL"))]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub tenant_id: i32,
+            pub email: String,
+            pub first_name: String,
+            pub last_name: String,
+            pub deleted_at: Option<DateTime>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[test]
+    fn test_create_index_from_entity_compound() {
+        for builder in [DbBackend::MySql, DbBackend::Postgres, DbBackend::Sqlite] {
+            let schema = Schema::new(builder);
+            let stmts = schema.create_index_from_entity(account::Entity);
+            assert_eq!(stmts.len(), 2);
+
+            let idx: IndexCreateStatement = Index::create()
+                .name("idx-account-first_name-last_name")
+                .table(account::Entity)
+                .col(account::Column::FirstName)
+                .col(account::Column::LastName)
+                .to_owned();
+            assert_eq!(builder.build(&stmts[0]), builder.build(&idx));
+
+            let idx: IndexCreateStatement = Index::create()
+                .name("idx-account-email")
+                .table(account::Entity)
+                .col(account::Column::TenantId)
+                .col(account::Column::Email)
+                .unique()
+                .to_owned();
+            assert_eq!(builder.build(&stmts[1]), builder.build(&idx));
+        }
+
+        assert_eq!(
+            DbBackend::Postgres
+                .build(
+                    &Schema::new(DbBackend::Postgres).create_index_from_entity(account::Entity)[1]
+                )
+                .to_string(),
+            r#"CREATE UNIQUE INDEX "idx-account-email" ON "account" ("tenant_id", "email")"#
+        );
+        assert_eq!(
+            DbBackend::MySql
+                .build(&Schema::new(DbBackend::MySql).create_index_from_entity(account::Entity)[0])
+                .to_string(),
+            "CREATE INDEX `idx-account-first_name-last_name` ON `account` (`first_name`, `last_name`)"
+        );
+    }
+
+    #[test]
+    fn test_create_partial_index_from_entity() {
+        let stmts =
+            Schema::new(DbBackend::Postgres).create_partial_index_from_entity(account::Entity);
+        assert_eq!(stmts.len(), 1);
+        assert_eq!(
+            stmts[0].to_string(),
+            r#"CREATE UNIQUE INDEX "idx-account-email" ON "account" ("email") WHERE deleted_at IS NULL"#
+        );
+
+        let stmts =
+            Schema::new(DbBackend::Sqlite).create_partial_index_from_entity(account::Entity);
+        assert_eq!(
+            stmts[0].to_string(),
+            r#"CREATE UNIQUE INDEX "idx-account-email" ON "account" ("email") WHERE deleted_at IS NULL"#
+        );
+
+        assert!(Schema::new(DbBackend::Postgres)
+            .create_partial_index_from_entity(indexes::Entity)
+            .is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Partial index is not supported in MySQL")]
+    fn test_create_partial_index_from_entity_mysql() {
+        Schema::new(DbBackend::MySql).create_partial_index_from_entity(account::Entity);
+    }
+
     #[test]
     fn test_create_table_from_entity_table_ref() {
         for builder in [DbBackend::MySql, DbBackend::Postgres, DbBackend::Sqlite] {
@@ -240,6 +788,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_create_table_from_entity_check_in() {
+        assert_eq!(
+            DbBackend::Postgres
+                .build(&Schema::new(DbBackend::Postgres).create_table_from_entity(LunchSet))
+                .to_string(),
+            [
+                r#"CREATE TABLE "lunch_set" ("#,
+                r#""id" serial NOT NULL PRIMARY KEY,"#,
+                r#""name" varchar NOT NULL,"#,
+                r#""tea" varchar NOT NULL CHECK ("tea" IN ('EverydayTea', 'BreakfastTea'))"#,
+                r#")"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            DbBackend::Sqlite
+                .build(&Schema::new(DbBackend::Sqlite).create_table_from_entity(LunchSet))
+                .to_string(),
+            [
+                r#"CREATE TABLE "lunch_set" ("#,
+                r#""id" integer NOT NULL PRIMARY KEY AUTOINCREMENT,"#,
+                r#""name" text NOT NULL,"#,
+                r#""tea" text NOT NULL CHECK ("tea" IN ('EverydayTea', 'BreakfastTea'))"#,
+                r#")"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            DbBackend::MySql
+                .build(&Schema::new(DbBackend::MySql).create_table_from_entity(LunchSet))
+                .to_string(),
+            [
+                "CREATE TABLE `lunch_set` (",
+                "`id` int NOT NULL AUTO_INCREMENT PRIMARY KEY,",
+                "`name` varchar(255) NOT NULL,",
+                "`tea` varchar(255) NOT NULL",
+                ")",
+            ]
+            .join(" ")
+        );
+    }
+
     fn get_cake_filling_price_stmt() -> TableCreateStatement {
         Table::create()
             .col(
@@ -338,4 +929,124 @@ mod tests {
             )
             .to_owned()
     }
+
+    #[test]
+    fn create_table_from_entity_generated_column() {
+        let schema = Schema::new(DbBackend::Postgres);
+        assert_eq!(
+            DbBackend::Postgres
+                .build(&schema.create_table_from_entity(LineItem))
+                .to_string(),
+            [
+                r#"CREATE TABLE "line_item" ("#,
+                r#""id" serial NOT NULL PRIMARY KEY,"#,
+                r#""price" integer NOT NULL,"#,
+                r#""quantity" integer NOT NULL,"#,
+                r#""total" integer NOT NULL GENERATED ALWAYS AS (price * quantity) STORED"#,
+                r#")"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    mod author {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "author")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub featured_book_id: Option<i32>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {
+            #[sea_orm(
+                belongs_to = "super::book::Entity",
+                from = "Column::FeaturedBookId",
+                to = "super::book::Column::Id"
+            )]
+            FeaturedBook,
+        }
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    mod book {
+        use crate as sea_orm;
+        use crate::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "book")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub author_id: i32,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {
+            #[sea_orm(
+                belongs_to = "super::author::Entity",
+                from = "Column::AuthorId",
+                to = "super::author::Column::Id"
+            )]
+            Author,
+        }
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[test]
+    fn create_tables_from_entities() {
+        for backend in [DbBackend::MySql, DbBackend::Postgres, DbBackend::Sqlite] {
+            let schema = Schema::new(backend);
+            assert_eq!(
+                schema.create_tables_from_entities(&[&fruit::Entity, &cake::Entity]),
+                [
+                    backend.build(&schema.create_table_from_entity(cake::Entity)),
+                    backend.build(&schema.create_table_from_entity(fruit::Entity)),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn create_tables_from_entities_cyclic() {
+        let schema = Schema::new(DbBackend::Postgres);
+        assert_eq!(
+            schema
+                .create_tables_from_entities(&[&author::Entity, &book::Entity])
+                .iter()
+                .map(|stmt| stmt.to_string())
+                .collect::<Vec<_>>(),
+            [
+                r#"CREATE TABLE "book" ( "id" serial NOT NULL PRIMARY KEY, "author_id" integer NOT NULL )"#,
+                [
+                    r#"CREATE TABLE "author" ( "id" serial NOT NULL PRIMARY KEY, "featured_book_id" integer,"#,
+                    r#"CONSTRAINT "fk-author-featured_book_id" FOREIGN KEY ("featured_book_id") REFERENCES "book" ("id") )"#,
+                ]
+                .join(" ")
+                .as_str(),
+                [
+                    r#"ALTER TABLE "book" ADD CONSTRAINT "fk-book-author_id""#,
+                    r#"FOREIGN KEY ("author_id") REFERENCES "author" ("id")"#,
+                ]
+                .join(" ")
+                .as_str(),
+            ]
+        );
+
+        // SQLite can't add a foreign key to an existing table, nor does it check them on creation
+        let schema = Schema::new(DbBackend::Sqlite);
+        assert_eq!(
+            schema.create_tables_from_entities(&[&author::Entity, &book::Entity]),
+            [
+                DbBackend::Sqlite.build(&schema.create_table_from_entity(book::Entity)),
+                DbBackend::Sqlite.build(&schema.create_table_from_entity(author::Entity)),
+            ]
+        );
+    }
 }
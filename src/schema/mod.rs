@@ -2,6 +2,8 @@ use crate::DbBackend;
 
 mod entity;
 
+pub use entity::EntityTable;
+
 /// This is a helper struct to convert [`EntityTrait`](crate::EntityTrait)
 /// into different [`sea_query`](crate::sea_query) statements.
 #[derive(Debug)]
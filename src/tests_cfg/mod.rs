@@ -8,6 +8,8 @@ pub mod entity_linked;
 pub mod filling;
 pub mod fruit;
 pub mod indexes;
+pub mod line_item;
+pub mod lunch_set;
 pub mod rust_keyword;
 pub mod vendor;
 
@@ -17,5 +19,7 @@ pub use cake_filling::Entity as CakeFilling;
 pub use cake_filling_price::Entity as CakeFillingPrice;
 pub use filling::Entity as Filling;
 pub use fruit::Entity as Fruit;
+pub use line_item::Entity as LineItem;
+pub use lunch_set::Entity as LunchSet;
 pub use rust_keyword::Entity as RustKeyword;
 pub use vendor::Entity as Vendor;
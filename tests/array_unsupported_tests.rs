@@ -0,0 +1,81 @@
+#![cfg(all(
+    any(feature = "sqlx-mysql", feature = "sqlx-sqlite"),
+    feature = "postgres-array"
+))]
+
+pub mod common;
+
+pub use common::TestContext;
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, ConnectionTrait, DbBackend, PgRange, Statement};
+
+#[sea_orm_macros::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("array_unsupported_tests").await;
+    read_array(&ctx.db).await?;
+    bind_array(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn read_array(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let driver = match db.get_database_backend() {
+        DbBackend::MySql => "sqlx-mysql",
+        DbBackend::Sqlite => "sqlx-sqlite",
+        DbBackend::Postgres => unreachable!(),
+    };
+
+    let row = db
+        .query_one(Statement::from_string(
+            db.get_database_backend(),
+            "SELECT 1 AS integers, 'a' AS names".to_owned(),
+        ))
+        .await?
+        .unwrap();
+
+    assert_eq!(
+        row.try_get::<Vec<i32>>("", "integers"),
+        Err(DbErr::Type(format!("Vec<i32> unsupported by {}", driver)))
+    );
+    assert_eq!(
+        row.try_get::<Vec<String>>("", "names"),
+        Err(DbErr::Type(format!(
+            "Vec<String> unsupported by {}",
+            driver
+        )))
+    );
+    assert_eq!(
+        row.try_get::<PgRange<i32>>("", "integers"),
+        Err(DbErr::Type(format!(
+            "PgRange<i32> unsupported by {}",
+            driver
+        )))
+    );
+
+    Ok(())
+}
+
+pub async fn bind_array(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let (backend, name) = match db.get_database_backend() {
+        DbBackend::MySql => (DbBackend::MySql, "MySQL"),
+        DbBackend::Sqlite => (DbBackend::Sqlite, "SQLite"),
+        DbBackend::Postgres => unreachable!(),
+    };
+    let stmt =
+        || Statement::from_sql_and_values(backend, "SELECT ? AS integers", [vec![1, 2].into()]);
+
+    assert_eq!(
+        db.query_one(stmt()).await.map(|_| ()),
+        Err(DbErr::Query(format!(
+            "Arrays are not supported by {}",
+            name
+        )))
+    );
+    assert_eq!(
+        db.execute(stmt()).await.map(|_| ()),
+        Err(DbErr::Exec(format!("Arrays are not supported by {}", name)))
+    );
+
+    Ok(())
+}
@@ -6,6 +6,7 @@ pub mod insert_default;
 pub mod json_struct;
 pub mod json_vec;
 pub mod metadata;
+pub mod post;
 pub mod repository;
 pub mod satellite;
 pub mod schema;
@@ -21,6 +22,7 @@ pub use insert_default::Entity as InsertDefault;
 pub use json_struct::Entity as JsonStruct;
 pub use json_vec::Entity as JsonVec;
 pub use metadata::Entity as Metadata;
+pub use post::Entity as Post;
 pub use repository::Entity as Repository;
 pub use satellite::Entity as Satellite;
 pub use schema::*;
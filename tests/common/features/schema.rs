@@ -41,6 +41,7 @@ pub async fn create_tables(db: &DatabaseConnection) -> Result<(), DbErr> {
     create_active_enum_table(db).await?;
     create_active_enum_child_table(db).await?;
     create_insert_default_table(db).await?;
+    create_post_table(db).await?;
 
     Ok(())
 }
@@ -289,6 +290,23 @@ pub async fn create_insert_default_table(db: &DbConn) -> Result<ExecResult, DbEr
     create_table(db, &create_table_stmt, InsertDefault).await
 }
 
+pub async fn create_post_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let create_table_stmt = sea_query::Table::create()
+        .table(post::Entity.table_ref())
+        .col(
+            ColumnDef::new(post::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(post::Column::Title).string().not_null())
+        .col(ColumnDef::new(post::Column::DeletedAt).timestamp_with_time_zone())
+        .to_owned();
+
+    create_table(db, &create_table_stmt, Post).await
+}
+
 pub async fn create_json_vec_table(db: &DbConn) -> Result<ExecResult, DbErr> {
     let create_table_stmt = sea_query::Table::create()
         .table(json_vec::Entity.table_ref())
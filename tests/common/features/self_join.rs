@@ -33,7 +33,7 @@ impl ActiveModelBehavior for ActiveModel {}
 mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
-    use sea_orm::*;
+    use sea_orm::{sea_query::Alias, *};
 
     #[test]
     fn find_linked_001() {
@@ -74,4 +74,24 @@ mod tests {
             .join(" ")
         );
     }
+
+    #[test]
+    fn join_as_001() {
+        assert_eq!(
+            Entity::find()
+                .join_as(JoinType::InnerJoin, Relation::SelfReferencing.def(), Alias::new("mgr"))
+                .select_also_as(Entity, Alias::new("mgr"))
+                .filter(Column::Time.with_table_alias(Alias::new("mgr")).lt(Time::from_hms_opt(2, 0, 0).unwrap()))
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                r#"SELECT `self_join`.`uuid` AS `A_uuid`, `self_join`.`uuid_ref` AS `A_uuid_ref`, `self_join`.`time` AS `A_time`,"#,
+                r#"`mgr`.`uuid` AS `B_uuid`, `mgr`.`uuid_ref` AS `B_uuid_ref`, `mgr`.`time` AS `B_time`"#,
+                r#"FROM `self_join`"#,
+                r#"INNER JOIN `self_join` AS `mgr` ON `self_join`.`uuid_ref` = `mgr`.`uuid`"#,
+                r#"WHERE `mgr`.`time` < '02:00:00'"#,
+            ]
+            .join(" ")
+        );
+    }
 }
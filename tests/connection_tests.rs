@@ -0,0 +1,316 @@
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), sea_orm::DbErr> {
+    let ctx = TestContext::new("connection_tests").await;
+
+    ctx.db.ping().await?;
+
+    ctx.delete().await;
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-sqlite")]
+async fn connection_pool_options() -> Result<(), sea_orm::DbErr> {
+    use sea_orm::{ConnectOptions, Database};
+    use std::time::Duration;
+
+    let mut opt = ConnectOptions::new("sqlite::memory:".to_owned());
+    opt.max_connections(3)
+        .min_connections(2)
+        .connect_timeout(Duration::from_secs(5))
+        .idle_timeout(Duration::from_secs(30))
+        .max_lifetime(Duration::from_secs(300))
+        .sqlx_logging(false);
+
+    let db = Database::connect(opt).await?;
+    let pool = db.get_sqlite_connection_pool().unwrap();
+    let pool_options = format!("{:?}", pool.options());
+
+    assert!(pool.size() >= 2);
+    assert!(pool_options.contains("max_connections: 3"));
+    assert!(pool_options.contains("min_connections: 2"));
+    assert!(pool_options.contains("connect_timeout: 5s"));
+    assert!(pool_options.contains("max_lifetime: Some(300s)"));
+    assert!(pool_options.contains("idle_timeout: Some(30s)"));
+
+    let db = Database::connect("sqlite::memory:").await?;
+    let pool_options = format!("{:?}", db.get_sqlite_connection_pool().unwrap().options());
+
+    assert!(pool_options.contains("max_connections: 1"));
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn connection_pool_size() -> Result<(), sea_orm::DbErr> {
+    use sea_orm::{ConnectOptions, ConnectionTrait, Database, DbBackend};
+
+    let base_url =
+        std::env::var("DATABASE_URL").expect("Enviroment variable 'DATABASE_URL' not set");
+    let url = if cfg!(feature = "sqlx-mysql") {
+        format!("{}/mysql", base_url)
+    } else if cfg!(feature = "sqlx-postgres") {
+        format!("{}/postgres", base_url)
+    } else {
+        base_url
+    };
+    let mut opt = ConnectOptions::new(url);
+    opt.max_connections(3).min_connections(2);
+    let db = Database::connect(opt).await?;
+
+    let (size, options) = match db.get_database_backend() {
+        #[cfg(feature = "sqlx-mysql")]
+        DbBackend::MySql => {
+            let pool = db.get_mysql_connection_pool().unwrap();
+            (pool.size(), format!("{:?}", pool.options()))
+        }
+        #[cfg(feature = "sqlx-postgres")]
+        DbBackend::Postgres => {
+            let pool = db.get_postgres_connection_pool().unwrap();
+            (pool.size(), format!("{:?}", pool.options()))
+        }
+        #[cfg(feature = "sqlx-sqlite")]
+        DbBackend::Sqlite => {
+            let pool = db.get_sqlite_connection_pool().unwrap();
+            (pool.size(), format!("{:?}", pool.options()))
+        }
+        #[allow(unreachable_patterns)]
+        _ => unreachable!(),
+    };
+    assert!((2..=3).contains(&size));
+    assert!(options.contains("max_connections: 3"));
+    assert!(options.contains("min_connections: 2"));
+
+    // The pool of another backend is not there
+    let disconnected = sea_orm::DatabaseConnection::Disconnected;
+    #[cfg(feature = "sqlx-mysql")]
+    assert!(disconnected.get_mysql_connection_pool().is_none());
+    #[cfg(feature = "sqlx-postgres")]
+    assert!(disconnected.get_postgres_connection_pool().is_none());
+    #[cfg(feature = "sqlx-sqlite")]
+    assert!(disconnected.get_sqlite_connection_pool().is_none());
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-sqlite")]
+async fn connection_lazy() -> Result<(), sea_orm::DbErr> {
+    use sea_orm::{ConnectionTrait, Database, DbBackend, DbErr, Statement};
+
+    // The directory does not exist, so the database file can never be opened
+    let url = "sqlite:///sea-orm/does/not/exist.db";
+    assert!(matches!(Database::connect(url).await, Err(DbErr::Conn(_))));
+
+    let db = Database::connect_lazy(url)?;
+    assert_eq!(db.get_database_backend(), DbBackend::Sqlite);
+    assert!(matches!(db.ping().await, Err(DbErr::Conn(_))));
+    assert!(db
+        .query_one(Statement::from_string(
+            DbBackend::Sqlite,
+            "SELECT 1".to_owned()
+        ))
+        .await
+        .is_err());
+
+    let db = Database::connect_lazy("sqlite::memory:")?;
+    db.ping().await?;
+
+    assert!(matches!(
+        Database::connect_lazy("unknown://localhost"),
+        Err(DbErr::Conn(_))
+    ));
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-sqlite")]
+async fn connection_execute_unprepared() -> Result<(), sea_orm::DbErr> {
+    use sea_orm::{ConnectionTrait, Database, TransactionTrait};
+
+    let db = Database::connect("sqlite::memory:").await?;
+
+    db.execute_unprepared(
+        "CREATE TABLE item (id integer PRIMARY KEY, name text NOT NULL); \
+         INSERT INTO item (name) VALUES ('apple'), ('orange');",
+    )
+    .await?;
+    assert_eq!(item_names(&db).await?, ["apple", "orange"]);
+
+    let txn = db.begin().await?;
+    let res = txn
+        .execute_unprepared(
+            "INSERT INTO item (name) VALUES ('banana'); INSERT INTO item (name) VALUES ('mango');",
+        )
+        .await?;
+    assert_eq!(res.rows_affected(), 2);
+    txn.rollback().await?;
+    assert_eq!(item_names(&db).await?, ["apple", "orange"]);
+
+    let txn = db.begin().await?;
+    txn.execute_unprepared(
+        "INSERT INTO item (name) VALUES ('banana'); DELETE FROM item WHERE name = 'apple';",
+    )
+    .await?;
+    txn.commit().await?;
+    assert_eq!(item_names(&db).await?, ["orange", "banana"]);
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-sqlite")]
+async fn connection_close() -> Result<(), sea_orm::DbErr> {
+    use sea_orm::{
+        ConnectionTrait, Database, DbBackend, DbErr, SqlxSqliteConnector, Statement,
+        TransactionTrait,
+    };
+
+    let db = Database::connect("sqlite::memory:").await?;
+    let other = SqlxSqliteConnector::from_sqlx_sqlite_pool(
+        db.get_sqlite_connection_pool().unwrap().clone(),
+    );
+    let stmt = || Statement::from_string(DbBackend::Sqlite, "SELECT 1".to_owned());
+
+    // Closing waits for the transaction to give its connection back
+    let txn = db.begin().await?;
+    let close = db.close();
+    futures::pin_mut!(close);
+    assert!(futures::poll!(close.as_mut()).is_pending());
+    txn.query_one(stmt()).await?;
+    txn.commit().await?;
+    close.await?;
+
+    let pool_closed = DbErr::Conn("pool closed".to_owned());
+    assert_eq!(
+        other.query_one(stmt()).await.err(),
+        Some(pool_closed.clone())
+    );
+    assert_eq!(
+        other.query_all(stmt()).await.err(),
+        Some(pool_closed.clone())
+    );
+    assert_eq!(other.execute(stmt()).await.err(), Some(pool_closed.clone()));
+    assert_eq!(other.begin().await.err(), Some(pool_closed));
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-sqlite")]
+async fn connection_pool_status() -> Result<(), sea_orm::DbErr> {
+    use sea_orm::{
+        ConnectOptions, Database, DbBackend, MockDatabase, PoolStatus, TransactionTrait,
+    };
+
+    let mut opt = ConnectOptions::new("sqlite::memory:".to_owned());
+    opt.max_connections(2);
+    let db = Database::connect(opt).await?;
+
+    let txn = db.begin().await?;
+    let status = db.pool_status().unwrap();
+    assert!(status.in_use >= 1);
+    assert_eq!(status.size, status.idle + status.in_use);
+
+    let other_txn = db.begin().await?;
+    assert_eq!(
+        db.pool_status(),
+        Some(PoolStatus {
+            size: 2,
+            idle: 0,
+            in_use: 2,
+        })
+    );
+
+    txn.commit().await?;
+    other_txn.rollback().await?;
+
+    let db = MockDatabase::new(DbBackend::Sqlite).into_connection();
+    assert_eq!(db.pool_status(), None);
+
+    Ok(())
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+async fn item_names<C>(db: &C) -> Result<Vec<String>, sea_orm::DbErr>
+where
+    C: sea_orm::ConnectionTrait,
+{
+    let stmt = sea_orm::Statement::from_string(
+        db.get_database_backend(),
+        "SELECT name FROM item ORDER BY id".to_owned(),
+    );
+    db.query_all(stmt)
+        .await?
+        .iter()
+        .map(|row| row.try_get("", "name"))
+        .collect()
+}
+
+#[sea_orm_macros::test]
+#[cfg(feature = "sqlx-postgres")]
+async fn connection_schema_search_path() -> Result<(), sea_orm::DbErr> {
+    use sea_orm::{ConnectOptions, ConnectionTrait, Database, DbBackend, Statement};
+
+    let ctx = TestContext::new("connection_schema_search_path").await;
+    for sql in [
+        "CREATE SCHEMA my_schema",
+        "CREATE TABLE my_schema.bakery (id integer PRIMARY KEY)",
+        "INSERT INTO my_schema.bakery VALUES (42)",
+    ] {
+        ctx.db
+            .execute(Statement::from_string(DbBackend::Postgres, sql.to_owned()))
+            .await?;
+    }
+
+    let base_url = std::env::var("DATABASE_URL").unwrap();
+    let mut opt = ConnectOptions::new(format!("{}/connection_schema_search_path", base_url));
+    opt.max_connections(2)
+        .schema_search_path("my_schema, public");
+
+    for db in [
+        Database::connect(opt.clone()).await?,
+        Database::connect_lazy(opt)?,
+    ] {
+        let res = db
+            .query_one(Statement::from_string(
+                DbBackend::Postgres,
+                "SHOW search_path".to_owned(),
+            ))
+            .await?
+            .unwrap();
+        assert_eq!(
+            res.try_get::<String>("", "search_path")?,
+            "my_schema, public"
+        );
+
+        let res = db
+            .query_one(Statement::from_string(
+                DbBackend::Postgres,
+                "SELECT id FROM bakery".to_owned(),
+            ))
+            .await?
+            .unwrap();
+        assert_eq!(res.try_get::<i32>("", "id")?, 42);
+    }
+
+    ctx.delete().await;
+
+    Ok(())
+}
@@ -137,3 +137,64 @@ pub async fn test_update_deleted_customer(db: &DbConn) {
 
     assert_eq!(customer, None);
 }
+
+pub async fn test_update_many_bakeries(db: &DbConn) {
+    let mut ids = Vec::new();
+    for name in ["Bakery A", "Bakery B", "Bakery C"] {
+        let res = Bakery::insert(bakery::ActiveModel {
+            name: Set(name.to_owned()),
+            profit_margin: Set(5.0),
+            ..Default::default()
+        })
+        .exec(db)
+        .await
+        .expect("could not insert bakery");
+        ids.push(res.last_insert_id);
+    }
+
+    let res = Bakery::update_many()
+        .set_many([
+            bakery::ActiveModel {
+                id: Set(ids[0]),
+                name: Set("Bakery A+".to_owned()),
+                ..Default::default()
+            },
+            bakery::ActiveModel {
+                id: Set(ids[1]),
+                name: Set("Bakery B+".to_owned()),
+                profit_margin: Set(7.5),
+            },
+        ])
+        .expect("could not build the update")
+        .exec(db)
+        .await
+        .expect("could not update bakeries");
+    assert_eq!(res.rows_affected, 2);
+
+    let bakeries = Bakery::find()
+        .filter(bakery::Column::Id.is_in(ids.clone()))
+        .order_by_asc(bakery::Column::Id)
+        .all(db)
+        .await
+        .expect("could not find bakeries");
+    assert_eq!(
+        bakeries,
+        [
+            bakery::Model {
+                id: ids[0],
+                name: "Bakery A+".to_owned(),
+                profit_margin: 5.0,
+            },
+            bakery::Model {
+                id: ids[1],
+                name: "Bakery B+".to_owned(),
+                profit_margin: 7.5,
+            },
+            bakery::Model {
+                id: ids[2],
+                name: "Bakery C".to_owned(),
+                profit_margin: 5.0,
+            },
+        ]
+    );
+}
@@ -33,6 +33,7 @@ pub async fn create_entities(db: &DatabaseConnection) {
     test_update_cake(db).await;
     test_update_bakery(db).await;
     test_update_deleted_customer(db).await;
+    test_update_many_bakeries(db).await;
 
     test_delete_cake(db).await;
     test_delete_bakery(db).await;
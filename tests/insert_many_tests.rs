@@ -0,0 +1,121 @@
+pub mod common;
+
+pub use common::{bakery_chain::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{
+    entity::prelude::*, sea_query::Expr, ConnectionTrait, Insert, QueryOrder, QuerySelect, Set,
+};
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("insert_many_tests").await;
+    create_tables(&ctx.db).await?;
+    insert_many_chunked(&ctx.db).await?;
+    insert_many_returning_keys(&ctx.db).await?;
+    insert_from_select(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn insert_many_chunked(db: &DatabaseConnection) -> Result<(), DbErr> {
+    // 1000 rows of 2 columns exceed the 999 bind parameters allowed by SQLite
+    let bakeries = (0..1000).map(|i| bakery::ActiveModel {
+        name: Set(format!("Bakery {}", i)),
+        profit_margin: Set(i as f64),
+        ..Default::default()
+    });
+
+    let rows_affected = bakery::Entity::insert_many(bakeries)
+        .exec_without_returning(db)
+        .await?;
+
+    assert_eq!(rows_affected, 1000);
+    assert_eq!(bakery::Entity::find().count(db).await?, 1000);
+
+    let bakeries = (0..10).map(|i| bakery::ActiveModel {
+        name: Set(format!("Cafe {}", i)),
+        profit_margin: Set(i as f64),
+        ..Default::default()
+    });
+
+    let rows_affected = bakery::Entity::insert_many(bakeries)
+        .chunk_size(3)
+        .exec_without_returning(db)
+        .await?;
+
+    assert_eq!(rows_affected, 10);
+    assert_eq!(bakery::Entity::find().count(db).await?, 1010);
+
+    Ok(())
+}
+
+pub async fn insert_many_returning_keys(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let names: Vec<String> = (0..5).map(|i| format!("Bistro {}", i)).collect();
+    let bakeries = names.iter().map(|name| bakery::ActiveModel {
+        name: Set(name.clone()),
+        profit_margin: Set(1.0),
+        ..Default::default()
+    });
+
+    let ids = bakery::Entity::insert_many(bakeries)
+        .exec_with_returning_keys(db)
+        .await?;
+
+    assert_eq!(ids.len(), names.len());
+    for (id, name) in ids.into_iter().zip(names) {
+        assert_eq!(
+            bakery::Entity::find_by_id(id)
+                .one(db)
+                .await?
+                .map(|b| b.name),
+            Some(name)
+        );
+    }
+
+    Ok(())
+}
+
+pub async fn insert_from_select(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let copy_cafes = || {
+        Insert::<bakery::ActiveModel>::from_select(
+            [bakery::Column::Name, bakery::Column::ProfitMargin],
+            bakery::Entity::find()
+                .select_only()
+                .column(bakery::Column::Name)
+                .column_as(
+                    Expr::col(bakery::Column::ProfitMargin).mul(2),
+                    "profit_margin",
+                )
+                .filter(bakery::Column::Name.starts_with("Cafe"))
+                .order_by_asc(bakery::Column::Id),
+        )
+    };
+
+    assert_eq!(copy_cafes()?.exec_without_returning(db).await?, 10);
+
+    let cafes = bakery::Entity::find()
+        .filter(bakery::Column::Name.starts_with("Cafe"))
+        .order_by_asc(bakery::Column::Id)
+        .all(db)
+        .await?;
+    assert_eq!(cafes.len(), 20);
+    for (cafe, copy) in cafes[..10].iter().zip(&cafes[10..]) {
+        assert_eq!(copy.name, cafe.name);
+        assert_eq!(copy.profit_margin, cafe.profit_margin * 2.0);
+    }
+
+    let keys = copy_cafes()?.exec_with_returning_keys(db).await;
+    if db.support_returning() {
+        assert_eq!(keys?.len(), 20);
+    } else {
+        assert!(matches!(keys, Err(DbErr::Exec(_))));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,208 @@
+#![cfg(feature = "sqlx-sqlite")]
+
+pub mod common;
+
+use sea_orm::migration::{MigrationName, MigrationTrait, Migrator, SchemaManager};
+use sea_orm::{
+    sea_query::{Alias, ColumnDef, Table},
+    ConnectOptions, ConnectionTrait, Database, DbErr, DeriveCoreMigrationName, Statement,
+};
+
+mod m20220101_000001_create_post_table {
+    use super::*;
+
+    #[derive(DeriveCoreMigrationName)]
+    pub struct Migration;
+
+    #[async_trait::async_trait]
+    impl MigrationTrait for Migration {
+        async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+            manager
+                .create_table(
+                    Table::create()
+                        .table(Alias::new("post"))
+                        .col(
+                            ColumnDef::new(Alias::new("id"))
+                                .integer()
+                                .not_null()
+                                .primary_key(),
+                        )
+                        .col(ColumnDef::new(Alias::new("title")).string().not_null())
+                        .to_owned(),
+                )
+                .await
+        }
+
+        async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+            manager
+                .drop_table(Table::drop().table(Alias::new("post")).to_owned())
+                .await
+        }
+    }
+}
+
+mod m20220101_000002_create_comment_table {
+    use super::*;
+
+    #[derive(DeriveCoreMigrationName)]
+    pub struct Migration;
+
+    #[async_trait::async_trait]
+    impl MigrationTrait for Migration {
+        async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+            manager
+                .create_table(
+                    Table::create()
+                        .table(Alias::new("comment"))
+                        .col(
+                            ColumnDef::new(Alias::new("id"))
+                                .integer()
+                                .not_null()
+                                .primary_key(),
+                        )
+                        .to_owned(),
+                )
+                .await
+        }
+    }
+}
+
+mod m20220101_000003_create_tag_table {
+    use super::*;
+
+    #[derive(DeriveCoreMigrationName)]
+    pub struct Migration;
+
+    #[async_trait::async_trait]
+    impl MigrationTrait for Migration {
+        async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+            manager
+                .create_table(
+                    Table::create()
+                        .table(Alias::new("tag"))
+                        .col(
+                            ColumnDef::new(Alias::new("id"))
+                                .integer()
+                                .not_null()
+                                .primary_key(),
+                        )
+                        .to_owned(),
+                )
+                .await?;
+            manager
+                .get_connection()
+                .execute_unprepared("INSERT INTO missing (id) VALUES (1)")
+                .await
+                .map(|_| ())
+        }
+    }
+}
+
+fn migrator() -> Migrator {
+    Migrator::new(vec![
+        Box::new(m20220101_000001_create_post_table::Migration),
+        Box::new(m20220101_000002_create_comment_table::Migration),
+    ])
+}
+
+#[test]
+fn derive_migration_name() {
+    assert_eq!(
+        m20220101_000001_create_post_table::Migration.name(),
+        "m20220101_000001_create_post_table"
+    );
+}
+
+#[sea_orm_macros::test]
+async fn migration_up_and_down() -> Result<(), DbErr> {
+    let mut opt = ConnectOptions::new("sqlite::memory:".to_owned());
+    // an in-memory database only lives in its own connection
+    opt.max_connections(1);
+    let db = Database::connect(opt).await?;
+    let backend = db.get_database_backend();
+    let insert_post = || {
+        Statement::from_string(
+            backend,
+            "INSERT INTO post (id, title) VALUES (1, 'Migrated')".to_owned(),
+        )
+    };
+    let migrator = migrator();
+
+    migrator.up(&db, Some(1)).await?;
+    assert_eq!(
+        migrator.get_applied_migrations(&db).await?,
+        vec!["m20220101_000001_create_post_table"]
+    );
+    assert_eq!(
+        migrator.get_pending_migrations(&db).await?,
+        vec!["m20220101_000002_create_comment_table"]
+    );
+    db.execute(insert_post()).await?;
+
+    // Applied migrations are skipped, the tables are not created twice
+    migrator.up(&db, None).await?;
+    migrator.up(&db, None).await?;
+    assert_eq!(migrator.get_applied_migrations(&db).await?.len(), 2);
+    assert!(migrator.get_pending_migrations(&db).await?.is_empty());
+
+    // The last migration cannot be reverted
+    assert_eq!(
+        migrator.down(&db, Some(1)).await,
+        Err(DbErr::Migration(
+            "Migration 'm20220101_000002_create_comment_table' cannot be reverted".to_owned()
+        ))
+    );
+
+    let post_only = Migrator::new(vec![Box::new(
+        m20220101_000001_create_post_table::Migration,
+    )]);
+    assert_eq!(
+        post_only.up(&db, None).await,
+        Err(DbErr::Migration(
+            r#"Migrations ["m20220101_000002_create_comment_table"] have been applied but are missing"#
+                .to_owned()
+        ))
+    );
+
+    db.execute(Statement::from_string(
+        backend,
+        "DELETE FROM seaql_migrations WHERE version = 'm20220101_000002_create_comment_table'"
+            .to_owned(),
+    ))
+    .await?;
+    post_only.down(&db, None).await?;
+    assert!(post_only.get_applied_migrations(&db).await?.is_empty());
+    assert!(db.execute(insert_post()).await.is_err());
+
+    post_only.up(&db, None).await?;
+    db.execute(insert_post()).await?;
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+async fn migration_rolled_back_on_error() -> Result<(), DbErr> {
+    let mut opt = ConnectOptions::new("sqlite::memory:".to_owned());
+    opt.max_connections(1);
+    let db = Database::connect(opt).await?;
+    let migrator = Migrator::new(vec![
+        Box::new(m20220101_000001_create_post_table::Migration),
+        Box::new(m20220101_000003_create_tag_table::Migration),
+    ]);
+
+    assert!(migrator.up(&db, None).await.is_err());
+    assert_eq!(
+        migrator.get_pending_migrations(&db).await?,
+        vec!["m20220101_000003_create_tag_table"]
+    );
+    // The table created before the error is rolled back with the migration
+    assert!(db
+        .execute(Statement::from_string(
+            db.get_database_backend(),
+            "SELECT * FROM tag".to_owned(),
+        ))
+        .await
+        .is_err());
+
+    Ok(())
+}
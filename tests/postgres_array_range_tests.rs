@@ -0,0 +1,311 @@
+#![cfg(all(feature = "sqlx-postgres", feature = "postgres-array"))]
+
+pub mod common;
+
+pub use common::TestContext;
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, ConnectionTrait, PgRange, Statement};
+
+#[sea_orm_macros::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("postgres_array_range_tests").await;
+    create_tables(&ctx.db).await?;
+    insert_and_select(&ctx.db).await?;
+    insert_and_select_range(&ctx.db).await?;
+    insert_and_select_enum_array(&ctx.db).await?;
+    bind_typed_arrays(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn create_tables(db: &DatabaseConnection) -> Result<(), DbErr> {
+    db.execute(Statement::from_string(
+        db.get_database_backend(),
+        [
+            "CREATE TABLE collection (",
+            "id serial NOT NULL PRIMARY KEY,",
+            "integers integer[] NOT NULL,",
+            "names text[] NOT NULL,",
+            "days date[] NOT NULL,",
+            "tokens uuid[] NOT NULL,",
+            "span int4range NOT NULL",
+            ")",
+        ]
+        .join(" "),
+    ))
+    .await?;
+    db.execute(Statement::from_string(
+        db.get_database_backend(),
+        [
+            "CREATE TABLE reservation (",
+            "id serial NOT NULL PRIMARY KEY,",
+            "seats int4range NOT NULL,",
+            "period tsrange",
+            ")",
+        ]
+        .join(" "),
+    ))
+    .await?;
+
+    Ok(())
+}
+
+pub async fn insert_and_select(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let integers = vec![1, 2, 3];
+    let names = vec!["a".to_owned(), "b".to_owned()];
+    let days = vec![
+        Date::from_ymd_opt(2022, 1, 1).unwrap(),
+        Date::from_ymd_opt(2022, 12, 31).unwrap(),
+    ];
+    let tokens = vec![Uuid::new_v4(), Uuid::new_v4()];
+
+    db.execute(Statement::from_sql_and_values(
+        db.get_database_backend(),
+        &[
+            "INSERT INTO collection (integers, names, days, tokens, span)",
+            "VALUES ($1, $2, $3::text[]::date[], $4::text[]::uuid[], $5::int4range)",
+        ]
+        .join(" "),
+        [
+            integers.clone().into(),
+            names.clone().into(),
+            days.iter().map(Date::to_string).collect::<Vec<_>>().into(),
+            tokens
+                .iter()
+                .map(Uuid::to_string)
+                .collect::<Vec<_>>()
+                .into(),
+            PgRange::from(1..10).into(),
+        ],
+    ))
+    .await?;
+
+    let row = db
+        .query_one(Statement::from_string(
+            db.get_database_backend(),
+            "SELECT integers, names, days, tokens, span FROM collection".to_owned(),
+        ))
+        .await?
+        .unwrap();
+
+    assert_eq!(row.try_get::<Vec<i32>>("", "integers")?, integers);
+    assert_eq!(row.try_get::<Vec<String>>("", "names")?, names);
+    assert_eq!(row.try_get::<Vec<Date>>("", "days")?, days);
+    assert_eq!(row.try_get::<Vec<Uuid>>("", "tokens")?, tokens);
+    assert_eq!(
+        row.try_get::<PgRange<i32>>("", "span")?,
+        PgRange::from(1..10)
+    );
+
+    let row = db
+        .query_one(Statement::from_sql_and_values(
+            db.get_database_backend(),
+            r#"SELECT id FROM collection WHERE integers = $1"#,
+            [integers.into()],
+        ))
+        .await?;
+    assert!(row.is_some());
+
+    Ok(())
+}
+
+mod reservation {
+    use sea_orm::entity::prelude::*;
+    use sea_orm::PgRange;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[sea_orm(table_name = "reservation")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i32,
+        pub seats: PgRange<i32>,
+        pub period: Option<PgRange<DateTime>>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+pub async fn insert_and_select_range(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use sea_orm::{ActiveModelTrait, QueryFilter, Set};
+    use std::ops::Bound;
+
+    let day = Date::from_ymd_opt(2022, 1, 1).unwrap();
+    let reserved = reservation::ActiveModel {
+        seats: Set(PgRange::from(1..=4)),
+        period: Set(Some(PgRange::from(
+            day.and_hms_opt(18, 0, 0).unwrap()..day.and_hms_opt(20, 30, 0).unwrap(),
+        ))),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    // Postgres normalizes discrete ranges to an excluded upper bound
+    assert_eq!(reserved.seats, PgRange::from(1..5));
+
+    let open = reservation::ActiveModel {
+        seats: Set((Bound::Excluded(10), Bound::Unbounded).into()),
+        period: Set(None),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    assert_eq!(open.seats, (Bound::Included(11), Bound::Unbounded).into());
+
+    assert_eq!(
+        reservation::Entity::find()
+            .filter(reservation::Column::Seats.eq(PgRange::from(1..5)))
+            .all(db)
+            .await?,
+        vec![reserved.clone()]
+    );
+
+    let mut updated: reservation::ActiveModel = open.into();
+    updated.seats = Set(PgRange::from(20..30));
+    let updated = updated.update(db).await?;
+    assert_eq!(
+        reservation::Entity::find_by_id(updated.id).one(db).await?,
+        Some(reservation::Model {
+            seats: PgRange::from(20..30),
+            ..updated
+        })
+    );
+
+    Ok(())
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "category")]
+pub enum Category {
+    #[sea_orm(string_value = "B")]
+    Big,
+    #[sea_orm(string_value = "S")]
+    Small,
+}
+
+pub async fn insert_and_select_enum_array(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use sea_orm::{
+        sea_query::{Alias, Expr, Query},
+        ActiveEnum, FromQueryResult,
+    };
+
+    #[derive(Debug, PartialEq, FromQueryResult)]
+    struct Categories {
+        categories: Vec<Category>,
+    }
+
+    let backend = db.get_database_backend();
+    db.execute(Statement::from_string(
+        backend,
+        "CREATE TYPE category AS ENUM ('B', 'S')".to_owned(),
+    ))
+    .await?;
+    db.execute(Statement::from_string(
+        backend,
+        "CREATE TABLE category_array (id integer NOT NULL PRIMARY KEY, categories category[] NOT NULL)"
+            .to_owned(),
+    ))
+    .await?;
+
+    let categories = vec![Category::Small, Category::Big, Category::Small];
+    for (id, categories) in [(1, categories.clone()), (2, vec![])] {
+        db.execute(
+            backend.build(
+                Query::insert()
+                    .into_table(Alias::new("category_array"))
+                    .columns([Alias::new("id"), Alias::new("categories")])
+                    .exprs_panic([Expr::val(id).into(), Category::into_array_expr(categories)]),
+            ),
+        )
+        .await?;
+    }
+
+    // Postgres enum arrays are read as text arrays
+    let select = |id: i32| {
+        backend.build(
+            Query::select()
+                .expr_as(
+                    Expr::col(Alias::new("categories")).as_enum(Alias::new("text[]")),
+                    Alias::new("categories"),
+                )
+                .from(Alias::new("category_array"))
+                .and_where(Expr::col(Alias::new("id")).eq(id)),
+        )
+    };
+    assert_eq!(
+        Categories::find_by_statement(select(1)).one(db).await?,
+        Some(Categories { categories })
+    );
+    assert_eq!(
+        Categories::find_by_statement(select(2)).one(db).await?,
+        Some(Categories { categories: vec![] })
+    );
+
+    Ok(())
+}
+
+pub async fn bind_typed_arrays(db: &DatabaseConnection) -> Result<(), DbErr> {
+    use sea_orm::sea_query::Value;
+
+    let backend = db.get_database_backend();
+    let days = vec![
+        Date::from_ymd_opt(2022, 1, 1).unwrap(),
+        Date::from_ymd_opt(2022, 12, 31).unwrap(),
+    ];
+    let tokens = vec![Uuid::new_v4(), Uuid::new_v4()];
+    let documents = vec![Json::from(1), serde_json::json!({ "a": [true] })];
+    let prices = vec![Decimal::new(1050, 2), Decimal::new(-3, 0)];
+
+    let row = db
+        .query_one(Statement::from_sql_and_values(
+            backend,
+            "SELECT $1 AS days, $2 AS tokens, $3 AS documents, $4 AS prices, cardinality($5) AS chunks",
+            [
+                days.clone().into(),
+                tokens.clone().into(),
+                documents.clone().into(),
+                prices.clone().into(),
+                Value::Array(Some(Box::new(vec![
+                    Value::Bytes(Some(Box::new(vec![0, 1]))),
+                    Value::Bytes(None),
+                ]))),
+            ],
+        ))
+        .await?
+        .unwrap();
+    assert_eq!(row.try_get::<Vec<Date>>("", "days")?, days);
+    assert_eq!(row.try_get::<Vec<Uuid>>("", "tokens")?, tokens);
+    assert_eq!(row.try_get::<Vec<Json>>("", "documents")?, documents);
+    assert_eq!(row.try_get::<Vec<Decimal>>("", "prices")?, prices);
+    assert_eq!(row.try_get::<i32>("", "chunks")?, 2);
+
+    // An array mixing element types cannot be bound, and fails the statement
+    let mixed = Value::Array(Some(Box::new(vec![1.into(), "a".into()])));
+    assert_eq!(
+        db.query_one(Statement::from_sql_and_values(
+            backend,
+            "SELECT $1 AS mixed",
+            [mixed.clone()],
+        ))
+        .await
+        .map(|_| ()),
+        Err(DbErr::Query(
+            "Array elements should all be of the same type, found String(Some(\"a\")) in an array of Int"
+                .to_owned()
+        ))
+    );
+    assert!(matches!(
+        db.execute(Statement::from_sql_and_values(
+            backend,
+            "SELECT $1 AS mixed",
+            [mixed],
+        ))
+        .await,
+        Err(DbErr::Exec(_))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+pub mod common;
+
+pub use common::{bakery_chain::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, ConnectionTrait, DatabaseConnection, DbBackend, Set};
+use sea_query::{Alias, Expr, Query};
+use serde_json::json;
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("query_json_tests").await;
+    create_tables(&ctx.db).await?;
+    query_all_json(&ctx.db).await?;
+    query_column_names(&ctx.db).await?;
+    query_values(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn query_all_json(db: &DatabaseConnection) -> Result<(), DbErr> {
+    bakery::Entity::insert_many([
+        bakery::ActiveModel {
+            name: Set("SeaSide Bakery".to_owned()),
+            profit_margin: Set(10.4),
+            ..Default::default()
+        },
+        bakery::ActiveModel {
+            name: Set("Top Bakery".to_owned()),
+            profit_margin: Set(15.0),
+            ..Default::default()
+        },
+    ])
+    .exec(db)
+    .await?;
+
+    let builder = db.get_database_backend();
+    let stmt = builder.build(
+        Query::select()
+            .columns([
+                bakery::Column::Id,
+                bakery::Column::Name,
+                bakery::Column::ProfitMargin,
+            ])
+            .from(bakery::Entity)
+            .order_by(bakery::Column::Id, sea_query::Order::Asc),
+    );
+
+    assert_eq!(
+        db.query_all_json(stmt).await?,
+        [
+            json!({ "id": 1, "name": "SeaSide Bakery", "profit_margin": 10.4 }),
+            json!({ "id": 2, "name": "Top Bakery", "profit_margin": 15.0 }),
+        ]
+    );
+
+    let stmt = builder.build(
+        Query::select()
+            .column(bakery::Column::Name)
+            .expr_as(Expr::val(Option::<String>::None), Alias::new("nothing"))
+            .from(bakery::Entity)
+            .and_where(bakery::Column::Name.eq("Top Bakery")),
+    );
+
+    assert_eq!(
+        db.query_all_json(stmt).await?,
+        [json!({ "name": "Top Bakery", "nothing": null })]
+    );
+
+    Ok(())
+}
+
+pub async fn query_column_names(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let stmt = db.get_database_backend().build(
+        Query::select()
+            .columns([
+                bakery::Column::Name,
+                bakery::Column::ProfitMargin,
+                bakery::Column::Id,
+            ])
+            .expr_as(Expr::val(1), Alias::new("one"))
+            .from(bakery::Entity),
+    );
+
+    let row = db.query_one(stmt).await?.unwrap();
+    assert_eq!(row.column_names(), ["name", "profit_margin", "id", "one"]);
+
+    Ok(())
+}
+
+pub async fn query_values(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let stmt = db.get_database_backend().build(
+        Query::select()
+            .columns([
+                bakery::Column::Name,
+                bakery::Column::ProfitMargin,
+                bakery::Column::Id,
+            ])
+            .from(bakery::Entity)
+            .and_where(bakery::Column::Name.eq("Top Bakery")),
+    );
+
+    let row = db.query_one(stmt).await?.unwrap();
+    assert_eq!(
+        row.try_get_values()?,
+        [
+            Value::String(Some(Box::new("Top Bakery".to_owned()))),
+            Value::Double(Some(15.0)),
+            match db.get_database_backend() {
+                // SQLite declares an `INTEGER` column as 64-bit
+                DbBackend::Sqlite => Value::BigInt(Some(2)),
+                _ => Value::Int(Some(2)),
+            },
+        ]
+    );
+
+    Ok(())
+}
@@ -222,3 +222,236 @@ pub async fn find_all_filter_with_results() {
 
     ctx.delete().await;
 }
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn find_all_filter_escaped() {
+    let ctx = TestContext::new("find_all_filter_escaped").await;
+    create_tables(&ctx.db).await.unwrap();
+
+    for name in [
+        "100% Bakery",
+        "1000 Bakery",
+        "Sea_Side",
+        "SeaSide",
+        r"Back\Slash",
+    ] {
+        bakery::ActiveModel {
+            name: Set(name.to_owned()),
+            profit_margin: Set(10.0),
+            ..Default::default()
+        }
+        .insert(&ctx.db)
+        .await
+        .expect("could not insert bakery");
+    }
+
+    let find = |filter| async {
+        Bakery::find()
+            .filter(filter)
+            .all(&ctx.db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|bakery| bakery.name)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        find(bakery::Column::Name.contains("100%")).await,
+        ["100% Bakery", "1000 Bakery"]
+    );
+    assert_eq!(
+        find(bakery::Column::Name.contains_escaped("100%")).await,
+        ["100% Bakery"]
+    );
+    assert_eq!(
+        find(bakery::Column::Name.starts_with_escaped("Sea_")).await,
+        ["Sea_Side"]
+    );
+    assert_eq!(
+        find(bakery::Column::Name.ends_with_escaped("a_Side")).await,
+        ["Sea_Side"]
+    );
+    assert_eq!(
+        find(bakery::Column::Name.contains_escaped(r"k\S")).await,
+        [r"Back\Slash"]
+    );
+
+    if ctx.db.get_database_backend() == sea_orm::DbBackend::Postgres {
+        assert_eq!(
+            find(bakery::Column::Name.icontains_escaped("sea_")).await,
+            ["Sea_Side"]
+        );
+    }
+
+    ctx.delete().await;
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn group_by_having() {
+    use sea_orm::{
+        sea_query::{Alias, Expr},
+        QueryOrder, QuerySelect,
+    };
+
+    let ctx = TestContext::new("group_by_having").await;
+    create_tables(&ctx.db).await.unwrap();
+
+    for (bakery_name, bakers) in [
+        ("SeaSide Bakery", 3),
+        ("Top Bakery", 1),
+        ("LakeSide Bakery", 2),
+    ] {
+        let bakery = bakery::ActiveModel {
+            name: Set(bakery_name.to_owned()),
+            profit_margin: Set(10.4),
+            ..Default::default()
+        }
+        .insert(&ctx.db)
+        .await
+        .expect("could not insert bakery");
+
+        for i in 0..bakers {
+            baker::ActiveModel {
+                name: Set(format!("Baker {} of {}", i, bakery_name)),
+                contact_details: Set(serde_json::json!({})),
+                bakery_id: Set(Some(bakery.id)),
+                ..Default::default()
+            }
+            .insert(&ctx.db)
+            .await
+            .expect("could not insert baker");
+        }
+    }
+
+    let bakers_per_bakery: Vec<(String, i64)> = Bakery::find()
+        .select_only()
+        .column(bakery::Column::Name)
+        .column_as(baker::Column::Id.count(), "num_of_bakers")
+        .inner_join(Baker)
+        .group_by(bakery::Column::Name)
+        .having(Expr::expr(Expr::col((Baker, baker::Column::Id)).count()).gt(1))
+        .order_by_asc(bakery::Column::Name)
+        .into_tuple()
+        .all(&ctx.db)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        bakers_per_bakery,
+        [
+            ("LakeSide Bakery".to_owned(), 2),
+            ("SeaSide Bakery".to_owned(), 3)
+        ]
+    );
+
+    // Postgres does not allow output column aliases in HAVING
+    if ctx.db.get_database_backend() != sea_orm::DbBackend::Postgres {
+        let bakers_per_bakery: Vec<(String, i64)> = Bakery::find()
+            .select_only()
+            .column(bakery::Column::Name)
+            .column_as(baker::Column::Id.count(), "num_of_bakers")
+            .inner_join(Baker)
+            .group_by(bakery::Column::Name)
+            .having(Expr::col(Alias::new("num_of_bakers")).gt(2))
+            .into_tuple()
+            .all(&ctx.db)
+            .await
+            .unwrap();
+
+        assert_eq!(bakers_per_bakery, [("SeaSide Bakery".to_owned(), 3)]);
+    }
+
+    ctx.delete().await;
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn count_joined() {
+    use sea_orm::{PaginatorTrait, QuerySelect};
+
+    let ctx = TestContext::new("count_joined").await;
+    create_tables(&ctx.db).await.unwrap();
+
+    for (bakery_name, bakers) in [
+        ("SeaSide Bakery", 3),
+        ("Top Bakery", 0),
+        ("LakeSide Bakery", 2),
+    ] {
+        let bakery = bakery::ActiveModel {
+            name: Set(bakery_name.to_owned()),
+            profit_margin: Set(10.4),
+            ..Default::default()
+        }
+        .insert(&ctx.db)
+        .await
+        .expect("could not insert bakery");
+
+        for i in 0..bakers {
+            baker::ActiveModel {
+                name: Set(format!("Baker {} of {}", i, bakery_name)),
+                contact_details: Set(serde_json::json!({})),
+                bakery_id: Set(Some(bakery.id)),
+                ..Default::default()
+            }
+            .insert(&ctx.db)
+            .await
+            .expect("could not insert baker");
+        }
+    }
+
+    // One row per baker
+    assert_eq!(Bakery::find().inner_join(Baker).count(&ctx.db).await, Ok(5));
+
+    // One row per bakery having bakers
+    assert_eq!(
+        Bakery::find()
+            .select_only()
+            .column(bakery::Column::Id)
+            .inner_join(Baker)
+            .group_by(bakery::Column::Id)
+            .count(&ctx.db)
+            .await,
+        Ok(2)
+    );
+
+    assert_eq!(
+        Bakery::find()
+            .inner_join(Baker)
+            .count_distinct(&ctx.db, bakery::Column::Id)
+            .await,
+        Ok(2)
+    );
+
+    // NULLs are not counted
+    assert_eq!(
+        Bakery::find()
+            .left_join(Baker)
+            .count_distinct(&ctx.db, baker::Column::BakeryId)
+            .await,
+        Ok(2)
+    );
+    assert_eq!(
+        Bakery::find()
+            .left_join(Baker)
+            .count_distinct(&ctx.db, bakery::Column::Id)
+            .await,
+        Ok(3)
+    );
+
+    ctx.delete().await;
+}
@@ -727,3 +727,79 @@ pub async fn linked() -> Result<(), DbErr> {
 
     Ok(())
 }
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn find_with_related_paginate() -> Result<(), DbErr> {
+    let ctx = TestContext::new("test_find_with_related_paginate").await;
+    create_tables(&ctx.db).await?;
+
+    let mut bakeries = Vec::new();
+    for (name, num_bakers) in [("Bakery A", 3), ("Bakery B", 0), ("Bakery C", 1)] {
+        let bakery = bakery::ActiveModel {
+            name: Set(name.to_owned()),
+            profit_margin: Set(10.4),
+            ..Default::default()
+        }
+        .insert(&ctx.db)
+        .await?;
+        let mut bakers = Vec::new();
+        for i in 0..num_bakers {
+            bakers.push(
+                baker::ActiveModel {
+                    name: Set(format!("{} Baker {}", name, i)),
+                    contact_details: Set(serde_json::json!({})),
+                    bakery_id: Set(Some(bakery.id)),
+                    ..Default::default()
+                }
+                .insert(&ctx.db)
+                .await?,
+            );
+        }
+        bakeries.push((bakery, bakers));
+    }
+
+    let mut paginator = bakery::Entity::find()
+        .find_with_related(baker::Entity)
+        .order_by_asc(bakery::Column::Name)
+        .paginate(&ctx.db, 2);
+
+    assert_eq!(paginator.num_items().await?, 3);
+    assert_eq!(paginator.num_pages().await?, 2);
+    assert_eq!(
+        paginator.fetch_and_next().await?,
+        Some(bakeries[0..2].to_vec())
+    );
+    assert_eq!(
+        paginator.fetch_and_next().await?,
+        Some(bakeries[2..].to_vec())
+    );
+    assert_eq!(paginator.fetch_and_next().await?, None);
+
+    // Only the bakeries having a matching baker are paginated
+    let paginator = bakery::Entity::find()
+        .find_with_related(baker::Entity)
+        .filter(baker::Column::Name.contains("Baker 0"))
+        .paginate(&ctx.db, 1);
+
+    assert_eq!(paginator.num_items().await?, 2);
+    assert_eq!(
+        paginator.fetch_page(1).await?,
+        vec![(bakeries[2].0.clone(), bakeries[2].1.clone())]
+    );
+    assert_eq!(
+        bakery::Entity::find()
+            .find_with_related(baker::Entity)
+            .count(&ctx.db)
+            .await?,
+        3
+    );
+
+    ctx.delete().await;
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ pub mod common;
 
 pub use common::{bakery_chain::*, setup::*, TestContext};
 pub use sea_orm::{entity::prelude::*, *};
-pub use sea_query::Query;
+pub use sea_query::{Expr, Query};
 
 #[sea_orm_macros::test]
 #[cfg(any(
@@ -63,6 +63,128 @@ async fn main() -> Result<(), DbErr> {
         assert!(update_res.rows_affected() > 0);
     }
 
+    if db.support_returning() {
+        let models = Entity::insert_many([
+            ActiveModel {
+                name: Set("Bakery Shop 2".to_owned()),
+                profit_margin: Set(0.6),
+                ..Default::default()
+            },
+            ActiveModel {
+                name: Set("Bakery Shop 3".to_owned()),
+                profit_margin: Set(0.7),
+                ..Default::default()
+            },
+        ])
+        .exec_with_returning_many(db)
+        .await?;
+
+        assert_eq!(models.len(), 2);
+        assert_eq!(models[0].name, "Bakery Shop 2");
+        assert_eq!(models[0].profit_margin, 0.6);
+        assert_eq!(models[1].name, "Bakery Shop 3");
+        assert_eq!(models[1].profit_margin, 0.7);
+        assert_eq!(
+            Entity::find_by_id(models[1].id).one(db).await?,
+            Some(models[1].clone())
+        );
+    } else {
+        assert!(Entity::insert(ActiveModel {
+            name: Set("Bakery Shop 2".to_owned()),
+            profit_margin: Set(0.6),
+            ..Default::default()
+        })
+        .exec_with_returning_many(db)
+        .await
+        .is_err());
+    }
+
+    if db.support_returning() {
+        let models = Entity::update_many()
+            .col_expr(Column::ProfitMargin, Expr::value(0.8))
+            .filter(Column::Name.starts_with("Bakery Shop"))
+            .exec_with_returning(db)
+            .await?;
+
+        assert!(!models.is_empty());
+        assert!(models.iter().all(|model| model.profit_margin == 0.8));
+    } else {
+        assert!(Entity::update_many()
+            .col_expr(Column::ProfitMargin, Expr::value(0.8))
+            .exec_with_returning(db)
+            .await
+            .is_err());
+    }
+
+    if db.support_returning() {
+        #[derive(Debug, PartialEq, FromQueryResult)]
+        struct BakeryName {
+            name: String,
+        }
+
+        let ids: Vec<(i32, f64)> = Entity::insert_many([
+            ActiveModel {
+                name: Set("Bakery Shop 4".to_owned()),
+                profit_margin: Set(0.4),
+                ..Default::default()
+            },
+            ActiveModel {
+                name: Set("Bakery Shop 5".to_owned()),
+                profit_margin: Set(0.5),
+                ..Default::default()
+            },
+        ])
+        .returning([Column::Id, Column::ProfitMargin])
+        .exec_with_returning_tuple(db)
+        .await?;
+
+        assert_eq!(ids.len(), 2);
+        assert_eq!(ids[0].1, 0.4);
+        assert_eq!(ids[1].1, 0.5);
+
+        let names: Vec<BakeryName> = Entity::update_many()
+            .col_expr(Column::ProfitMargin, Expr::value(0.9))
+            .filter(Column::Id.eq(ids[1].0))
+            .returning([Column::Name])
+            .exec_with_returning_as(db)
+            .await?;
+
+        assert_eq!(
+            names,
+            [BakeryName {
+                name: "Bakery Shop 5".to_owned()
+            }]
+        );
+    } else {
+        assert!(Entity::update_many()
+            .col_expr(Column::ProfitMargin, Expr::value(0.9))
+            .returning([Column::Name])
+            .exec_with_returning_tuple::<String, _>(db)
+            .await
+            .is_err());
+    }
+
+    if db.support_returning() {
+        let models = Entity::delete_many()
+            .filter(Column::Name.starts_with("Bakery Shop"))
+            .exec_with_returning(db)
+            .await?;
+
+        assert!(!models.is_empty());
+        assert!(models
+            .iter()
+            .all(|model| model.name.starts_with("Bakery Shop")));
+        assert_eq!(
+            Entity::find()
+                .filter(Column::Name.starts_with("Bakery Shop"))
+                .count(db)
+                .await?,
+            0
+        );
+    } else {
+        assert!(Entity::delete_many().exec_with_returning(db).await.is_err());
+    }
+
     ctx.delete().await;
 
     Ok(())
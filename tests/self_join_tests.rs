@@ -2,7 +2,7 @@ pub mod common;
 
 pub use common::{features::*, setup::*, TestContext};
 use pretty_assertions::assert_eq;
-use sea_orm::{entity::prelude::*, *};
+use sea_orm::{entity::prelude::*, sea_query::Alias, *};
 
 #[sea_orm_macros::test]
 #[cfg(any(
@@ -80,10 +80,72 @@ pub async fn create_metadata(db: &DatabaseConnection) -> Result<(), DbErr> {
             .await?,
         vec![
             (model.clone(), None),
-            (linked_model, Some(model)),
+            (linked_model.clone(), Some(model.clone())),
             (not_linked_model, None),
         ]
     );
 
+    assert_eq!(
+        self_join::Entity::find()
+            .join_as(
+                JoinType::InnerJoin,
+                self_join::Relation::SelfReferencing.def(),
+                Alias::new("mgr")
+            )
+            .select_also_as(self_join::Entity, Alias::new("mgr"))
+            .filter(
+                self_join::Column::Uuid
+                    .with_table_alias(Alias::new("mgr"))
+                    .eq(model.uuid)
+            )
+            .all(db)
+            .await?,
+        vec![(linked_model.clone(), Some(model.clone()))]
+    );
+
+    assert_eq!(
+        find_ancestors(db, &linked_model).await?,
+        vec![linked_model, model]
+    );
+
     Ok(())
 }
+
+/// Walk up the self referencing chain with a recursive common table expression
+pub async fn find_ancestors(
+    db: &DatabaseConnection,
+    model: &self_join::Model,
+) -> Result<Vec<self_join::Model>, DbErr> {
+    use sea_query::{CommonTableExpression, Expr, Query, UnionType, WithClause};
+
+    let ancestor = Alias::new("ancestor");
+    let recursive = self_join::Entity::find()
+        .into_query()
+        .inner_join(
+            ancestor.clone(),
+            Expr::tbl(self_join::Entity, self_join::Column::Uuid)
+                .equals(ancestor.clone(), self_join::Column::UuidRef),
+        )
+        .to_owned();
+    let base = self_join::Entity::find()
+        .filter(self_join::Column::Uuid.eq(model.uuid))
+        .into_query()
+        .union(UnionType::All, recursive)
+        .to_owned();
+    let cte = CommonTableExpression::new()
+        .query(base)
+        .columns(self_join::Column::iter())
+        .table_name(ancestor.clone())
+        .to_owned();
+
+    let stmt = Query::select()
+        .columns(self_join::Column::iter())
+        .from(ancestor)
+        .order_by(self_join::Column::Time, sea_query::Order::Desc)
+        .to_owned()
+        .with(WithClause::new().recursive(true).cte(cte).to_owned());
+
+    self_join::Model::find_by_statement(db.get_database_backend().build(&stmt))
+        .all(db)
+        .await
+}
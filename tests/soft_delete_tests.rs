@@ -0,0 +1,67 @@
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*};
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("soft_delete_tests").await;
+    create_tables(&ctx.db).await?;
+    soft_delete(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn soft_delete(db: &DatabaseConnection) -> Result<(), DbErr> {
+    for title in ["Hello", "Sea", "ORM"] {
+        post::ActiveModel {
+            title: Set(title.to_owned()),
+            ..Default::default()
+        }
+        .insert(db)
+        .await?;
+    }
+
+    let res = post::Entity::delete_many()
+        .filter(post::Column::Title.eq("Sea"))
+        .exec(db)
+        .await?;
+    assert_eq!(res.rows_affected, 1);
+
+    // Deleting a soft deleted row again affects nothing
+    let res = post::Entity::delete_by_id(2).exec(db).await?;
+    assert_eq!(res.rows_affected, 0);
+
+    let titles = |posts: Vec<post::Model>| -> Vec<String> {
+        posts.into_iter().map(|post| post.title).collect()
+    };
+
+    assert_eq!(
+        titles(post::Entity::find().all(db).await?),
+        vec!["Hello", "ORM"]
+    );
+    assert_eq!(post::Entity::find_by_id(2).one(db).await?, None);
+
+    let deleted = post::Entity::find_with_deleted()
+        .filter(post::Column::Id.eq(2))
+        .one(db)
+        .await?
+        .expect("soft deleted post should still exist");
+    assert!(deleted.deleted_at.is_some());
+
+    let res = post::Entity::delete_by_id(2).hard_delete().exec(db).await?;
+    assert_eq!(res.rows_affected, 1);
+    assert_eq!(
+        titles(post::Entity::find_with_deleted().all(db).await?),
+        vec!["Hello", "ORM"]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+pub mod common;
+
+pub use common::{bakery_chain::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, Set, SqlErr};
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("sql_err_tests").await;
+    create_tables(&ctx.db).await?;
+    insert_duplicate_key(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn insert_duplicate_key(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let bakery = bakery::ActiveModel {
+        id: Set(1),
+        name: Set("SeaSide Bakery".to_owned()),
+        profit_margin: Set(10.4),
+    };
+
+    bakery.clone().insert(db).await?;
+    let err = bakery.insert(db).await.expect_err("duplicate primary key");
+
+    assert!(matches!(err, DbErr::Database(_)));
+    assert!(matches!(err.sql_err(), Some(SqlErr::UniqueViolation(_))));
+
+    // SQLite reports extended result codes instead of SQLSTATE codes
+    let sql_state = if cfg!(feature = "sqlx-postgres") {
+        Some("23505")
+    } else if cfg!(feature = "sqlx-mysql") {
+        Some("23000")
+    } else {
+        None
+    };
+    assert_eq!(err.sql_state(), sql_state);
+
+    #[cfg(feature = "sqlx-postgres")]
+    assert_eq!(
+        err.sql_err(),
+        Some(&SqlErr::UniqueViolation(Some("bakery_pkey".to_owned())))
+    );
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 pub mod common;
 pub use common::{features::*, setup::*, TestContext};
-use sea_orm::{entity::prelude::*, DatabaseConnection, IntoActiveModel};
-use time::macros::{date, time};
+use sea_orm::{entity::prelude::*, DatabaseConnection, IntoActiveModel, QuerySelect};
+use time::macros::{date, offset, time};
 
 #[sea_orm_macros::test]
 #[cfg(any(
@@ -9,14 +9,11 @@ use time::macros::{date, time};
     feature = "sqlx-sqlite",
     feature = "sqlx-postgres"
 ))]
-#[cfg_attr(
-    feature = "sqlx-sqlite",
-    should_panic(expected = "time::Date unsupported by sqlx-sqlite")
-)]
 async fn main() {
     let ctx = TestContext::new("time_crate_tests").await;
     create_tables(&ctx.db).await.unwrap();
     create_transaction_log(&ctx.db).await.unwrap();
+    create_transaction_log_with_offset(&ctx.db).await.unwrap();
 
     ctx.delete().await;
 }
@@ -44,3 +41,37 @@ pub async fn create_transaction_log(db: &DatabaseConnection) -> Result<(), DbErr
 
     Ok(())
 }
+
+pub async fn create_transaction_log_with_offset(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let date_time_tz = date!(2022 - 03 - 13)
+        .with_time(time!(16:24:00.123456))
+        .assume_offset(offset!(+08:00));
+    let transaction_log = transaction_log::Model {
+        id: 2,
+        date: date!(2022 - 03 - 13),
+        time: time!(16:24:00.123456),
+        date_time: date!(2022 - 03 - 13).with_time(time!(16:24:00.123456)),
+        date_time_tz,
+    };
+
+    TransactionLog::insert(transaction_log.clone().into_active_model())
+        .exec(db)
+        .await?;
+
+    let found = TransactionLog::find_by_id(2).one(db).await?.unwrap();
+    // The instant is preserved, the offset is normalized to UTC
+    assert_eq!(found, transaction_log);
+    assert_eq!(found.date_time_tz.offset(), offset!(UTC));
+    assert_eq!(found.date_time_tz.time(), time!(08:24:00.123456));
+
+    // A timezone aware column is not silently read as a naive date time
+    let naive: Result<Option<time::PrimitiveDateTime>, DbErr> = TransactionLog::find_by_id(2)
+        .select_only()
+        .column(transaction_log::Column::DateTimeTz)
+        .into_tuple()
+        .one(db)
+        .await;
+    assert!(naive.is_err());
+
+    Ok(())
+}
@@ -196,6 +196,54 @@ pub async fn transaction_begin_commit() -> Result<(), DbErr> {
     Ok(())
 }
 
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn transaction_begin_with_config() -> Result<(), DbErr> {
+    let ctx = TestContext::new("transaction_begin_with_config_test").await;
+    create_tables(&ctx.db).await?;
+
+    {
+        let txn = ctx
+            .db
+            .begin_with_config(
+                Some(IsolationLevel::Serializable),
+                Some(AccessMode::ReadWrite),
+            )
+            .await?;
+
+        bakery::ActiveModel {
+            name: Set("SeaSide Bakery".to_owned()),
+            profit_margin: Set(10.4),
+            ..Default::default()
+        }
+        .save(&txn)
+        .await?;
+
+        txn.commit().await?;
+    }
+
+    {
+        let txn = ctx
+            .db
+            .begin_with_config(
+                Some(IsolationLevel::ReadCommitted),
+                Some(AccessMode::ReadOnly),
+            )
+            .await?;
+
+        assert_eq!(bakery::Entity::find().all(&txn).await?.len(), 1);
+
+        txn.commit().await?;
+    }
+
+    ctx.delete().await;
+    Ok(())
+}
+
 #[sea_orm_macros::test]
 #[cfg(any(
     feature = "sqlx-mysql",
@@ -686,3 +734,44 @@ pub async fn transaction_nested() {
 
     ctx.delete().await;
 }
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn transaction_with_retry_closed_pool() -> Result<(), DbErr> {
+    use std::time::{Duration, Instant};
+
+    let ctx = TestContext::new("transaction_with_retry_closed_pool_test").await;
+    match ctx.db.get_database_backend() {
+        #[cfg(feature = "sqlx-mysql")]
+        DbBackend::MySql => ctx.db.get_mysql_connection_pool().unwrap().close().await,
+        #[cfg(feature = "sqlx-postgres")]
+        DbBackend::Postgres => ctx.db.get_postgres_connection_pool().unwrap().close().await,
+        #[cfg(feature = "sqlx-sqlite")]
+        DbBackend::Sqlite => ctx.db.get_sqlite_connection_pool().unwrap().close().await,
+        #[allow(unreachable_patterns)]
+        _ => unreachable!(),
+    }
+
+    let mut policy = RetryPolicy::new(3);
+    policy.backoff(Duration::from_secs(60));
+    let start = Instant::now();
+    let res = ctx
+        .db
+        .transaction_with_retry(&policy, |_| Box::pin(async { Ok(()) }))
+        .await;
+
+    // The pool cannot be reopened, so the first failure is returned without waiting to retry
+    assert!(matches!(
+        res,
+        Err(TransactionError::Connection(DbErr::Conn(msg))) if msg == "pool closed"
+    ));
+    assert!(start.elapsed() < policy.get_backoff());
+
+    ctx.delete().await;
+
+    Ok(())
+}